@@ -16,20 +16,27 @@ use solana_cli_config::Config;
 use solana_sdk::{commitment_config::CommitmentConfig, pubkey::Pubkey};
 
 pub fn get_cli_config(args: &Cli) -> Result<CliConfig, anyhow::Error> {
+    let fee_payer = args
+        .fee_payer
+        .as_deref()
+        .map(CliSigner::new_from_source)
+        .transpose()?;
+
     let cli_config = if let Some(config_file) = &args.config_file {
         let config = Config::load(config_file.as_os_str().to_str().unwrap())?;
         let signer = if let Some(ledger) = &args.ledger {
             CliSigner::new_ledger(ledger)
         } else if let Some(keypair_path) = &args.keypair {
-            CliSigner::new_keypair_from_path(keypair_path)
+            CliSigner::new_from_source(keypair_path)?
         } else {
-            CliSigner::new_keypair_from_path(&config.keypair_path)
+            CliSigner::new_from_source(&config.keypair_path)?
         };
 
         CliConfig {
             rpc_url: config.json_rpc_url,
             commitment: CommitmentConfig::from_str(&config.commitment)?,
             signer: Some(signer),
+            fee_payer,
         }
     } else {
         let config_file = solana_cli_config::CONFIG_FILE
@@ -39,9 +46,9 @@ pub fn get_cli_config(args: &Cli) -> Result<CliConfig, anyhow::Error> {
             let signer = if let Some(ledger) = &args.ledger {
                 CliSigner::new_ledger(ledger)
             } else if let Some(keypair_path) = &args.keypair {
-                CliSigner::new_keypair_from_path(keypair_path)
+                CliSigner::new_from_source(keypair_path)?
             } else {
-                CliSigner::new_keypair_from_path(&config.keypair_path)
+                CliSigner::new_from_source(&config.keypair_path)?
             };
 
             let rpc = if let Some(rpc) = &args.rpc_url {
@@ -54,6 +61,7 @@ pub fn get_cli_config(args: &Cli) -> Result<CliConfig, anyhow::Error> {
                 rpc_url: rpc,
                 commitment: CommitmentConfig::from_str(&config.commitment)?,
                 signer: Some(signer),
+                fee_payer,
             }
         } else {
             CliConfig {
@@ -67,14 +75,15 @@ pub fn get_cli_config(args: &Cli) -> Result<CliConfig, anyhow::Error> {
                 } else {
                     CommitmentConfig::confirmed()
                 },
-                signer: args.ledger.as_ref().map_or_else(
-                    || {
-                        args.keypair
-                            .as_ref()
-                            .map(|keypair| CliSigner::new_keypair_from_path(keypair))
-                    },
-                    |ledger| Some(CliSigner::new_ledger(ledger)),
-                ),
+                signer: if let Some(ledger) = &args.ledger {
+                    Some(CliSigner::new_ledger(ledger))
+                } else {
+                    args.keypair
+                        .as_deref()
+                        .map(CliSigner::new_from_source)
+                        .transpose()?
+                },
+                fee_payer,
             }
         }
     };
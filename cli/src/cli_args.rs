@@ -25,9 +25,20 @@ pub struct Cli {
     #[arg(long, global = true, help = "Ledger device path or identifier")]
     pub ledger: Option<String>,
 
-    #[arg(long, global = true, help = "Keypair")]
+    #[arg(
+        long,
+        global = true,
+        help = "Signer: keypair path or URI (file:, usb://ledger?key=0, prompt://, stdin://)"
+    )]
     pub keypair: Option<String>,
 
+    #[arg(
+        long,
+        global = true,
+        help = "Fee payer: keypair path or URI, defaults to the signer"
+    )]
+    pub fee_payer: Option<String>,
+
     #[arg(long, global = true, help = "Verbose mode")]
     pub verbose: bool,
 
@@ -8,4 +8,7 @@ pub struct CliConfig {
     pub commitment: CommitmentConfig,
 
     pub signer: Option<CliSigner>,
+
+    /// Pays transaction fees and account creation costs, defaults to `signer`
+    pub fee_payer: Option<CliSigner>,
 }
@@ -1,3 +1,5 @@
+use anyhow::anyhow;
+use solana_clap_utils::keypair::{keypair_from_seed_phrase, parse_signer_source, SignerSourceKind};
 use solana_remote_wallet::{
     ledger::get_ledger_from_info,
     remote_keypair::RemoteKeypair,
@@ -6,7 +8,7 @@ use solana_remote_wallet::{
 use solana_sdk::{
     derivation_path::DerivationPath,
     pubkey::Pubkey,
-    signature::{read_keypair_file, Keypair, Signature, Signer, SignerError},
+    signature::{read_keypair, read_keypair_file, Keypair, Signature, Signer, SignerError},
 };
 
 pub struct CliSigner {
@@ -37,6 +39,45 @@ impl CliSigner {
         )
     }
 
+    /// Creates a signer from a solana-clap style signer source.
+    ///
+    /// Accepts `usb://ledger?key=<index>`, `prompt://`, `stdin://`, `file:<path>` and plain
+    /// keypair file paths, mirroring the Solana CLI.
+    pub fn new_from_source(source: &str) -> anyhow::Result<Self> {
+        let signer_source = parse_signer_source(source)?;
+
+        match signer_source.kind {
+            SignerSourceKind::Usb(_) => Ok(Self::new_ledger(source)),
+            SignerSourceKind::Prompt => {
+                let keypair = keypair_from_seed_phrase(
+                    "signer",
+                    false,
+                    true,
+                    signer_source.derivation_path,
+                    signer_source.legacy,
+                )
+                .map_err(|e| anyhow!("Failed to read keypair from seed phrase: {e}"))?;
+
+                Ok(Self::new_keypair(keypair))
+            }
+            SignerSourceKind::Stdin => {
+                let keypair = read_keypair(&mut std::io::stdin())
+                    .map_err(|e| anyhow!("Failed to read keypair from stdin: {e}"))?;
+
+                Ok(Self::new_keypair(keypair))
+            }
+            SignerSourceKind::Filepath(path) => {
+                let keypair = read_keypair_file(&path)
+                    .map_err(|e| anyhow!("Failed to read keypair file {path}: {e}"))?;
+
+                Ok(Self::new_keypair(keypair))
+            }
+            SignerSourceKind::Pubkey(pubkey) => {
+                Err(anyhow!("Pubkey {pubkey} cannot be used as a signer"))
+            }
+        }
+    }
+
     // Will only work with Ledger devices as
     pub fn new_ledger(path: &str) -> Self {
         println!("\nConnecting to Ledger Device");
@@ -1,25 +1,31 @@
 use ::log::info;
 use anyhow::anyhow;
-use jito_restaking_client_common::log::PrettyDisplay;
-// use base64::{engine::general_purpose, Engine};
+use base64::{engine::general_purpose, Engine};
 use borsh::BorshDeserialize;
 use cli_config::CliConfig;
 use cli_signer::CliSigner;
+use jito_restaking_client_common::log::PrettyDisplay;
 use log::print_base58_tx;
+use solana_account_decoder::{UiAccountEncoding, UiDataSliceConfig};
 use solana_rpc_client::rpc_client::RpcClient;
-// use solana_account_decoder::{UiAccountEncoding, UiDataSliceConfig};
-// use solana_rpc_client_api::{
-//     config::{RpcAccountInfoConfig, RpcProgramAccountsConfig},
-//     filter::{Memcmp, MemcmpEncodedBytes, RpcFilterType},
-// };
+use solana_rpc_client_api::{
+    config::{RpcAccountInfoConfig, RpcProgramAccountsConfig},
+    filter::{Memcmp, MemcmpEncodedBytes, RpcFilterType},
+};
 use solana_sdk::{
-    instruction::Instruction, pubkey::Pubkey, signers::Signers, transaction::Transaction,
+    instruction::Instruction, pubkey::Pubkey, signer::Signer, signers::Signers,
+    transaction::Transaction,
+};
+use spl_token_2022::{
+    extension::StateWithExtensions,
+    state::{Account as TokenAccount, Mint},
 };
 
 pub mod cli_args;
 pub mod cli_config;
 pub mod cli_signer;
 pub mod log;
+pub mod ui_amount;
 pub mod vault_whitelist;
 pub mod vault_whitelist_handler;
 
@@ -35,6 +41,14 @@ pub(crate) trait CliHandler {
             .ok_or_else(|| anyhow!("Signer not provided"))
     }
 
+    /// Returns the fee payer, falling back to the signer when `--fee-payer` is not provided.
+    fn fee_payer(&self) -> anyhow::Result<&CliSigner> {
+        match self.cli_config().fee_payer.as_ref() {
+            Some(fee_payer) => Ok(fee_payer),
+            None => self.signer(),
+        }
+    }
+
     /// Creates a new RPC client using the configuration from the CLI handler.
     ///
     /// This method constructs an RPC client with the URL and commitment level specified in the
@@ -47,57 +61,57 @@ pub(crate) trait CliHandler {
         )
     }
 
-    // Creates an RPC program accounts configuration for fetching accounts of type `T` with an optional public key filter.
-    //
-    // This method constructs a configuration that can be used with RPC methods to fetch program accounts
-    // that match specific criteria. It automatically adds filters for the account data size and the discriminator
-    // of type `T` to ensure only accounts of the expected type are returned.
-    // fn get_rpc_program_accounts_config<T: jito_bytemuck::Discriminator>(
-    //     &self,
-    //     filter_pubkey: Option<(&Pubkey, usize)>,
-    // ) -> anyhow::Result<RpcProgramAccountsConfig> {
-    //     let data_size = std::mem::size_of::<T>()
-    //         .checked_add(8)
-    //         .ok_or_else(|| anyhow!("Failed to add"))?;
-
-    //     let encoded_discriminator =
-    //         general_purpose::STANDARD.encode(vec![T::DISCRIMINATOR, 0, 0, 0, 0, 0, 0, 0]);
-    //     let discriminator_filter = RpcFilterType::Memcmp(Memcmp::new(
-    //         0,
-    //         MemcmpEncodedBytes::Base64(encoded_discriminator),
-    //     ));
-
-    //     let mut filters = vec![
-    //         RpcFilterType::DataSize(data_size as u64),
-    //         discriminator_filter,
-    //     ];
-
-    //     if let Some((pubkey, offset)) = filter_pubkey {
-    //         let pubkey_filter = RpcFilterType::Memcmp(Memcmp::new(
-    //             offset,
-    //             MemcmpEncodedBytes::Base64(general_purpose::STANDARD.encode(pubkey.to_bytes())),
-    //         ));
-
-    //         filters.push(pubkey_filter);
-    //     }
-
-    //     let config = RpcProgramAccountsConfig {
-    //         filters: Some(filters),
-    //         account_config: RpcAccountInfoConfig {
-    //             encoding: Some(UiAccountEncoding::Base64),
-    //             data_slice: Some(UiDataSliceConfig {
-    //                 offset: 0,
-    //                 length: data_size,
-    //             }),
-    //             commitment: None,
-    //             min_context_slot: None,
-    //         },
-    //         with_context: Some(false),
-    //         sort_results: Some(false),
-    //     };
-
-    //     Ok(config)
-    // }
+    /// Creates an RPC program accounts configuration for fetching accounts of type `T` with an optional public key filter.
+    ///
+    /// This method constructs a configuration that can be used with RPC methods to fetch program accounts
+    /// that match specific criteria. It automatically adds filters for the account data size and the discriminator
+    /// of type `T` to ensure only accounts of the expected type are returned.
+    fn get_rpc_program_accounts_config<T: jito_bytemuck::Discriminator>(
+        &self,
+        filter_pubkey: Option<(&Pubkey, usize)>,
+    ) -> anyhow::Result<RpcProgramAccountsConfig> {
+        let data_size = std::mem::size_of::<T>()
+            .checked_add(8)
+            .ok_or_else(|| anyhow!("Failed to add"))?;
+
+        let encoded_discriminator =
+            general_purpose::STANDARD.encode(vec![T::DISCRIMINATOR, 0, 0, 0, 0, 0, 0, 0]);
+        let discriminator_filter = RpcFilterType::Memcmp(Memcmp::new(
+            0,
+            MemcmpEncodedBytes::Base64(encoded_discriminator),
+        ));
+
+        let mut filters = vec![
+            RpcFilterType::DataSize(data_size as u64),
+            discriminator_filter,
+        ];
+
+        if let Some((pubkey, offset)) = filter_pubkey {
+            let pubkey_filter = RpcFilterType::Memcmp(Memcmp::new(
+                offset,
+                MemcmpEncodedBytes::Base64(general_purpose::STANDARD.encode(pubkey.to_bytes())),
+            ));
+
+            filters.push(pubkey_filter);
+        }
+
+        let config = RpcProgramAccountsConfig {
+            filters: Some(filters),
+            account_config: RpcAccountInfoConfig {
+                encoding: Some(UiAccountEncoding::Base64),
+                data_slice: Some(UiDataSliceConfig {
+                    offset: 0,
+                    length: data_size,
+                }),
+                commitment: None,
+                min_context_slot: None,
+            },
+            with_context: Some(false),
+            sort_results: Some(false),
+        };
+
+        Ok(config)
+    }
 
     /// Fetches and deserializes an account
     ///
@@ -115,6 +129,56 @@ pub(crate) trait CliHandler {
         Ok(account)
     }
 
+    /// Returns the token program owning `mint`
+    ///
+    /// Token-2022 mints are rejected: the whitelist program mints, enqueues and burns through the
+    /// Jito Vault program with SPL Token CPIs, so such a vault cannot complete those flows.
+    fn get_token_program_id(&self, mint: &Pubkey) -> anyhow::Result<Pubkey> {
+        let rpc_client = self.get_rpc_client();
+
+        let account = rpc_client.get_account(mint)?;
+        if account.owner.eq(&spl_token_2022::id()) {
+            return Err(anyhow!(
+                "Mint {} is a Token-2022 mint, which the vault whitelist does not support",
+                mint
+            ));
+        }
+        if account.owner.ne(&spl_token::id()) {
+            return Err(anyhow!(
+                "Mint {} is owned by {}, which is not a token program",
+                mint,
+                account.owner
+            ));
+        }
+
+        Ok(account.owner)
+    }
+
+    /// Returns the decimals of `mint`, owned by either SPL Token or Token-2022
+    fn get_mint_decimals(&self, mint: &Pubkey) -> anyhow::Result<u8> {
+        let rpc_client = self.get_rpc_client();
+
+        let account = rpc_client.get_account(mint)?;
+        let mint = StateWithExtensions::<Mint>::unpack(&account.data)?;
+
+        Ok(mint.base.decimals)
+    }
+
+    /// Returns the balance of `token_account` in base units, zero if it does not exist yet
+    fn get_token_balance(&self, token_account: &Pubkey) -> anyhow::Result<u64> {
+        let rpc_client = self.get_rpc_client();
+
+        let account = rpc_client
+            .get_account_with_commitment(token_account, self.cli_config().commitment)?
+            .value;
+        match account {
+            Some(account) => Ok(StateWithExtensions::<TokenAccount>::unpack(&account.data)?
+                .base
+                .amount),
+            None => Ok(0),
+        }
+    }
+
     /// Processes a transaction by either printing it as Base58 or sending it.
     ///
     /// This method handles the logic for processing a set of instructions as a transaction.
@@ -143,4 +207,22 @@ pub(crate) trait CliHandler {
 
         Ok(())
     }
+
+    /// Processes a transaction signed by `signer` with fees paid by the configured fee payer.
+    ///
+    /// The fee payer only signs once when it is the same key as `signer`, so a Ledger is not
+    /// prompted twice for the same transaction.
+    fn process_transaction_with_fee_payer(
+        &self,
+        ixs: &[Instruction],
+        signer: &CliSigner,
+    ) -> anyhow::Result<()> {
+        let fee_payer = self.fee_payer()?;
+
+        if fee_payer.pubkey().eq(&signer.pubkey()) {
+            self.process_transaction(ixs, &signer.pubkey(), &[signer])
+        } else {
+            self.process_transaction(ixs, &fee_payer.pubkey(), &[signer, fee_payer])
+        }
+    }
 }
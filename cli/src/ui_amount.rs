@@ -0,0 +1,125 @@
+use std::str::FromStr;
+
+use anyhow::anyhow;
+
+/// A token amount in UI units such as `12.5`, or `ALL` for the whole balance
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum UiAmount {
+    All,
+    Decimal { whole: String, fraction: String },
+}
+
+impl FromStr for UiAmount {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.eq_ignore_ascii_case("ALL") {
+            return Ok(Self::All);
+        }
+
+        let (whole, fraction) = s.split_once('.').unwrap_or((s, ""));
+        if (whole.is_empty() && fraction.is_empty())
+            || !whole
+                .chars()
+                .chain(fraction.chars())
+                .all(|c| c.is_ascii_digit())
+        {
+            return Err(anyhow!("Invalid amount {}, expected e.g. 12.5 or ALL", s));
+        }
+
+        Ok(Self::Decimal {
+            whole: whole.to_string(),
+            fraction: fraction.to_string(),
+        })
+    }
+}
+
+impl UiAmount {
+    /// Converts to base units of a mint with `decimals`, `ALL` resolving to `balance`
+    pub fn to_base_units(&self, decimals: u8, balance: u64) -> anyhow::Result<u64> {
+        let (whole, fraction) = match self {
+            Self::All => return Ok(balance),
+            Self::Decimal { whole, fraction } => (whole, fraction),
+        };
+
+        let fraction = fraction.trim_end_matches('0');
+        if fraction.len() > usize::from(decimals) {
+            return Err(anyhow!(
+                "Amount {}.{} has more than {} decimal places",
+                whole,
+                fraction,
+                decimals
+            ));
+        }
+
+        let digits = format!(
+            "{}{:0<width$}",
+            whole,
+            fraction,
+            width = usize::from(decimals)
+        );
+        if digits.is_empty() {
+            return Ok(0);
+        }
+
+        digits
+            .parse::<u64>()
+            .map_err(|_| anyhow!("Amount {}.{} is too large", whole, fraction))
+    }
+
+    /// Converts to base units, rejecting `ALL` for amounts that are not drawn from a balance
+    pub fn to_base_units_exact(&self, decimals: u8) -> anyhow::Result<u64> {
+        match self {
+            Self::All => Err(anyhow!("ALL is only accepted for input amounts")),
+            amount => amount.to_base_units(decimals, 0),
+        }
+    }
+}
+
+/// Formats `amount` base units of a mint with `decimals` in UI units
+pub fn format_ui_amount(amount: u64, decimals: u8) -> String {
+    let decimals = usize::from(decimals);
+    let digits = format!("{:0>width$}", amount, width = decimals.saturating_add(1));
+    let (whole, fraction) = digits.split_at(digits.len().saturating_sub(decimals));
+    let fraction = fraction.trim_end_matches('0');
+
+    if fraction.is_empty() {
+        whole.to_string()
+    } else {
+        format!("{}.{}", whole, fraction)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_to_base_units() {
+        let amount = |s: &str| UiAmount::from_str(s).unwrap();
+
+        assert_eq!(amount("12.5").to_base_units(9, 0).unwrap(), 12_500_000_000);
+        assert_eq!(amount("12").to_base_units(9, 0).unwrap(), 12_000_000_000);
+        assert_eq!(amount(".5").to_base_units(1, 0).unwrap(), 5);
+        assert_eq!(amount("1.50").to_base_units(1, 0).unwrap(), 15);
+        assert_eq!(amount("0").to_base_units(0, 0).unwrap(), 0);
+        assert_eq!(amount("all").to_base_units(9, 42).unwrap(), 42);
+        assert!(amount("1.25").to_base_units(1, 0).is_err());
+        assert!(amount("18446744073709551616").to_base_units(0, 0).is_err());
+        assert!(amount("ALL").to_base_units_exact(9).is_err());
+
+        assert!(UiAmount::from_str("").is_err());
+        assert!(UiAmount::from_str(".").is_err());
+        assert!(UiAmount::from_str("1.2.3").is_err());
+        assert!(UiAmount::from_str("-1").is_err());
+    }
+
+    #[test]
+    fn test_format_ui_amount() {
+        assert_eq!(format_ui_amount(12_500_000_000, 9), "12.5");
+        assert_eq!(format_ui_amount(12_000_000_000, 9), "12");
+        assert_eq!(format_ui_amount(5, 9), "0.000000005");
+        assert_eq!(format_ui_amount(0, 9), "0");
+        assert_eq!(format_ui_amount(42, 0), "42");
+    }
+}
@@ -1,8 +1,11 @@
 use std::path::PathBuf;
 
-use clap::{command, Subcommand};
+use clap::{command, Subcommand, ValueEnum};
+use jito_vault_whitelist_core::whitelist::WhitelistMode;
 use solana_sdk::pubkey::Pubkey;
 
+use crate::ui_amount::UiAmount;
+
 #[derive(Subcommand)]
 pub enum VaultWhitelistCommands {
     Config {
@@ -21,44 +24,351 @@ pub enum ConfigActions {
     Initialize,
     /// Fetches global config
     Get,
+    /// Upgrades a Config, Whitelist or WhitelistUser account to the current layout version
+    MigrateAccount { account: Pubkey },
 }
 
 /// Vault Whitelist commands
 #[derive(Subcommand)]
 pub enum VaultWhitelistActions {
     /// Creates a new vault whitelist
-    Initialize { vault: Pubkey },
+    Initialize {
+        vault: Pubkey,
+
+        #[arg(long, help = "Name telling the whitelist apart, at most 32 bytes")]
+        name: Option<String>,
+
+        #[arg(long, help = "KYC provider vetting the whitelist's users")]
+        kyc_provider: Option<Pubkey>,
+
+        #[arg(long, help = "URI of the off-chain policy document, at most 128 bytes")]
+        uri: Option<String>,
+    },
+
+    /// Fetches a vault whitelist, including its membership and activity counters
+    Get { vault: Pubkey },
+
+    /// Fetches a whitelisted user, including its deposit and withdrawal totals
+    GetUser { vault: Pubkey, user: Pubkey },
 
     /// Set mint burn admin
     SetMintBurnAdmin { vault: Pubkey },
 
     /// Add to whitelist
-    AddToWhitelist { vault: Pubkey, user: Pubkey },
+    AddToWhitelist {
+        vault: Pubkey,
+        user: Pubkey,
+
+        #[arg(
+            long,
+            help = "32-byte hex tag linking the user to an off-chain record, such as a hash of a KYC case ID"
+        )]
+        reference: Option<String>,
+    },
 
     /// Remove from whitelist
-    RemoveFromWhitelist { vault: Pubkey, user: Pubkey },
+    RemoveFromWhitelist {
+        vault: Pubkey,
+        user: Pubkey,
+
+        #[arg(
+            long,
+            help = "Account receiving the reclaimed rent, defaults to the account that paid it"
+        )]
+        rent_receiver: Option<Pubkey>,
+    },
+
+    /// Set whether the whitelist is an allow list or a deny list
+    SetMode {
+        vault: Pubkey,
+
+        #[arg(value_enum)]
+        mode: WhitelistModeArg,
+    },
+
+    /// Set the number of slots after a user's last mint before the user may enqueue a withdrawal
+    SetLockup { vault: Pubkey, lockup_slots: u64 },
+
+    /// Set the whitelist metadata, omitted fields keep their current value
+    SetMetadata {
+        vault: Pubkey,
+
+        #[arg(
+            long,
+            help = "Name telling the whitelist apart, at most 32 bytes, empty clears it"
+        )]
+        name: Option<String>,
+
+        #[arg(
+            long,
+            help = "KYC provider vetting the whitelist's users, the system program clears it"
+        )]
+        kyc_provider: Option<Pubkey>,
+
+        #[arg(
+            long,
+            help = "URI of the off-chain policy document, at most 128 bytes, empty clears it"
+        )]
+        uri: Option<String>,
+    },
+
+    /// Set who may burn the signer's matured withdrawal tickets on the signer's behalf
+    SetBurnDelegate {
+        vault: Pubkey,
+
+        /// Burn delegate, omitting it clears the delegate
+        burn_delegate: Option<Pubkey>,
+
+        #[arg(long, help = "Let anyone burn the signer's matured withdrawal tickets")]
+        permissionless: bool,
+    },
+
+    /// Set the key allowed to burn matured withdrawal tickets on behalf of stakers
+    SetBurnCrank {
+        vault: Pubkey,
+
+        /// Burn crank, omitting it disables burning on behalf of stakers
+        burn_crank: Option<Pubkey>,
+    },
+
+    /// Set the limits on minting and enqueueing withdrawals per window of epochs, zero disables a
+    /// limit
+    SetRateLimit {
+        vault: Pubkey,
+
+        /// Length of a rate limit window in epochs, `0` disables rate limits
+        rate_limit_epochs: u64,
+
+        #[arg(
+            long,
+            default_value_t = 0,
+            help = "Supported tokens minted through the whitelist per window"
+        )]
+        mint_limit: u64,
+
+        #[arg(
+            long,
+            default_value_t = 0,
+            help = "VRT enqueued through the whitelist per window"
+        )]
+        enqueue_limit: u64,
+
+        #[arg(
+            long,
+            default_value_t = 0,
+            help = "Supported tokens minted by each user per window"
+        )]
+        user_mint_limit: u64,
+
+        #[arg(
+            long,
+            default_value_t = 0,
+            help = "VRT enqueued by each user per window"
+        )]
+        user_enqueue_limit: u64,
+    },
+
+    /// Set the smallest and largest amounts accepted by a single mint, zero is unbounded
+    SetDepositLimits {
+        vault: Pubkey,
+
+        #[arg(
+            long,
+            help = "Minimum supported tokens per mint in UI units such as 12.5, unbounded if omitted"
+        )]
+        min_deposit: Option<UiAmount>,
+
+        #[arg(
+            long,
+            help = "Maximum supported tokens per mint in UI units such as 12.5, unbounded if omitted"
+        )]
+        max_deposit: Option<UiAmount>,
+    },
+
+    /// Set deposit limits for a whitelisted user, overriding the whitelist's when non-zero
+    SetUserDepositLimits {
+        vault: Pubkey,
+        user: Pubkey,
+
+        #[arg(
+            long,
+            help = "Minimum supported tokens per mint in UI units such as 12.5, the whitelist's if omitted"
+        )]
+        min_deposit: Option<UiAmount>,
 
-    /// Mint
+        #[arg(
+            long,
+            help = "Maximum supported tokens per mint in UI units such as 12.5, the whitelist's if omitted"
+        )]
+        max_deposit: Option<UiAmount>,
+    },
+
+    /// Allow a whitelisted user to mint VRT on behalf of other whitelisted users
+    SetCustodian {
+        vault: Pubkey,
+        user: Pubkey,
+
+        #[arg(long, help = "Revoke the custodian flag instead of granting it")]
+        revoke: bool,
+    },
+
+    /// Set the tag linking a whitelisted user to an off-chain record
+    SetReference {
+        vault: Pubkey,
+        user: Pubkey,
+
+        #[arg(help = "32-byte hex tag, omitting it clears the reference")]
+        reference: Option<String>,
+    },
+
+    /// Add to blacklist, denying the user in deny-list mode
+    AddToBlacklist { vault: Pubkey, user: Pubkey },
+
+    /// Remove from blacklist
+    RemoveFromBlacklist {
+        vault: Pubkey,
+        user: Pubkey,
+
+        #[arg(
+            long,
+            help = "Account receiving the reclaimed rent, defaults to the account that paid it"
+        )]
+        rent_receiver: Option<Pubkey>,
+    },
+
+    /// Add every user in a CSV file whose first column is the user address and optional second
+    /// column is the user's hex reference, skipping users already listed
+    Import {
+        vault: Pubkey,
+        file: PathBuf,
+
+        #[arg(long, help = "Import into the blacklist instead of the whitelist")]
+        blacklist: bool,
+    },
+
+    /// List the users on the whitelist in the format read by `import`
+    List {
+        vault: Pubkey,
+
+        #[arg(long, help = "List the blacklist instead of the whitelist")]
+        blacklist: bool,
+    },
+
+    /// Mint VRT as the signer (`--keypair` / `--ledger`)
     Mint {
-        signer_keypair_path: PathBuf,
         vault: Pubkey,
-        amount_in: u64,
-        min_amount_out: u64,
+
+        #[arg(help = "Supported tokens to deposit in UI units such as 12.5, or ALL")]
+        amount_in: UiAmount,
+
+        #[arg(help = "Minimum VRT to receive in UI units")]
+        min_amount_out: Option<UiAmount>,
+
+        #[arg(
+            long,
+            conflicts_with = "min_amount_out",
+            help = "Derive min_amount_out from the vault's current exchange rate less this slippage"
+        )]
+        slippage_bps: Option<u16>,
+    },
+
+    /// Mint VRT from the signer's supported tokens to a whitelisted beneficiary, the signer must
+    /// be a custodian
+    MintFor {
+        vault: Pubkey,
+        beneficiary: Pubkey,
+
+        #[arg(help = "Supported tokens to deposit in UI units such as 12.5, or ALL")]
+        amount_in: UiAmount,
+
+        #[arg(help = "Minimum VRT to receive in UI units")]
+        min_amount_out: Option<UiAmount>,
+
+        #[arg(
+            long,
+            conflicts_with = "min_amount_out",
+            help = "Derive min_amount_out from the vault's current exchange rate less this slippage"
+        )]
+        slippage_bps: Option<u16>,
     },
 
-    /// Enqueue Withdrawal
+    /// Enqueue Withdrawal as the signer (`--keypair` / `--ledger`)
     EnqueueWithdrawal {
-        signer_keypair_path: PathBuf,
         vault: Pubkey,
-        amount: u64,
+
+        #[arg(help = "VRT to withdraw in UI units such as 12.5, or ALL")]
+        amount: UiAmount,
+
+        #[arg(
+            long,
+            help = "Signer used as the withdrawal ticket base, a new keypair is generated if omitted. Required with --print-tx"
+        )]
+        base: Option<String>,
     },
 
-    /// Burn Withdrawal Ticket
+    /// Burn Withdrawal Ticket as the signer (`--keypair` / `--ledger`)
     BurnWithdrawalTicket {
-        signer_keypair_path: PathBuf,
         vault: Pubkey,
+
+        #[arg(
+            long,
+            help = "Withdrawal ticket to burn, required when the signer has more than one"
+        )]
+        ticket: Option<Pubkey>,
+
+        #[arg(
+            long,
+            help = "Minimum supported tokens to receive in UI units, the check is skipped if omitted"
+        )]
+        min_amount_out: Option<UiAmount>,
+
+        #[arg(
+            long,
+            conflicts_with = "min_amount_out",
+            help = "Derive min_amount_out from the vault's current exchange rate less this slippage"
+        )]
+        slippage_bps: Option<u16>,
+    },
+
+    /// List outstanding withdrawal tickets with when they become burnable and their estimated payout
+    Tickets {
+        vault: Pubkey,
+
+        #[arg(long, help = "Staker whose tickets are listed, defaults to the signer")]
+        staker: Option<Pubkey>,
     },
 
     /// Close whitelist
-    CloseWhitelist { vault: Pubkey },
+    CloseWhitelist {
+        vault: Pubkey,
+
+        #[arg(
+            long,
+            help = "Account receiving the reclaimed rent, defaults to the account that paid it"
+        )]
+        rent_receiver: Option<Pubkey>,
+    },
+
+    /// Close every WhitelistUser left behind by a closed or re-created whitelist, refunding rent to
+    /// each payer
+    CloseOrphanedWhitelistUsers { vault: Pubkey },
+}
+
+/// Whitelist mode
+#[derive(Clone, Copy, ValueEnum)]
+pub enum WhitelistModeArg {
+    /// Only whitelisted users may mint and redeem
+    AllowList,
+
+    /// Everyone except blacklisted users may mint and redeem
+    DenyList,
+}
+
+impl From<WhitelistModeArg> for WhitelistMode {
+    fn from(mode: WhitelistModeArg) -> Self {
+        match mode {
+            WhitelistModeArg::AllowList => Self::AllowList,
+            WhitelistModeArg::DenyList => Self::DenyList,
+        }
+    }
 }
@@ -1,4 +1,7 @@
-use std::path::PathBuf;
+use std::{
+    path::{Path, PathBuf},
+    str::FromStr,
+};
 
 use anyhow::anyhow;
 use borsh::BorshDeserialize;
@@ -7,25 +10,50 @@ use jito_restaking_client_common::log::PrettyDisplay;
 use jito_vault_core::{
     config::Config, vault_staker_withdrawal_ticket::VaultStakerWithdrawalTicket,
 };
-use jito_vault_whitelist_client::instructions::{
-    AddToWhitelistBuilder, BurnWithdrawalTicketBuilder, CloseWhitelistBuilder,
-    EnqueueWithdrawalBuilder, InitializeConfigBuilder, InitializeWhitelistBuilder, MintBuilder,
-    RemoveFromWhitelistBuilder, SetMintBurnAdminBuilder,
+use jito_vault_whitelist_client::{
+    instructions::{
+        AddToBlacklistBuilder, AddToWhitelistBuilder, BurnWithdrawalTicketBuilder,
+        CloseOrphanedWhitelistUserBuilder, CloseWhitelistBuilder, EnqueueWithdrawalBuilder,
+        InitializeConfigBuilder, InitializeWhitelistBuilder, MigrateAccountBuilder, MintBuilder,
+        MintForBuilder, RemoveFromBlacklistBuilder, RemoveFromWhitelistBuilder,
+        SetBurnCrankBuilder, SetBurnDelegateBuilder, SetCustodianBuilder, SetDepositLimitsBuilder,
+        SetLockupBuilder, SetMintBurnAdminBuilder, SetRateLimitBuilder,
+        SetUserDepositLimitsBuilder, SetUserReferenceBuilder, SetWhitelistMetadataBuilder,
+        SetWhitelistModeBuilder,
+    },
+    quote, withdrawal_ticket,
+};
+use jito_vault_whitelist_core::{
+    blacklisted::Blacklisted,
+    whitelist::WhitelistMode,
+    whitelist_metadata::WhitelistMetadata,
+    whitelist_user::{WhitelistUser, WHITELIST_USER_REFERENCE_LEN},
 };
 use log::{debug, info};
 use solana_program::pubkey::Pubkey;
-use solana_sdk::{signature::read_keypair_file, signer::Signer};
+use solana_sdk::{instruction::Instruction, signature::Keypair, signer::Signer};
 use spl_associated_token_account::{
-    get_associated_token_address, instruction::create_associated_token_account_idempotent,
+    get_associated_token_address_with_program_id,
+    instruction::create_associated_token_account_idempotent,
 };
 
 use crate::{
     cli_config::CliConfig,
     cli_signer::CliSigner,
+    ui_amount::{format_ui_amount, UiAmount},
     vault_whitelist::{ConfigActions, VaultWhitelistActions, VaultWhitelistCommands},
     CliHandler,
 };
 
+/// Maximum number of `CloseOrphanedWhitelistUser` instructions packed into one transaction
+const CLOSE_ORPHANED_WHITELIST_USERS_PER_TX: usize = 10;
+
+/// Maximum number of `AddToWhitelist` or `AddToBlacklist` instructions packed into one transaction
+const IMPORT_USERS_PER_TX: usize = 5;
+
+/// Maximum number of accounts fetched by one `getMultipleAccounts` request
+const MAX_MULTIPLE_ACCOUNTS: usize = 100;
+
 pub struct VaultWhitelistCliHandler {
     /// The configuration of CLI
     cli_config: CliConfig,
@@ -74,45 +102,195 @@ impl VaultWhitelistCliHandler {
             VaultWhitelistCommands::Config {
                 action: ConfigActions::Get,
             } => self.get_config(),
+            VaultWhitelistCommands::Config {
+                action: ConfigActions::MigrateAccount { account },
+            } => self.migrate_account(account),
+            VaultWhitelistCommands::Whitelist {
+                action:
+                    VaultWhitelistActions::Initialize {
+                        vault,
+                        name,
+                        kyc_provider,
+                        uri,
+                    },
+            } => self.initialize_whitelist(vault, name, kyc_provider, uri),
+            VaultWhitelistCommands::Whitelist {
+                action:
+                    VaultWhitelistActions::SetMetadata {
+                        vault,
+                        name,
+                        kyc_provider,
+                        uri,
+                    },
+            } => self.set_whitelist_metadata(vault, name, kyc_provider, uri),
             VaultWhitelistCommands::Whitelist {
-                action: VaultWhitelistActions::Initialize { vault },
-            } => self.initialize_whitelist(vault),
+                action: VaultWhitelistActions::Get { vault },
+            } => self.get_whitelist(vault),
+            VaultWhitelistCommands::Whitelist {
+                action: VaultWhitelistActions::GetUser { vault, user },
+            } => self.get_whitelist_user(vault, user),
             VaultWhitelistCommands::Whitelist {
                 action: VaultWhitelistActions::SetMintBurnAdmin { vault },
             } => self.set_mint_burn_admin(vault),
             VaultWhitelistCommands::Whitelist {
-                action: VaultWhitelistActions::AddToWhitelist { vault, user },
-            } => self.add_to_whitelist(vault, user),
+                action:
+                    VaultWhitelistActions::AddToWhitelist {
+                        vault,
+                        user,
+                        reference,
+                    },
+            } => self.add_to_whitelist(vault, user, reference),
+            VaultWhitelistCommands::Whitelist {
+                action:
+                    VaultWhitelistActions::RemoveFromWhitelist {
+                        vault,
+                        user,
+                        rent_receiver,
+                    },
+            } => self.remove_from_whitelist(vault, user, rent_receiver),
+            VaultWhitelistCommands::Whitelist {
+                action: VaultWhitelistActions::SetMode { vault, mode },
+            } => self.set_whitelist_mode(vault, mode.into()),
+            VaultWhitelistCommands::Whitelist {
+                action:
+                    VaultWhitelistActions::SetLockup {
+                        vault,
+                        lockup_slots,
+                    },
+            } => self.set_lockup(vault, lockup_slots),
+            VaultWhitelistCommands::Whitelist {
+                action: VaultWhitelistActions::SetBurnCrank { vault, burn_crank },
+            } => self.set_burn_crank(vault, burn_crank),
+            VaultWhitelistCommands::Whitelist {
+                action:
+                    VaultWhitelistActions::SetBurnDelegate {
+                        vault,
+                        burn_delegate,
+                        permissionless,
+                    },
+            } => self.set_burn_delegate(vault, burn_delegate, permissionless),
+            VaultWhitelistCommands::Whitelist {
+                action:
+                    VaultWhitelistActions::SetRateLimit {
+                        vault,
+                        rate_limit_epochs,
+                        mint_limit,
+                        enqueue_limit,
+                        user_mint_limit,
+                        user_enqueue_limit,
+                    },
+            } => self.set_rate_limit(
+                vault,
+                rate_limit_epochs,
+                mint_limit,
+                enqueue_limit,
+                user_mint_limit,
+                user_enqueue_limit,
+            ),
+            VaultWhitelistCommands::Whitelist {
+                action:
+                    VaultWhitelistActions::SetDepositLimits {
+                        vault,
+                        min_deposit,
+                        max_deposit,
+                    },
+            } => self.set_deposit_limits(vault, min_deposit, max_deposit),
+            VaultWhitelistCommands::Whitelist {
+                action:
+                    VaultWhitelistActions::SetUserDepositLimits {
+                        vault,
+                        user,
+                        min_deposit,
+                        max_deposit,
+                    },
+            } => self.set_user_deposit_limits(vault, user, min_deposit, max_deposit),
+            VaultWhitelistCommands::Whitelist {
+                action:
+                    VaultWhitelistActions::SetCustodian {
+                        vault,
+                        user,
+                        revoke,
+                    },
+            } => self.set_custodian(vault, user, !revoke),
+            VaultWhitelistCommands::Whitelist {
+                action:
+                    VaultWhitelistActions::SetReference {
+                        vault,
+                        user,
+                        reference,
+                    },
+            } => self.set_user_reference(vault, user, reference),
+            VaultWhitelistCommands::Whitelist {
+                action: VaultWhitelistActions::AddToBlacklist { vault, user },
+            } => self.add_to_blacklist(vault, user),
+            VaultWhitelistCommands::Whitelist {
+                action:
+                    VaultWhitelistActions::RemoveFromBlacklist {
+                        vault,
+                        user,
+                        rent_receiver,
+                    },
+            } => self.remove_from_blacklist(vault, user, rent_receiver),
+            VaultWhitelistCommands::Whitelist {
+                action:
+                    VaultWhitelistActions::Import {
+                        vault,
+                        file,
+                        blacklist,
+                    },
+            } => self.import_users(vault, file, blacklist),
             VaultWhitelistCommands::Whitelist {
-                action: VaultWhitelistActions::RemoveFromWhitelist { vault, user },
-            } => self.remove_from_whitelist(vault, user),
+                action: VaultWhitelistActions::List { vault, blacklist },
+            } => self.list_users(vault, blacklist),
             VaultWhitelistCommands::Whitelist {
                 action:
                     VaultWhitelistActions::Mint {
-                        signer_keypair_path,
                         vault,
                         amount_in,
                         min_amount_out,
+                        slippage_bps,
+                    },
+            } => self.mint(vault, amount_in, min_amount_out, slippage_bps),
+            VaultWhitelistCommands::Whitelist {
+                action:
+                    VaultWhitelistActions::MintFor {
+                        vault,
+                        beneficiary,
+                        amount_in,
+                        min_amount_out,
+                        slippage_bps,
                     },
-            } => self.mint(signer_keypair_path, vault, amount_in, min_amount_out),
+            } => self.mint_for(vault, beneficiary, amount_in, min_amount_out, slippage_bps),
             VaultWhitelistCommands::Whitelist {
                 action:
                     VaultWhitelistActions::EnqueueWithdrawal {
-                        signer_keypair_path,
                         vault,
                         amount,
+                        base,
                     },
-            } => self.enqueue_withdrawal(signer_keypair_path, vault, amount),
+            } => self.enqueue_withdrawal(vault, amount, base),
             VaultWhitelistCommands::Whitelist {
                 action:
                     VaultWhitelistActions::BurnWithdrawalTicket {
-                        signer_keypair_path,
                         vault,
+                        ticket,
+                        min_amount_out,
+                        slippage_bps,
+                    },
+            } => self.burn_withdrawal_ticket(vault, ticket, min_amount_out, slippage_bps),
+            VaultWhitelistCommands::Whitelist {
+                action: VaultWhitelistActions::Tickets { vault, staker },
+            } => self.list_withdrawal_tickets(vault, staker),
+            VaultWhitelistCommands::Whitelist {
+                action:
+                    VaultWhitelistActions::CloseWhitelist {
+                        vault,
+                        rent_receiver,
                     },
-            } => self.burn_withdrawal_ticket(signer_keypair_path, vault),
+            } => self.close_whitelist(vault, rent_receiver),
             VaultWhitelistCommands::Whitelist {
-                action: VaultWhitelistActions::CloseWhitelist { vault },
-            } => self.close_whitelist(vault),
+                action: VaultWhitelistActions::CloseOrphanedWhitelistUsers { vault },
+            } => self.close_orphaned_whitelist_users(vault),
         }
     }
 }
@@ -170,12 +348,34 @@ impl VaultWhitelistCliHandler {
         info!("{}", config.pretty_display());
         Ok(())
     }
+
+    /// Upgrade an account to the current layout version
+    pub fn migrate_account(&self, account: Pubkey) -> anyhow::Result<()> {
+        let fee_payer = self.fee_payer()?;
+
+        let mut ix_builder = MigrateAccountBuilder::new();
+        ix_builder.account(account);
+        let mut ix = ix_builder.instruction();
+        ix.program_id = self.vault_whitelist_program_id;
+
+        info!("Migrating account {}", account);
+
+        self.process_transaction(&[ix], &fee_payer.pubkey(), &[fee_payer])?;
+
+        Ok(())
+    }
 }
 
 /// Handle Vault Whitelist Whitelist
 impl VaultWhitelistCliHandler {
     /// Initialize Whitelist
-    pub fn initialize_whitelist(&self, vault: Pubkey) -> anyhow::Result<()> {
+    pub fn initialize_whitelist(
+        &self,
+        vault: Pubkey,
+        name: Option<String>,
+        kyc_provider: Option<Pubkey>,
+        uri: Option<String>,
+    ) -> anyhow::Result<()> {
         let signer = self.signer()?;
         let admin = signer.pubkey();
 
@@ -184,6 +384,8 @@ impl VaultWhitelistCliHandler {
             &vault,
         )
         .0;
+        let whitelist_metadata =
+            WhitelistMetadata::find_program_address(&self.vault_whitelist_program_id, &whitelist).0;
 
         let mut ix_builder = InitializeWhitelistBuilder::new();
         ix_builder
@@ -195,7 +397,12 @@ impl VaultWhitelistCliHandler {
             )
             .whitelist(whitelist)
             .vault(vault)
-            .vault_admin(admin);
+            .vault_admin(admin)
+            .payer(self.fee_payer()?.pubkey())
+            .kyc_provider(kyc_provider.unwrap_or_else(solana_sdk::system_program::id))
+            .whitelist_metadata(whitelist_metadata)
+            .name(Self::padded_bytes("name", &name.unwrap_or_default())?)
+            .uri(Self::padded_bytes("uri", &uri.unwrap_or_default())?);
 
         let mut ix = ix_builder.instruction();
         ix.program_id = self.vault_whitelist_program_id;
@@ -203,18 +410,30 @@ impl VaultWhitelistCliHandler {
         info!("Initializing Whitelist at address: {}", whitelist);
 
         let ixs = [ix];
-        self.process_transaction(&ixs, &signer.pubkey(), &[signer])?;
+        self.process_transaction_with_fee_payer(&ixs, signer)?;
 
         if !self.print_tx {
             let account =
                 self.get_account::<jito_vault_whitelist_client::accounts::Whitelist>(&whitelist)?;
             info!("{}", account.pretty_display());
+            let account = self
+                .get_account::<jito_vault_whitelist_client::accounts::WhitelistMetadata>(
+                    &whitelist_metadata,
+                )?;
+            info!("{}", account.pretty_display());
         }
 
         Ok(())
     }
 
-    pub fn set_mint_burn_admin(&self, vault: Pubkey) -> anyhow::Result<()> {
+    /// Set Whitelist Metadata, keeping the current value of omitted fields
+    pub fn set_whitelist_metadata(
+        &self,
+        vault: Pubkey,
+        name: Option<String>,
+        kyc_provider: Option<Pubkey>,
+        uri: Option<String>,
+    ) -> anyhow::Result<()> {
         let signer = self.signer()?;
         let admin = signer.pubkey();
 
@@ -223,8 +442,30 @@ impl VaultWhitelistCliHandler {
             &vault,
         )
         .0;
-
-        let mut ix_builder = SetMintBurnAdminBuilder::new();
+        let whitelist_metadata =
+            WhitelistMetadata::find_program_address(&self.vault_whitelist_program_id, &whitelist).0;
+
+        // Whitelists created before metadata existed have no WhitelistMetadata account yet
+        let (current_name, current_kyc_provider, current_uri) =
+            match self.get_whitelist_metadata(&whitelist_metadata)? {
+                Some(account) => (account.name, account.kyc_provider, account.uri),
+                None => (
+                    [0; jito_vault_whitelist_core::whitelist_metadata::WHITELIST_NAME_LEN],
+                    solana_sdk::system_program::id(),
+                    [0; jito_vault_whitelist_core::whitelist_metadata::WHITELIST_URI_LEN],
+                ),
+            };
+
+        let name = match name {
+            Some(name) => Self::padded_bytes("name", &name)?,
+            None => current_name,
+        };
+        let uri = match uri {
+            Some(uri) => Self::padded_bytes("uri", &uri)?,
+            None => current_uri,
+        };
+
+        let mut ix_builder = SetWhitelistMetadataBuilder::new();
         ix_builder
             .config(
                 jito_vault_whitelist_core::config::Config::find_program_address(
@@ -232,36 +473,96 @@ impl VaultWhitelistCliHandler {
                 )
                 .0,
             )
-            .vault_config(
-                jito_vault_core::config::Config::find_program_address(&self.vault_program_id).0,
-            )
-            .whitelist(whitelist)
             .vault(vault)
+            .whitelist(whitelist)
+            .whitelist_metadata(whitelist_metadata)
             .vault_admin(admin)
-            .jito_vault_program(self.vault_program_id);
+            .payer(self.fee_payer()?.pubkey())
+            .kyc_provider(kyc_provider.unwrap_or(current_kyc_provider))
+            .name(name)
+            .uri(uri);
 
         let mut ix = ix_builder.instruction();
         ix.program_id = self.vault_whitelist_program_id;
 
-        info!("Setting Mint Burn Admin");
+        info!("Setting metadata of Whitelist {}", whitelist);
 
         let ixs = [ix];
-        self.process_transaction(&ixs, &signer.pubkey(), &[signer])?;
+        self.process_transaction_with_fee_payer(&ixs, signer)?;
 
         if !self.print_tx {
-            let account =
-                self.get_account::<jito_vault_whitelist_client::accounts::Whitelist>(&whitelist)?;
+            let account = self
+                .get_account::<jito_vault_whitelist_client::accounts::WhitelistMetadata>(
+                    &whitelist_metadata,
+                )?;
             info!("{}", account.pretty_display());
         }
 
         Ok(())
     }
 
-    /// Add to whitelist
-    pub fn add_to_whitelist(&self, vault: Pubkey, user: Pubkey) -> anyhow::Result<()> {
-        let signer = self.signer()?;
-        let admin = signer.pubkey();
+    /// Encodes `value` as UTF-8 padded with zeros to `N` bytes
+    fn padded_bytes<const N: usize>(field: &str, value: &str) -> anyhow::Result<[u8; N]> {
+        let bytes = value.as_bytes();
+        if bytes.len() > N {
+            return Err(anyhow!("The {} is longer than {} bytes", field, N));
+        }
+
+        let mut padded = [0; N];
+        padded[..bytes.len()].copy_from_slice(bytes);
+
+        Ok(padded)
+    }
+
+    /// Get Whitelist
+    pub fn get_whitelist(&self, vault: Pubkey) -> anyhow::Result<()> {
+        let whitelist = jito_vault_whitelist_core::whitelist::Whitelist::find_program_address(
+            &self.vault_whitelist_program_id,
+            &vault,
+        )
+        .0;
+
+        debug!(
+            "Reading the jito vault whitelist account at address: {}",
+            whitelist
+        );
+
+        let account =
+            self.get_account::<jito_vault_whitelist_client::accounts::Whitelist>(&whitelist)?;
+        info!("Whitelist at address {}", whitelist);
+        info!("{}", account.pretty_display());
+
+        let whitelist_metadata =
+            WhitelistMetadata::find_program_address(&self.vault_whitelist_program_id, &whitelist).0;
+        if let Some(account) = self.get_whitelist_metadata(&whitelist_metadata)? {
+            info!("Whitelist metadata at address {}", whitelist_metadata);
+            info!("{}", account.pretty_display());
+        }
+
+        Ok(())
+    }
+
+    /// Fetches the WhitelistMetadata at `whitelist_metadata`, `None` if it was never created
+    fn get_whitelist_metadata(
+        &self,
+        whitelist_metadata: &Pubkey,
+    ) -> anyhow::Result<Option<jito_vault_whitelist_client::accounts::WhitelistMetadata>> {
+        let account = self
+            .get_rpc_client()
+            .get_account_with_commitment(whitelist_metadata, self.cli_config.commitment)?
+            .value;
+
+        account
+            .map(|account| {
+                jito_vault_whitelist_client::accounts::WhitelistMetadata::deserialize(
+                    &mut account.data.as_slice(),
+                )
+            })
+            .transpose()
+            .map_err(Into::into)
+    }
 
+    pub fn get_whitelist_user(&self, vault: Pubkey, user: Pubkey) -> anyhow::Result<()> {
         let whitelist = jito_vault_whitelist_core::whitelist::Whitelist::find_program_address(
             &self.vault_whitelist_program_id,
             &vault,
@@ -275,7 +576,30 @@ impl VaultWhitelistCliHandler {
             )
             .0;
 
-        let mut ix_builder = AddToWhitelistBuilder::new();
+        debug!(
+            "Reading the jito vault whitelist user account at address: {}",
+            whitelist_user
+        );
+
+        let account = self
+            .get_account::<jito_vault_whitelist_client::accounts::WhitelistUser>(&whitelist_user)?;
+        info!("Whitelist user at address {}", whitelist_user);
+        info!("{}", account.pretty_display());
+
+        Ok(())
+    }
+
+    pub fn set_mint_burn_admin(&self, vault: Pubkey) -> anyhow::Result<()> {
+        let signer = self.signer()?;
+        let admin = signer.pubkey();
+
+        let whitelist = jito_vault_whitelist_core::whitelist::Whitelist::find_program_address(
+            &self.vault_whitelist_program_id,
+            &vault,
+        )
+        .0;
+
+        let mut ix_builder = SetMintBurnAdminBuilder::new();
         ix_builder
             .config(
                 jito_vault_whitelist_core::config::Config::find_program_address(
@@ -283,16 +607,18 @@ impl VaultWhitelistCliHandler {
                 )
                 .0,
             )
+            .vault_config(
+                jito_vault_core::config::Config::find_program_address(&self.vault_program_id).0,
+            )
             .whitelist(whitelist)
-            .whitelist_user(whitelist_user)
             .vault(vault)
             .vault_admin(admin)
-            .user(user);
+            .jito_vault_program(self.vault_program_id);
 
         let mut ix = ix_builder.instruction();
         ix.program_id = self.vault_whitelist_program_id;
 
-        info!("Adding to whitelist");
+        info!("Setting Mint Burn Admin");
 
         let ixs = [ix];
         self.process_transaction(&ixs, &signer.pubkey(), &[signer])?;
@@ -306,8 +632,46 @@ impl VaultWhitelistCliHandler {
         Ok(())
     }
 
-    /// Remove from whitelist
-    pub fn remove_from_whitelist(&self, vault: Pubkey, user: Pubkey) -> anyhow::Result<()> {
+    /// Add to whitelist
+    pub fn add_to_whitelist(
+        &self,
+        vault: Pubkey,
+        user: Pubkey,
+        reference: Option<String>,
+    ) -> anyhow::Result<()> {
+        let signer = self.signer()?;
+        let admin = signer.pubkey();
+
+        let whitelist = jito_vault_whitelist_core::whitelist::Whitelist::find_program_address(
+            &self.vault_whitelist_program_id,
+            &vault,
+        )
+        .0;
+
+        let reference = Self::parse_reference(&reference.unwrap_or_default())?;
+        let ix = self.add_to_whitelist_ix(vault, whitelist, admin, user, reference)?;
+
+        info!("Adding to whitelist");
+
+        let ixs = [ix];
+        self.process_transaction_with_fee_payer(&ixs, signer)?;
+
+        if !self.print_tx {
+            let account =
+                self.get_account::<jito_vault_whitelist_client::accounts::Whitelist>(&whitelist)?;
+            info!("{}", account.pretty_display());
+        }
+
+        Ok(())
+    }
+
+    /// Remove from whitelist, refunding rent to `rent_receiver` or the recorded payer
+    pub fn remove_from_whitelist(
+        &self,
+        vault: Pubkey,
+        user: Pubkey,
+        rent_receiver: Option<Pubkey>,
+    ) -> anyhow::Result<()> {
         let signer = self.signer()?;
         let admin = signer.pubkey();
 
@@ -324,6 +688,17 @@ impl VaultWhitelistCliHandler {
             )
             .0;
 
+        let rent_receiver = match rent_receiver {
+            Some(rent_receiver) => rent_receiver,
+            None => {
+                let whitelist_user_acc = self
+                    .get_account::<jito_vault_whitelist_client::accounts::WhitelistUser>(
+                    &whitelist_user,
+                )?;
+                Self::recorded_payer(whitelist_user_acc.payer, admin)
+            }
+        };
+
         let mut ix_builder = RemoveFromWhitelistBuilder::new();
         ix_builder
             .config(
@@ -336,6 +711,7 @@ impl VaultWhitelistCliHandler {
             .whitelist_user(whitelist_user)
             .vault(vault)
             .vault_admin(admin)
+            .rent_receiver(rent_receiver)
             .user(user);
 
         let mut ix = ix_builder.instruction();
@@ -344,74 +720,23 @@ impl VaultWhitelistCliHandler {
         info!("Remove from whitelist");
 
         let ixs = [ix];
-        self.process_transaction(&ixs, &signer.pubkey(), &[signer])?;
+        self.process_transaction_with_fee_payer(&ixs, signer)?;
 
         Ok(())
     }
 
-    pub fn mint(
-        &self,
-        signer_keypair_path: PathBuf,
-        vault_pubkey: Pubkey,
-        amount_in: u64,
-        min_amount_out: u64,
-    ) -> anyhow::Result<()> {
-        let signer_keypair = read_keypair_file(signer_keypair_path)
-            .map_err(|e| anyhow!("Failed to read signer keypair: {}", e))?;
-        let signer = CliSigner::new(Some(signer_keypair), None);
+    /// Set whether the whitelist is an allow list or a deny list
+    pub fn set_whitelist_mode(&self, vault: Pubkey, mode: WhitelistMode) -> anyhow::Result<()> {
+        let signer = self.signer()?;
+        let admin = signer.pubkey();
 
         let whitelist = jito_vault_whitelist_core::whitelist::Whitelist::find_program_address(
             &self.vault_whitelist_program_id,
-            &vault_pubkey,
+            &vault,
         )
         .0;
-        let whitelist_user =
-            jito_vault_whitelist_core::whitelist_user::WhitelistUser::find_program_address(
-                &self.vault_whitelist_program_id,
-                &whitelist,
-                &signer.pubkey(),
-            )
-            .0;
-
-        let vault = self.get_account::<jito_vault_client::accounts::Vault>(&vault_pubkey)?;
-
-        let depositor = signer.pubkey();
-        let depositor_token_account =
-            get_associated_token_address(&depositor, &vault.supported_mint);
-        let depositor_vrt_token_account = get_associated_token_address(&depositor, &vault.vrt_mint);
-
-        let vault_token_account =
-            get_associated_token_address(&vault_pubkey, &vault.supported_mint);
-
-        let vault_fee_token_account =
-            get_associated_token_address(&vault.fee_wallet, &vault.vrt_mint);
-
-        let depositor_ata_ix = create_associated_token_account_idempotent(
-            &depositor,
-            &depositor,
-            &vault.supported_mint,
-            &spl_token::ID,
-        );
-        let depositor_vrt_ata_ix = create_associated_token_account_idempotent(
-            &depositor,
-            &depositor,
-            &vault.vrt_mint,
-            &spl_token::ID,
-        );
-        let vault_ata_ix = create_associated_token_account_idempotent(
-            &depositor,
-            &vault_pubkey,
-            &vault.supported_mint,
-            &spl_token::ID,
-        );
-        let vault_fee_ata_ix = create_associated_token_account_idempotent(
-            &depositor,
-            &vault.fee_wallet,
-            &vault.vrt_mint,
-            &spl_token::ID,
-        );
 
-        let mut ix_builder = MintBuilder::new();
+        let mut ix_builder = SetWhitelistModeBuilder::new();
         ix_builder
             .config(
                 jito_vault_whitelist_core::config::Config::find_program_address(
@@ -419,34 +744,17 @@ impl VaultWhitelistCliHandler {
                 )
                 .0,
             )
-            .vault_config(
-                jito_vault_core::config::Config::find_program_address(&self.vault_program_id).0,
-            )
-            .vault(vault_pubkey)
-            .vrt_mint(vault.vrt_mint)
-            .depositor(depositor)
-            .depositor_token_account(depositor_token_account)
-            .vault_token_account(vault_token_account)
-            .depositor_vrt_token_account(depositor_vrt_token_account)
-            .vault_fee_token_account(vault_fee_token_account)
+            .vault(vault)
             .whitelist(whitelist)
-            .whitelist_user(whitelist_user)
-            .jito_vault_program(self.vault_program_id)
-            .amount_in(amount_in)
-            .min_amount_out(min_amount_out);
+            .vault_admin(admin)
+            .mode(mode as u8);
 
         let mut ix = ix_builder.instruction();
         ix.program_id = self.vault_whitelist_program_id;
 
-        info!("Minting tokens");
+        info!("Setting whitelist mode to {:?}", mode);
 
-        let ixs = [
-            depositor_ata_ix,
-            depositor_vrt_ata_ix,
-            vault_ata_ix,
-            vault_fee_ata_ix,
-            ix,
-        ];
+        let ixs = [ix];
         self.process_transaction(&ixs, &signer.pubkey(), &[signer])?;
 
         if !self.print_tx {
@@ -458,49 +766,1045 @@ impl VaultWhitelistCliHandler {
         Ok(())
     }
 
-    pub fn enqueue_withdrawal(
-        &self,
-        signer_keypair_path: PathBuf,
-        vault_pubkey: Pubkey,
-        amount: u64,
-    ) -> anyhow::Result<()> {
-        let signer_keypair = read_keypair_file(signer_keypair_path)
-            .map_err(|e| anyhow!("Failed to read signer keypair: {}", e))?;
-        let signer = CliSigner::new(Some(signer_keypair), None);
+    /// Set the lockup between mint and enqueue withdrawal
+    pub fn set_lockup(&self, vault: Pubkey, lockup_slots: u64) -> anyhow::Result<()> {
+        let signer = self.signer()?;
+        let admin = signer.pubkey();
+
+        let whitelist = jito_vault_whitelist_core::whitelist::Whitelist::find_program_address(
+            &self.vault_whitelist_program_id,
+            &vault,
+        )
+        .0;
+
+        let mut ix_builder = SetLockupBuilder::new();
+        ix_builder
+            .config(
+                jito_vault_whitelist_core::config::Config::find_program_address(
+                    &self.vault_whitelist_program_id,
+                )
+                .0,
+            )
+            .vault(vault)
+            .whitelist(whitelist)
+            .vault_admin(admin)
+            .lockup_slots(lockup_slots);
+
+        let mut ix = ix_builder.instruction();
+        ix.program_id = self.vault_whitelist_program_id;
+
+        info!("Setting lockup to {} slots", lockup_slots);
+
+        let ixs = [ix];
+        self.process_transaction(&ixs, &signer.pubkey(), &[signer])?;
+
+        if !self.print_tx {
+            let account =
+                self.get_account::<jito_vault_whitelist_client::accounts::Whitelist>(&whitelist)?;
+            info!("{}", account.pretty_display());
+        }
+
+        Ok(())
+    }
+
+    /// Set the key allowed to burn withdrawal tickets on behalf of stakers
+    pub fn set_burn_crank(&self, vault: Pubkey, burn_crank: Option<Pubkey>) -> anyhow::Result<()> {
+        let signer = self.signer()?;
+        let admin = signer.pubkey();
+
+        let whitelist = jito_vault_whitelist_core::whitelist::Whitelist::find_program_address(
+            &self.vault_whitelist_program_id,
+            &vault,
+        )
+        .0;
+
+        // The system program is the default pubkey, which disables the burn crank
+        let burn_crank = burn_crank.unwrap_or_else(solana_sdk::system_program::id);
+
+        let mut ix_builder = SetBurnCrankBuilder::new();
+        ix_builder
+            .config(
+                jito_vault_whitelist_core::config::Config::find_program_address(
+                    &self.vault_whitelist_program_id,
+                )
+                .0,
+            )
+            .vault(vault)
+            .whitelist(whitelist)
+            .vault_admin(admin)
+            .burn_crank(burn_crank);
+
+        let mut ix = ix_builder.instruction();
+        ix.program_id = self.vault_whitelist_program_id;
+
+        info!("Setting burn crank to {}", burn_crank);
+
+        let ixs = [ix];
+        self.process_transaction(&ixs, &signer.pubkey(), &[signer])?;
+
+        if !self.print_tx {
+            let account =
+                self.get_account::<jito_vault_whitelist_client::accounts::Whitelist>(&whitelist)?;
+            info!("{}", account.pretty_display());
+        }
+
+        Ok(())
+    }
+
+    /// Set who may burn the signer's withdrawal tickets on the signer's behalf
+    pub fn set_burn_delegate(
+        &self,
+        vault: Pubkey,
+        burn_delegate: Option<Pubkey>,
+        permissionless: bool,
+    ) -> anyhow::Result<()> {
+        let signer = self.signer()?;
+        let user = signer.pubkey();
+
+        let whitelist = jito_vault_whitelist_core::whitelist::Whitelist::find_program_address(
+            &self.vault_whitelist_program_id,
+            &vault,
+        )
+        .0;
+        let whitelist_user = WhitelistUser::find_program_address(
+            &self.vault_whitelist_program_id,
+            &whitelist,
+            &user,
+        )
+        .0;
+
+        // The system program is the default pubkey, which clears the burn delegate
+        let burn_delegate = burn_delegate.unwrap_or_else(solana_sdk::system_program::id);
+
+        let mut ix_builder = SetBurnDelegateBuilder::new();
+        ix_builder
+            .config(
+                jito_vault_whitelist_core::config::Config::find_program_address(
+                    &self.vault_whitelist_program_id,
+                )
+                .0,
+            )
+            .vault(vault)
+            .whitelist(whitelist)
+            .whitelist_user(whitelist_user)
+            .user(user)
+            .burn_delegate(burn_delegate)
+            .permissionless(permissionless);
+
+        let mut ix = ix_builder.instruction();
+        ix.program_id = self.vault_whitelist_program_id;
+
+        info!(
+            "Setting burn delegate of {} to {}, permissionless {}",
+            user, burn_delegate, permissionless
+        );
+
+        let ixs = [ix];
+        self.process_transaction_with_fee_payer(&ixs, signer)?;
+
+        if !self.print_tx {
+            let account = self
+                .get_account::<jito_vault_whitelist_client::accounts::WhitelistUser>(
+                    &whitelist_user,
+                )?;
+            info!("{}", account.pretty_display());
+        }
+
+        Ok(())
+    }
+
+    /// Set the rate limits on minting and enqueueing withdrawals
+    pub fn set_rate_limit(
+        &self,
+        vault: Pubkey,
+        rate_limit_epochs: u64,
+        mint_limit: u64,
+        enqueue_limit: u64,
+        user_mint_limit: u64,
+        user_enqueue_limit: u64,
+    ) -> anyhow::Result<()> {
+        let signer = self.signer()?;
+        let admin = signer.pubkey();
+
+        let whitelist = jito_vault_whitelist_core::whitelist::Whitelist::find_program_address(
+            &self.vault_whitelist_program_id,
+            &vault,
+        )
+        .0;
+
+        let mut ix_builder = SetRateLimitBuilder::new();
+        ix_builder
+            .config(
+                jito_vault_whitelist_core::config::Config::find_program_address(
+                    &self.vault_whitelist_program_id,
+                )
+                .0,
+            )
+            .vault(vault)
+            .whitelist(whitelist)
+            .vault_admin(admin)
+            .rate_limit_epochs(rate_limit_epochs)
+            .mint_limit(mint_limit)
+            .enqueue_limit(enqueue_limit)
+            .user_mint_limit(user_mint_limit)
+            .user_enqueue_limit(user_enqueue_limit);
+
+        let mut ix = ix_builder.instruction();
+        ix.program_id = self.vault_whitelist_program_id;
+
+        info!("Setting rate limit window to {} epochs", rate_limit_epochs);
+
+        let ixs = [ix];
+        self.process_transaction(&ixs, &signer.pubkey(), &[signer])?;
+
+        if !self.print_tx {
+            let account =
+                self.get_account::<jito_vault_whitelist_client::accounts::Whitelist>(&whitelist)?;
+            info!("{}", account.pretty_display());
+        }
+
+        Ok(())
+    }
+
+    /// Set the deposit limits of the whitelist
+    pub fn set_deposit_limits(
+        &self,
+        vault: Pubkey,
+        min_deposit: Option<UiAmount>,
+        max_deposit: Option<UiAmount>,
+    ) -> anyhow::Result<()> {
+        let signer = self.signer()?;
+        let admin = signer.pubkey();
+
+        let (supported_decimals, min_deposit, max_deposit) =
+            self.deposit_limits_base_units(&vault, min_deposit, max_deposit)?;
+
+        let whitelist = jito_vault_whitelist_core::whitelist::Whitelist::find_program_address(
+            &self.vault_whitelist_program_id,
+            &vault,
+        )
+        .0;
+
+        let mut ix_builder = SetDepositLimitsBuilder::new();
+        ix_builder
+            .config(
+                jito_vault_whitelist_core::config::Config::find_program_address(
+                    &self.vault_whitelist_program_id,
+                )
+                .0,
+            )
+            .vault(vault)
+            .whitelist(whitelist)
+            .vault_admin(admin)
+            .min_deposit(min_deposit)
+            .max_deposit(max_deposit);
+
+        let mut ix = ix_builder.instruction();
+        ix.program_id = self.vault_whitelist_program_id;
+
+        info!(
+            "Setting deposit limits to min {}, max {}",
+            format_ui_amount(min_deposit, supported_decimals),
+            format_ui_amount(max_deposit, supported_decimals)
+        );
+
+        let ixs = [ix];
+        self.process_transaction(&ixs, &signer.pubkey(), &[signer])?;
+
+        if !self.print_tx {
+            let account =
+                self.get_account::<jito_vault_whitelist_client::accounts::Whitelist>(&whitelist)?;
+            info!("{}", account.pretty_display());
+        }
+
+        Ok(())
+    }
+
+    /// Converts deposit limits to base units of the vault's supported mint, returning its decimals
+    /// alongside. An omitted limit is zero, which disables it
+    fn deposit_limits_base_units(
+        &self,
+        vault: &Pubkey,
+        min_deposit: Option<UiAmount>,
+        max_deposit: Option<UiAmount>,
+    ) -> anyhow::Result<(u8, u64, u64)> {
+        let vault = self.get_account::<jito_vault_client::accounts::Vault>(vault)?;
+        let supported_decimals = self.get_mint_decimals(&vault.supported_mint)?;
+
+        let to_base_units = |limit: Option<UiAmount>| {
+            limit.map_or(Ok(0), |limit| limit.to_base_units_exact(supported_decimals))
+        };
+
+        Ok((
+            supported_decimals,
+            to_base_units(min_deposit)?,
+            to_base_units(max_deposit)?,
+        ))
+    }
+
+    /// Set the deposit limits of a whitelisted user
+    pub fn set_user_deposit_limits(
+        &self,
+        vault: Pubkey,
+        user: Pubkey,
+        min_deposit: Option<UiAmount>,
+        max_deposit: Option<UiAmount>,
+    ) -> anyhow::Result<()> {
+        let signer = self.signer()?;
+        let admin = signer.pubkey();
+
+        let (supported_decimals, min_deposit, max_deposit) =
+            self.deposit_limits_base_units(&vault, min_deposit, max_deposit)?;
+
+        let whitelist = jito_vault_whitelist_core::whitelist::Whitelist::find_program_address(
+            &self.vault_whitelist_program_id,
+            &vault,
+        )
+        .0;
+        let whitelist_user = WhitelistUser::find_program_address(
+            &self.vault_whitelist_program_id,
+            &whitelist,
+            &user,
+        )
+        .0;
+
+        let mut ix_builder = SetUserDepositLimitsBuilder::new();
+        ix_builder
+            .config(
+                jito_vault_whitelist_core::config::Config::find_program_address(
+                    &self.vault_whitelist_program_id,
+                )
+                .0,
+            )
+            .vault(vault)
+            .whitelist(whitelist)
+            .whitelist_user(whitelist_user)
+            .vault_admin(admin)
+            .user(user)
+            .min_deposit(min_deposit)
+            .max_deposit(max_deposit);
+
+        let mut ix = ix_builder.instruction();
+        ix.program_id = self.vault_whitelist_program_id;
+
+        info!(
+            "Setting deposit limits of {} to min {}, max {}",
+            user,
+            format_ui_amount(min_deposit, supported_decimals),
+            format_ui_amount(max_deposit, supported_decimals)
+        );
+
+        let ixs = [ix];
+        self.process_transaction(&ixs, &signer.pubkey(), &[signer])?;
+
+        if !self.print_tx {
+            let account = self
+                .get_account::<jito_vault_whitelist_client::accounts::WhitelistUser>(
+                    &whitelist_user,
+                )?;
+            info!("{}", account.pretty_display());
+        }
+
+        Ok(())
+    }
+
+    /// Set whether a whitelisted user is a custodian
+    pub fn set_custodian(
+        &self,
+        vault: Pubkey,
+        user: Pubkey,
+        custodian: bool,
+    ) -> anyhow::Result<()> {
+        let signer = self.signer()?;
+        let admin = signer.pubkey();
+
+        let whitelist = jito_vault_whitelist_core::whitelist::Whitelist::find_program_address(
+            &self.vault_whitelist_program_id,
+            &vault,
+        )
+        .0;
+        let whitelist_user = WhitelistUser::find_program_address(
+            &self.vault_whitelist_program_id,
+            &whitelist,
+            &user,
+        )
+        .0;
+
+        let mut ix_builder = SetCustodianBuilder::new();
+        ix_builder
+            .config(
+                jito_vault_whitelist_core::config::Config::find_program_address(
+                    &self.vault_whitelist_program_id,
+                )
+                .0,
+            )
+            .vault(vault)
+            .whitelist(whitelist)
+            .whitelist_user(whitelist_user)
+            .vault_admin(admin)
+            .user(user)
+            .custodian(custodian);
+
+        let mut ix = ix_builder.instruction();
+        ix.program_id = self.vault_whitelist_program_id;
+
+        info!("Setting custodian of {} to {}", user, custodian);
+
+        let ixs = [ix];
+        self.process_transaction(&ixs, &signer.pubkey(), &[signer])?;
+
+        if !self.print_tx {
+            let account = self
+                .get_account::<jito_vault_whitelist_client::accounts::WhitelistUser>(
+                    &whitelist_user,
+                )?;
+            info!("{}", account.pretty_display());
+        }
+
+        Ok(())
+    }
+
+    /// Set the tag linking a whitelisted user to an off-chain record, clearing it when omitted
+    pub fn set_user_reference(
+        &self,
+        vault: Pubkey,
+        user: Pubkey,
+        reference: Option<String>,
+    ) -> anyhow::Result<()> {
+        let signer = self.signer()?;
+        let admin = signer.pubkey();
+
+        let whitelist = jito_vault_whitelist_core::whitelist::Whitelist::find_program_address(
+            &self.vault_whitelist_program_id,
+            &vault,
+        )
+        .0;
+        let whitelist_user = WhitelistUser::find_program_address(
+            &self.vault_whitelist_program_id,
+            &whitelist,
+            &user,
+        )
+        .0;
+
+        let reference = Self::parse_reference(&reference.unwrap_or_default())?;
+
+        let mut ix_builder = SetUserReferenceBuilder::new();
+        ix_builder
+            .config(
+                jito_vault_whitelist_core::config::Config::find_program_address(
+                    &self.vault_whitelist_program_id,
+                )
+                .0,
+            )
+            .vault(vault)
+            .whitelist(whitelist)
+            .whitelist_user(whitelist_user)
+            .vault_admin(admin)
+            .user(user)
+            .reference(reference);
+
+        let mut ix = ix_builder.instruction();
+        ix.program_id = self.vault_whitelist_program_id;
+
+        info!("Setting reference of {}", user);
+
+        let ixs = [ix];
+        self.process_transaction(&ixs, &signer.pubkey(), &[signer])?;
+
+        if !self.print_tx {
+            let account = self
+                .get_account::<jito_vault_whitelist_client::accounts::WhitelistUser>(
+                    &whitelist_user,
+                )?;
+            info!("{}", account.pretty_display());
+        }
+
+        Ok(())
+    }
+
+    /// Add to blacklist
+    pub fn add_to_blacklist(&self, vault: Pubkey, user: Pubkey) -> anyhow::Result<()> {
+        let signer = self.signer()?;
+        let admin = signer.pubkey();
+
+        let whitelist = jito_vault_whitelist_core::whitelist::Whitelist::find_program_address(
+            &self.vault_whitelist_program_id,
+            &vault,
+        )
+        .0;
+
+        let ix = self.add_to_blacklist_ix(vault, whitelist, admin, user)?;
+
+        info!("Adding to blacklist");
+
+        let ixs = [ix];
+        self.process_transaction_with_fee_payer(&ixs, signer)?;
+
+        Ok(())
+    }
+
+    /// Remove from blacklist, refunding rent to `rent_receiver` or the recorded payer
+    pub fn remove_from_blacklist(
+        &self,
+        vault: Pubkey,
+        user: Pubkey,
+        rent_receiver: Option<Pubkey>,
+    ) -> anyhow::Result<()> {
+        let signer = self.signer()?;
+        let admin = signer.pubkey();
+
+        let whitelist = jito_vault_whitelist_core::whitelist::Whitelist::find_program_address(
+            &self.vault_whitelist_program_id,
+            &vault,
+        )
+        .0;
+        let blacklisted =
+            Blacklisted::find_program_address(&self.vault_whitelist_program_id, &whitelist, &user)
+                .0;
+
+        let rent_receiver = match rent_receiver {
+            Some(rent_receiver) => rent_receiver,
+            None => {
+                self.get_account::<jito_vault_whitelist_client::accounts::Blacklisted>(
+                    &blacklisted,
+                )?
+                .payer
+            }
+        };
+
+        let mut ix_builder = RemoveFromBlacklistBuilder::new();
+        ix_builder
+            .config(
+                jito_vault_whitelist_core::config::Config::find_program_address(
+                    &self.vault_whitelist_program_id,
+                )
+                .0,
+            )
+            .vault(vault)
+            .whitelist(whitelist)
+            .blacklisted(blacklisted)
+            .vault_admin(admin)
+            .rent_receiver(rent_receiver)
+            .user(user);
+
+        let mut ix = ix_builder.instruction();
+        ix.program_id = self.vault_whitelist_program_id;
+
+        info!("Remove from blacklist");
+
+        let ixs = [ix];
+        self.process_transaction(&ixs, &signer.pubkey(), &[signer])?;
+
+        Ok(())
+    }
+
+    /// Add every user listed in `path` to the whitelist, or to the blacklist when `blacklist` is
+    /// set, skipping users already on it in the current whitelist generation
+    pub fn import_users(
+        &self,
+        vault: Pubkey,
+        path: PathBuf,
+        blacklist: bool,
+    ) -> anyhow::Result<()> {
+        let signer = self.signer()?;
+        let admin = signer.pubkey();
+        let rpc_client = self.get_rpc_client();
+
+        let whitelist = jito_vault_whitelist_core::whitelist::Whitelist::find_program_address(
+            &self.vault_whitelist_program_id,
+            &vault,
+        )
+        .0;
+
+        let whitelist_acc = rpc_client.get_account(&whitelist)?;
+        let generation = jito_vault_whitelist_core::whitelist::Whitelist::try_from_slice_unchecked(
+            &whitelist_acc.data,
+        )?
+        .generation();
+
+        let users = Self::read_users(&path)?;
+
+        let mut ixs = Vec::with_capacity(users.len());
+        for users in users.chunks(MAX_MULTIPLE_ACCOUNTS) {
+            let addresses: Vec<Pubkey> = users
+                .iter()
+                .map(|(user, _)| self.list_entry_address(&whitelist, user, blacklist))
+                .collect();
+            let accounts = rpc_client.get_multiple_accounts(&addresses)?;
+
+            for ((user, reference), account) in users.iter().zip(accounts) {
+                // Entries of an earlier whitelist at the same PDA are reinitialized on import
+                let listed = match account {
+                    Some(account) if blacklist => {
+                        Blacklisted::try_from_slice_unchecked(&account.data)?.generation()
+                            == generation
+                    }
+                    Some(account) => {
+                        WhitelistUser::try_from_slice_unchecked(&account.data)?.generation()
+                            == generation
+                    }
+                    None => false,
+                };
+                if listed {
+                    debug!("Skipping {}, already listed", user);
+                    continue;
+                }
+
+                let ix = if blacklist {
+                    self.add_to_blacklist_ix(vault, whitelist, admin, *user)?
+                } else {
+                    self.add_to_whitelist_ix(vault, whitelist, admin, *user, *reference)?
+                };
+                ixs.push(ix);
+            }
+        }
+
+        info!(
+            "Importing {} of {} users from {}",
+            ixs.len(),
+            users.len(),
+            path.display()
+        );
+
+        for ixs in ixs.chunks(IMPORT_USERS_PER_TX) {
+            self.process_transaction_with_fee_payer(ixs, signer)?;
+        }
+
+        Ok(())
+    }
+
+    /// List the users on the whitelist, or on the blacklist when `blacklist` is set, in the format
+    /// read by `import`
+    pub fn list_users(&self, vault: Pubkey, blacklist: bool) -> anyhow::Result<()> {
+        let rpc_client = self.get_rpc_client();
+
+        let whitelist = jito_vault_whitelist_core::whitelist::Whitelist::find_program_address(
+            &self.vault_whitelist_program_id,
+            &vault,
+        )
+        .0;
+
+        let whitelist_acc = rpc_client.get_account(&whitelist)?;
+        let generation = jito_vault_whitelist_core::whitelist::Whitelist::try_from_slice_unchecked(
+            &whitelist_acc.data,
+        )?
+        .generation();
+
+        // Entries of an earlier whitelist at the same PDA are not listed
+        let mut users = if blacklist {
+            let config =
+                self.get_rpc_program_accounts_config::<Blacklisted>(Some((&whitelist, 8)))?;
+            let accounts = rpc_client
+                .get_program_accounts_with_config(&self.vault_whitelist_program_id, config)?;
+
+            let mut users = Vec::with_capacity(accounts.len());
+            for (_, account) in accounts {
+                let blacklisted = Blacklisted::try_from_slice_unchecked(&account.data)?;
+
+                if blacklisted.generation() == generation {
+                    users.push((blacklisted.user, None));
+                }
+            }
+            users
+        } else {
+            let config =
+                self.get_rpc_program_accounts_config::<WhitelistUser>(Some((&whitelist, 8)))?;
+            let accounts = rpc_client
+                .get_program_accounts_with_config(&self.vault_whitelist_program_id, config)?;
+
+            let mut users = Vec::with_capacity(accounts.len());
+            for (_, account) in accounts {
+                let whitelist_user = WhitelistUser::try_from_slice_unchecked(&account.data)?;
+
+                if whitelist_user.generation() == generation {
+                    users.push((whitelist_user.user, Some(*whitelist_user.reference())));
+                }
+            }
+            users
+        };
+        users.sort();
+
+        info!("{} users", users.len());
+        info!("{}", if blacklist { "user" } else { "user,reference" });
+        for (user, reference) in users {
+            match reference {
+                Some(reference) => info!("{},{}", user, Self::format_reference(&reference)),
+                None => info!("{}", user),
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Mint VRT on behalf of a whitelisted beneficiary
+    pub fn mint_for(
+        &self,
+        vault_pubkey: Pubkey,
+        beneficiary: Pubkey,
+        amount_in: UiAmount,
+        min_amount_out: Option<UiAmount>,
+        slippage_bps: Option<u16>,
+    ) -> anyhow::Result<()> {
+        let signer = self.signer()?;
+        let fee_payer = self.fee_payer()?.pubkey();
+
+        let whitelist = jito_vault_whitelist_core::whitelist::Whitelist::find_program_address(
+            &self.vault_whitelist_program_id,
+            &vault_pubkey,
+        )
+        .0;
+        let whitelist_user = WhitelistUser::find_program_address(
+            &self.vault_whitelist_program_id,
+            &whitelist,
+            &signer.pubkey(),
+        )
+        .0;
+        let beneficiary_whitelist_user = WhitelistUser::find_program_address(
+            &self.vault_whitelist_program_id,
+            &whitelist,
+            &beneficiary,
+        )
+        .0;
+
+        let vault = self.get_account::<jito_vault_client::accounts::Vault>(&vault_pubkey)?;
+        let supported_token_program = self.get_token_program_id(&vault.supported_mint)?;
+        let vrt_token_program = self.get_token_program_id(&vault.vrt_mint)?;
+
+        let depositor = signer.pubkey();
+        let depositor_token_account = get_associated_token_address_with_program_id(
+            &depositor,
+            &vault.supported_mint,
+            &supported_token_program,
+        );
+        let beneficiary_vrt_token_account = get_associated_token_address_with_program_id(
+            &beneficiary,
+            &vault.vrt_mint,
+            &vrt_token_program,
+        );
+
+        let supported_decimals = self.get_mint_decimals(&vault.supported_mint)?;
+        let vrt_decimals = self.get_mint_decimals(&vault.vrt_mint)?;
+        let balances = [
+            (
+                "supported token",
+                depositor_token_account,
+                supported_decimals,
+            ),
+            ("VRT", beneficiary_vrt_token_account, vrt_decimals),
+        ];
+        self.log_ui_balances("before", &balances)?;
+
+        let amount_in = amount_in.to_base_units(
+            supported_decimals,
+            self.get_token_balance(&depositor_token_account)?,
+        )?;
+        let min_amount_out = min_amount_out
+            .map(|min_amount_out| min_amount_out.to_base_units_exact(vrt_decimals))
+            .transpose()?;
+        let min_amount_out = Self::resolve_min_amount_out(
+            min_amount_out,
+            slippage_bps,
+            quote::quote_mint(&vault, amount_in),
+        )?
+        .ok_or_else(|| anyhow!("Pass min_amount_out or --slippage-bps"))?;
+
+        let vault_token_account = get_associated_token_address_with_program_id(
+            &vault_pubkey,
+            &vault.supported_mint,
+            &supported_token_program,
+        );
+
+        let vault_fee_token_account = get_associated_token_address_with_program_id(
+            &vault.fee_wallet,
+            &vault.vrt_mint,
+            &vrt_token_program,
+        );
+
+        let beneficiary_vrt_ata_ix = create_associated_token_account_idempotent(
+            &fee_payer,
+            &beneficiary,
+            &vault.vrt_mint,
+            &vrt_token_program,
+        );
+        let vault_ata_ix = create_associated_token_account_idempotent(
+            &fee_payer,
+            &vault_pubkey,
+            &vault.supported_mint,
+            &supported_token_program,
+        );
+        let vault_fee_ata_ix = create_associated_token_account_idempotent(
+            &fee_payer,
+            &vault.fee_wallet,
+            &vault.vrt_mint,
+            &vrt_token_program,
+        );
+
+        let mut ix_builder = MintForBuilder::new();
+        ix_builder
+            .config(
+                jito_vault_whitelist_core::config::Config::find_program_address(
+                    &self.vault_whitelist_program_id,
+                )
+                .0,
+            )
+            .vault_config(
+                jito_vault_core::config::Config::find_program_address(&self.vault_program_id).0,
+            )
+            .vault(vault_pubkey)
+            .vrt_mint(vault.vrt_mint)
+            .depositor(depositor)
+            .depositor_token_account(depositor_token_account)
+            .vault_token_account(vault_token_account)
+            .depositor_vrt_token_account(beneficiary_vrt_token_account)
+            .vault_fee_token_account(vault_fee_token_account)
+            .whitelist(whitelist)
+            .whitelist_user(whitelist_user)
+            .jito_vault_program(self.vault_program_id)
+            .token_program(supported_token_program)
+            .beneficiary(beneficiary)
+            .beneficiary_whitelist_user(beneficiary_whitelist_user)
+            .amount_in(amount_in)
+            .min_amount_out(min_amount_out);
+
+        let mut ix = ix_builder.instruction();
+        ix.program_id = self.vault_whitelist_program_id;
+
+        info!(
+            "Minting tokens for {}: amount_in = {}, min_amount_out = {}",
+            beneficiary,
+            format_ui_amount(amount_in, supported_decimals),
+            format_ui_amount(min_amount_out, vrt_decimals)
+        );
+
+        let ixs = [beneficiary_vrt_ata_ix, vault_ata_ix, vault_fee_ata_ix, ix];
+        self.process_transaction_with_fee_payer(&ixs, signer)?;
+
+        if !self.print_tx {
+            self.log_ui_balances("after", &balances)?;
+
+            let account = self
+                .get_account::<jito_vault_whitelist_client::accounts::WhitelistUser>(
+                    &beneficiary_whitelist_user,
+                )?;
+            info!("{}", account.pretty_display());
+        }
+
+        Ok(())
+    }
+
+    pub fn mint(
+        &self,
+        vault_pubkey: Pubkey,
+        amount_in: UiAmount,
+        min_amount_out: Option<UiAmount>,
+        slippage_bps: Option<u16>,
+    ) -> anyhow::Result<()> {
+        let signer = self.signer()?;
+        let fee_payer = self.fee_payer()?.pubkey();
+
+        let whitelist = jito_vault_whitelist_core::whitelist::Whitelist::find_program_address(
+            &self.vault_whitelist_program_id,
+            &vault_pubkey,
+        )
+        .0;
+        let whitelist_user = self.whitelist_user_address(&whitelist, &signer.pubkey())?;
+
+        let vault = self.get_account::<jito_vault_client::accounts::Vault>(&vault_pubkey)?;
+        let supported_token_program = self.get_token_program_id(&vault.supported_mint)?;
+        let vrt_token_program = self.get_token_program_id(&vault.vrt_mint)?;
+
+        let depositor = signer.pubkey();
+        let depositor_token_account = get_associated_token_address_with_program_id(
+            &depositor,
+            &vault.supported_mint,
+            &supported_token_program,
+        );
+        let depositor_vrt_token_account = get_associated_token_address_with_program_id(
+            &depositor,
+            &vault.vrt_mint,
+            &vrt_token_program,
+        );
+
+        let supported_decimals = self.get_mint_decimals(&vault.supported_mint)?;
+        let vrt_decimals = self.get_mint_decimals(&vault.vrt_mint)?;
+        let balances = [
+            (
+                "supported token",
+                depositor_token_account,
+                supported_decimals,
+            ),
+            ("VRT", depositor_vrt_token_account, vrt_decimals),
+        ];
+        self.log_ui_balances("before", &balances)?;
+
+        let amount_in = amount_in.to_base_units(
+            supported_decimals,
+            self.get_token_balance(&depositor_token_account)?,
+        )?;
+        let min_amount_out = min_amount_out
+            .map(|min_amount_out| min_amount_out.to_base_units_exact(vrt_decimals))
+            .transpose()?;
+        let min_amount_out = Self::resolve_min_amount_out(
+            min_amount_out,
+            slippage_bps,
+            quote::quote_mint(&vault, amount_in),
+        )?
+        .ok_or_else(|| anyhow!("Pass min_amount_out or --slippage-bps"))?;
+
+        let vault_token_account = get_associated_token_address_with_program_id(
+            &vault_pubkey,
+            &vault.supported_mint,
+            &supported_token_program,
+        );
+
+        let vault_fee_token_account = get_associated_token_address_with_program_id(
+            &vault.fee_wallet,
+            &vault.vrt_mint,
+            &vrt_token_program,
+        );
+
+        let depositor_ata_ix = create_associated_token_account_idempotent(
+            &fee_payer,
+            &depositor,
+            &vault.supported_mint,
+            &supported_token_program,
+        );
+        let depositor_vrt_ata_ix = create_associated_token_account_idempotent(
+            &fee_payer,
+            &depositor,
+            &vault.vrt_mint,
+            &vrt_token_program,
+        );
+        let vault_ata_ix = create_associated_token_account_idempotent(
+            &fee_payer,
+            &vault_pubkey,
+            &vault.supported_mint,
+            &supported_token_program,
+        );
+        let vault_fee_ata_ix = create_associated_token_account_idempotent(
+            &fee_payer,
+            &vault.fee_wallet,
+            &vault.vrt_mint,
+            &vrt_token_program,
+        );
+
+        let mut ix_builder = MintBuilder::new();
+        ix_builder
+            .config(
+                jito_vault_whitelist_core::config::Config::find_program_address(
+                    &self.vault_whitelist_program_id,
+                )
+                .0,
+            )
+            .vault_config(
+                jito_vault_core::config::Config::find_program_address(&self.vault_program_id).0,
+            )
+            .vault(vault_pubkey)
+            .vrt_mint(vault.vrt_mint)
+            .depositor(depositor)
+            .depositor_token_account(depositor_token_account)
+            .vault_token_account(vault_token_account)
+            .depositor_vrt_token_account(depositor_vrt_token_account)
+            .vault_fee_token_account(vault_fee_token_account)
+            .whitelist(whitelist)
+            .whitelist_user(whitelist_user)
+            .jito_vault_program(self.vault_program_id)
+            .token_program(supported_token_program)
+            .amount_in(amount_in)
+            .min_amount_out(min_amount_out);
+
+        let mut ix = ix_builder.instruction();
+        ix.program_id = self.vault_whitelist_program_id;
+
+        info!(
+            "Minting tokens: amount_in = {}, min_amount_out = {}",
+            format_ui_amount(amount_in, supported_decimals),
+            format_ui_amount(min_amount_out, vrt_decimals)
+        );
+
+        let ixs = [
+            depositor_ata_ix,
+            depositor_vrt_ata_ix,
+            vault_ata_ix,
+            vault_fee_ata_ix,
+            ix,
+        ];
+        self.process_transaction_with_fee_payer(&ixs, signer)?;
+
+        if !self.print_tx {
+            self.log_ui_balances("after", &balances)?;
+
+            let account =
+                self.get_account::<jito_vault_whitelist_client::accounts::Whitelist>(&whitelist)?;
+            info!("{}", account.pretty_display());
+        }
+
+        Ok(())
+    }
+
+    pub fn enqueue_withdrawal(
+        &self,
+        vault_pubkey: Pubkey,
+        amount: UiAmount,
+        base: Option<String>,
+    ) -> anyhow::Result<()> {
+        let signer = self.signer()?;
+        let fee_payer = self.fee_payer()?;
+
+        // Each ticket is derived from its own base, so a staker can hold several at once
+        let base = match base {
+            Some(source) => CliSigner::new_from_source(&source)?,
+            // A generated base is lost once printed, so the printed transaction could not be signed
+            None if self.print_tx => {
+                return Err(anyhow!("--base is required with --print-tx"));
+            }
+            None => CliSigner::new_keypair(Keypair::new()),
+        };
 
         let whitelist = jito_vault_whitelist_core::whitelist::Whitelist::find_program_address(
             &self.vault_whitelist_program_id,
             &vault_pubkey,
         )
         .0;
-        let whitelist_user =
-            jito_vault_whitelist_core::whitelist_user::WhitelistUser::find_program_address(
-                &self.vault_whitelist_program_id,
-                &whitelist,
-                &signer.pubkey(),
-            )
-            .0;
+        let whitelist_user = self.whitelist_user_address(&whitelist, &signer.pubkey())?;
 
         let vault = self.get_account::<jito_vault_client::accounts::Vault>(&vault_pubkey)?;
+        let vrt_token_program = self.get_token_program_id(&vault.vrt_mint)?;
 
         let vault_staker_withdrawal_ticket = VaultStakerWithdrawalTicket::find_program_address(
             &self.vault_program_id,
             &vault_pubkey,
-            &signer.pubkey(),
+            &base.pubkey(),
         )
         .0;
 
         let vault_staker_withdrawal_ticket_token_account =
-            get_associated_token_address(&vault_staker_withdrawal_ticket, &vault.vrt_mint);
+            get_associated_token_address_with_program_id(
+                &vault_staker_withdrawal_ticket,
+                &vault.vrt_mint,
+                &vrt_token_program,
+            );
+
+        let staker_vrt_token_account = get_associated_token_address_with_program_id(
+            &signer.pubkey(),
+            &vault.vrt_mint,
+            &vrt_token_program,
+        );
+
+        let vrt_decimals = self.get_mint_decimals(&vault.vrt_mint)?;
+        let balances = [("VRT", staker_vrt_token_account, vrt_decimals)];
+        self.log_ui_balances("before", &balances)?;
 
-        let staker_vrt_token_account =
-            get_associated_token_address(&signer.pubkey(), &vault.vrt_mint);
+        let amount = amount.to_base_units(
+            vrt_decimals,
+            self.get_token_balance(&staker_vrt_token_account)?,
+        )?;
 
         let vault_staker_withdrawal_ticket_ata_ix = create_associated_token_account_idempotent(
-            &signer.pubkey(),
+            &fee_payer.pubkey(),
             &vault_staker_withdrawal_ticket,
             &vault.vrt_mint,
-            &spl_token::ID,
+            &vrt_token_program,
         );
 
         let mut ix_builder = EnqueueWithdrawalBuilder::new();
@@ -521,24 +1825,33 @@ impl VaultWhitelistCliHandler {
             )
             .staker(signer.pubkey())
             .staker_vrt_token_account(staker_vrt_token_account)
-            .base(signer.pubkey())
+            .base(base.pubkey())
             .whitelist(whitelist)
             .whitelist_user(whitelist_user)
             .jito_vault_program(self.vault_program_id)
+            .token_program(vrt_token_program)
             .amount(amount);
 
         let mut ix = ix_builder.instruction();
         ix.program_id = self.vault_whitelist_program_id;
 
         info!(
-            "Enqueueing withdrawal: amount = {}, vault_id = {}",
-            amount, vault_pubkey
+            "Enqueueing withdrawal: amount = {}, vault_id = {}, ticket = {}",
+            format_ui_amount(amount, vrt_decimals),
+            vault_pubkey,
+            vault_staker_withdrawal_ticket
         );
 
         let ixs = [vault_staker_withdrawal_ticket_ata_ix, ix];
-        self.process_transaction(&ixs, &signer.pubkey(), &[signer])?;
+        if fee_payer.pubkey().eq(&signer.pubkey()) {
+            self.process_transaction(&ixs, &signer.pubkey(), &[signer, &base])?;
+        } else {
+            self.process_transaction(&ixs, &fee_payer.pubkey(), &[signer, fee_payer, &base])?;
+        }
 
         if !self.print_tx {
+            self.log_ui_balances("after", &balances)?;
+
             let account =
                 self.get_account::<jito_vault_whitelist_client::accounts::Whitelist>(&whitelist)?;
             info!("{}", account.pretty_display());
@@ -547,61 +1860,250 @@ impl VaultWhitelistCliHandler {
         Ok(())
     }
 
+    /// Fetch the outstanding withdrawal tickets of `staker` on `vault`, oldest first
+    fn staker_withdrawal_tickets(
+        &self,
+        vault: &Pubkey,
+        staker: &Pubkey,
+    ) -> anyhow::Result<Vec<(Pubkey, VaultStakerWithdrawalTicket)>> {
+        let rpc_client = self.get_rpc_client();
+
+        let config =
+            self.get_rpc_program_accounts_config::<VaultStakerWithdrawalTicket>(Some((
+                staker,
+                8 + std::mem::offset_of!(VaultStakerWithdrawalTicket, staker),
+            )))?;
+        let accounts =
+            rpc_client.get_program_accounts_with_config(&self.vault_program_id, config)?;
+
+        let mut tickets = Vec::with_capacity(accounts.len());
+        for (ticket_pubkey, account) in accounts {
+            let ticket = *VaultStakerWithdrawalTicket::try_from_slice_unchecked(&account.data)?;
+            if ticket.vault.eq(vault) {
+                tickets.push((ticket_pubkey, ticket));
+            }
+        }
+        tickets.sort_by_key(|(_, ticket)| ticket.slot_unstaked());
+
+        Ok(tickets)
+    }
+
+    /// Log the balance of each `(name, token_account, decimals)` in UI units
+    fn log_ui_balances(&self, when: &str, balances: &[(&str, Pubkey, u8)]) -> anyhow::Result<()> {
+        for (name, token_account, decimals) in balances {
+            let balance = self.get_token_balance(token_account)?;
+            info!(
+                "{} balance {}: {}",
+                name,
+                when,
+                format_ui_amount(balance, *decimals)
+            );
+        }
+
+        Ok(())
+    }
+
+    /// Returns `min_amount_out` if given, otherwise `quoted` less `slippage_bps` if given
+    fn resolve_min_amount_out(
+        min_amount_out: Option<u64>,
+        slippage_bps: Option<u16>,
+        quoted: Option<u64>,
+    ) -> anyhow::Result<Option<u64>> {
+        match (min_amount_out, slippage_bps) {
+            (Some(min_amount_out), _) => Ok(Some(min_amount_out)),
+            (None, Some(slippage_bps)) => {
+                let quoted = quoted.ok_or_else(|| anyhow!("Failed to quote the vault"))?;
+                let min_amount_out = quote::min_amount_out(quoted, slippage_bps)
+                    .ok_or_else(|| anyhow!("Slippage must be at most 10000 bps"))?;
+                info!(
+                    "Quoted {} at {} bps slippage, min_amount_out {}",
+                    quoted, slippage_bps, min_amount_out
+                );
+                Ok(Some(min_amount_out))
+            }
+            (None, None) => Ok(None),
+        }
+    }
+
+    /// List outstanding withdrawal tickets of `staker`, defaulting to the signer
+    pub fn list_withdrawal_tickets(
+        &self,
+        vault_pubkey: Pubkey,
+        staker: Option<Pubkey>,
+    ) -> anyhow::Result<()> {
+        let staker = match staker {
+            Some(staker) => staker,
+            None => self.signer()?.pubkey(),
+        };
+
+        let rpc_client = self.get_rpc_client();
+
+        let vault = self.get_account::<jito_vault_client::accounts::Vault>(&vault_pubkey)?;
+
+        let config = Config::find_program_address(&self.vault_program_id).0;
+        let config_account_raw = rpc_client.get_account(&config)?;
+        let config_account = jito_vault_client::accounts::Config::deserialize(
+            &mut config_account_raw.data.as_slice(),
+        )?;
+        let epoch_length = config_account.epoch_length;
+
+        let current_epoch = rpc_client
+            .get_slot()?
+            .checked_div(epoch_length)
+            .ok_or_else(|| anyhow!("Vault config epoch length is zero"))?;
+
+        let vrt_decimals = self.get_mint_decimals(&vault.vrt_mint)?;
+        let supported_decimals = self.get_mint_decimals(&vault.supported_mint)?;
+
+        let tickets = self.staker_withdrawal_tickets(&vault_pubkey, &staker)?;
+
+        info!("{} tickets, current epoch {}", tickets.len(), current_epoch);
+        info!("ticket,base,vrt_amount,slot_unstaked,burnable_epoch,estimated_payout,ready");
+        for (ticket_pubkey, ticket) in tickets {
+            let burnable_epoch =
+                withdrawal_ticket::burnable_epoch(ticket.slot_unstaked(), epoch_length)
+                    .ok_or_else(|| anyhow!("Failed to compute burnable epoch"))?;
+
+            let estimated_payout =
+                quote::quote_withdrawal(&vault, &config_account, ticket.vrt_amount())
+                    .ok_or_else(|| anyhow!("Failed to estimate payout"))?;
+
+            info!(
+                "{},{},{},{},{},{},{}",
+                ticket_pubkey,
+                ticket.base,
+                format_ui_amount(ticket.vrt_amount(), vrt_decimals),
+                ticket.slot_unstaked(),
+                burnable_epoch,
+                format_ui_amount(estimated_payout, supported_decimals),
+                current_epoch >= burnable_epoch
+            );
+        }
+
+        Ok(())
+    }
+
     pub fn burn_withdrawal_ticket(
         &self,
-        signer_keypair_path: PathBuf,
         vault_pubkey: Pubkey,
+        ticket: Option<Pubkey>,
+        min_amount_out: Option<UiAmount>,
+        slippage_bps: Option<u16>,
     ) -> anyhow::Result<()> {
-        let signer_keypair = read_keypair_file(signer_keypair_path)
-            .map_err(|e| anyhow!("Failed to read signer keypair: {}", e))?;
-        let signer = CliSigner::new(Some(signer_keypair), None);
+        let signer = self.signer()?;
+        let fee_payer = self.fee_payer()?.pubkey();
 
         let whitelist = jito_vault_whitelist_core::whitelist::Whitelist::find_program_address(
             &self.vault_whitelist_program_id,
             &vault_pubkey,
         )
         .0;
-        let whitelist_user =
-            jito_vault_whitelist_core::whitelist_user::WhitelistUser::find_program_address(
-                &self.vault_whitelist_program_id,
-                &whitelist,
-                &signer.pubkey(),
-            )
-            .0;
+        let whitelist_user = self.whitelist_user_address(&whitelist, &signer.pubkey())?;
 
         let vault = self.get_account::<jito_vault_client::accounts::Vault>(&vault_pubkey)?;
+        let supported_token_program = self.get_token_program_id(&vault.supported_mint)?;
+        let vrt_token_program = self.get_token_program_id(&vault.vrt_mint)?;
 
         let staker = signer.pubkey();
-        let staker_token_account = get_associated_token_address(&staker, &vault.supported_mint);
-
-        let vault_token_account =
-            get_associated_token_address(&vault_pubkey, &vault.supported_mint);
+        let staker_token_account = get_associated_token_address_with_program_id(
+            &staker,
+            &vault.supported_mint,
+            &supported_token_program,
+        );
 
-        let vault_fee_token_account =
-            get_associated_token_address(&vault.fee_wallet, &vault.vrt_mint);
-        let vault_staker_withdrawal_ticket = VaultStakerWithdrawalTicket::find_program_address(
-            &self.vault_program_id,
+        let vault_token_account = get_associated_token_address_with_program_id(
             &vault_pubkey,
-            &signer.pubkey(),
-        )
-        .0;
+            &vault.supported_mint,
+            &supported_token_program,
+        );
+
+        let vault_fee_token_account = get_associated_token_address_with_program_id(
+            &vault.fee_wallet,
+            &vault.vrt_mint,
+            &vrt_token_program,
+        );
+        let (vault_staker_withdrawal_ticket, vrt_amount) = match ticket {
+            Some(ticket) => {
+                let account = self.get_rpc_client().get_account(&ticket)?;
+                let vault_staker_withdrawal_ticket =
+                    VaultStakerWithdrawalTicket::try_from_slice_unchecked(&account.data)?;
+                if vault_staker_withdrawal_ticket.vault.ne(&vault_pubkey)
+                    || vault_staker_withdrawal_ticket.staker.ne(&staker)
+                {
+                    return Err(anyhow!(
+                        "Withdrawal ticket {} does not belong to staker {} on vault {}",
+                        ticket,
+                        staker,
+                        vault_pubkey
+                    ));
+                }
+                (ticket, vault_staker_withdrawal_ticket.vrt_amount())
+            }
+            None => {
+                let tickets = self.staker_withdrawal_tickets(&vault_pubkey, &staker)?;
+                match tickets.as_slice() {
+                    [(ticket, vault_staker_withdrawal_ticket)] => {
+                        (*ticket, vault_staker_withdrawal_ticket.vrt_amount())
+                    }
+                    [] => {
+                        return Err(anyhow!(
+                            "Staker {} has no withdrawal tickets on vault {}",
+                            staker,
+                            vault_pubkey
+                        ))
+                    }
+                    _ => {
+                        return Err(anyhow!(
+                        "Staker {} has {} withdrawal tickets on vault {}, choose one with --ticket",
+                        staker,
+                        tickets.len(),
+                        vault_pubkey
+                    ))
+                    }
+                }
+            }
+        };
 
         let vault_staker_withdrawal_ticket_token_account =
-            get_associated_token_address(&vault_staker_withdrawal_ticket, &vault.vrt_mint);
+            get_associated_token_address_with_program_id(
+                &vault_staker_withdrawal_ticket,
+                &vault.vrt_mint,
+                &vrt_token_program,
+            );
 
         let config = Config::find_program_address(&self.vault_program_id).0;
         let config_account_raw = self.get_rpc_client().get_account(&config)?;
         let config_account = Config::try_from_slice_unchecked(&config_account_raw.data)?;
+        let quote_config = jito_vault_client::accounts::Config::deserialize(
+            &mut config_account_raw.data.as_slice(),
+        )?;
+
+        let supported_decimals = self.get_mint_decimals(&vault.supported_mint)?;
+        let balances = [("supported token", staker_token_account, supported_decimals)];
+        self.log_ui_balances("before", &balances)?;
+
+        let min_amount_out = min_amount_out
+            .map(|min_amount_out| min_amount_out.to_base_units_exact(supported_decimals))
+            .transpose()?;
+        let min_amount_out = Self::resolve_min_amount_out(
+            min_amount_out,
+            slippage_bps,
+            quote::quote_withdrawal(&vault, &quote_config, vrt_amount),
+        )?
+        .unwrap_or(0);
 
         let program_fee_ata_ix = create_associated_token_account_idempotent(
-            &signer.pubkey(),
+            &fee_payer,
             &config_account.program_fee_wallet,
             &vault.vrt_mint,
-            &spl_token::ID,
+            &vrt_token_program,
         );
 
-        let program_fee_token_account =
-            get_associated_token_address(&config_account.program_fee_wallet, &vault.vrt_mint);
+        let program_fee_token_account = get_associated_token_address_with_program_id(
+            &config_account.program_fee_wallet,
+            &vault.vrt_mint,
+            &vrt_token_program,
+        );
 
         let mut ix_builder = BurnWithdrawalTicketBuilder::new();
         ix_builder
@@ -627,20 +2129,25 @@ impl VaultWhitelistCliHandler {
             )
             .whitelist(whitelist)
             .whitelist_user(whitelist_user)
-            .jito_vault_program(self.vault_program_id);
+            .jito_vault_program(self.vault_program_id)
+            // Forwarded to the Jito Vault burn, which burns the ticket's VRT with it
+            .token_program(vrt_token_program)
+            .min_amount_out(min_amount_out);
 
         let mut ix = ix_builder.instruction();
         ix.program_id = self.vault_whitelist_program_id;
 
         info!(
-            "Burning withdrawal ticket for vault: {}, staker: {}, staker token account: {}",
-            vault_pubkey, staker, staker_token_account
+            "Burning withdrawal ticket {} for vault: {}, staker: {}, staker token account: {}",
+            vault_staker_withdrawal_ticket, vault_pubkey, staker, staker_token_account
         );
 
         let ixs = [program_fee_ata_ix, ix];
-        self.process_transaction(&ixs, &signer.pubkey(), &[signer])?;
+        self.process_transaction_with_fee_payer(&ixs, signer)?;
 
         if !self.print_tx {
+            self.log_ui_balances("after", &balances)?;
+
             let account =
                 self.get_account::<jito_vault_whitelist_client::accounts::Whitelist>(&whitelist)?;
             info!("{}", account.pretty_display());
@@ -650,7 +2157,11 @@ impl VaultWhitelistCliHandler {
     }
 
     /// Close whitelist
-    pub fn close_whitelist(&self, vault: Pubkey) -> anyhow::Result<()> {
+    pub fn close_whitelist(
+        &self,
+        vault: Pubkey,
+        rent_receiver: Option<Pubkey>,
+    ) -> anyhow::Result<()> {
         let signer = self.signer()?;
         let admin = signer.pubkey();
 
@@ -660,6 +2171,15 @@ impl VaultWhitelistCliHandler {
         )
         .0;
 
+        let rent_receiver = match rent_receiver {
+            Some(rent_receiver) => rent_receiver,
+            None => {
+                let whitelist_acc = self
+                    .get_account::<jito_vault_whitelist_client::accounts::Whitelist>(&whitelist)?;
+                Self::recorded_payer(whitelist_acc.payer, admin)
+            }
+        };
+
         let mut ix_builder = CloseWhitelistBuilder::new();
         ix_builder
             .config(
@@ -674,6 +2194,7 @@ impl VaultWhitelistCliHandler {
             .whitelist(whitelist)
             .vault(vault)
             .vault_admin(admin)
+            .rent_receiver(rent_receiver)
             .jito_vault_program(self.vault_program_id);
 
         let mut ix = ix_builder.instruction();
@@ -686,4 +2207,224 @@ impl VaultWhitelistCliHandler {
 
         Ok(())
     }
+
+    /// Close every WhitelistUser of a closed or re-created whitelist
+    pub fn close_orphaned_whitelist_users(&self, vault_pubkey: Pubkey) -> anyhow::Result<()> {
+        let fee_payer = self.fee_payer()?;
+        let rpc_client = self.get_rpc_client();
+
+        let whitelist = jito_vault_whitelist_core::whitelist::Whitelist::find_program_address(
+            &self.vault_whitelist_program_id,
+            &vault_pubkey,
+        )
+        .0;
+
+        // Users of a re-created whitelist stay valid only when they match its generation
+        let whitelist_generation = rpc_client
+            .get_account_with_commitment(&whitelist, rpc_client.commitment())?
+            .value
+            .map(|account| {
+                jito_vault_whitelist_core::whitelist::Whitelist::try_from_slice_unchecked(
+                    &account.data,
+                )
+                .map(|whitelist| whitelist.generation())
+            })
+            .transpose()?;
+
+        let vault = self.get_account::<jito_vault_client::accounts::Vault>(&vault_pubkey)?;
+
+        let config =
+            self.get_rpc_program_accounts_config::<WhitelistUser>(Some((&whitelist, 8)))?;
+        let accounts = rpc_client
+            .get_program_accounts_with_config(&self.vault_whitelist_program_id, config)?;
+
+        let mut ixs = Vec::with_capacity(accounts.len());
+        for (whitelist_user_pubkey, account) in accounts {
+            let whitelist_user = WhitelistUser::try_from_slice_unchecked(&account.data)?;
+            if whitelist_generation.eq(&Some(whitelist_user.generation())) {
+                continue;
+            }
+
+            let mut ix_builder = CloseOrphanedWhitelistUserBuilder::new();
+            ix_builder
+                .config(
+                    jito_vault_whitelist_core::config::Config::find_program_address(
+                        &self.vault_whitelist_program_id,
+                    )
+                    .0,
+                )
+                .vault(vault_pubkey)
+                .whitelist(whitelist)
+                .whitelist_user(whitelist_user_pubkey)
+                .rent_receiver(Self::recorded_payer(whitelist_user.payer, vault.admin))
+                .user(whitelist_user.user);
+
+            let mut ix = ix_builder.instruction();
+            ix.program_id = self.vault_whitelist_program_id;
+
+            ixs.push(ix);
+        }
+
+        info!("Found {} orphaned WhitelistUser accounts", ixs.len());
+
+        for ixs in ixs.chunks(CLOSE_ORPHANED_WHITELIST_USERS_PER_TX) {
+            self.process_transaction(ixs, &fee_payer.pubkey(), &[fee_payer])?;
+        }
+
+        Ok(())
+    }
+
+    /// Returns the payer recorded on an account, falling back to the vault admin for accounts
+    /// created before the payer was recorded.
+    fn recorded_payer(payer: Pubkey, vault_admin: Pubkey) -> Pubkey {
+        if payer.eq(&Pubkey::default()) {
+            vault_admin
+        } else {
+            payer
+        }
+    }
+
+    /// Builds an `AddToWhitelist` instruction with rent paid by the fee payer
+    fn add_to_whitelist_ix(
+        &self,
+        vault: Pubkey,
+        whitelist: Pubkey,
+        admin: Pubkey,
+        user: Pubkey,
+        reference: [u8; WHITELIST_USER_REFERENCE_LEN],
+    ) -> anyhow::Result<Instruction> {
+        let whitelist_user = WhitelistUser::find_program_address(
+            &self.vault_whitelist_program_id,
+            &whitelist,
+            &user,
+        )
+        .0;
+
+        let mut ix_builder = AddToWhitelistBuilder::new();
+        ix_builder
+            .config(
+                jito_vault_whitelist_core::config::Config::find_program_address(
+                    &self.vault_whitelist_program_id,
+                )
+                .0,
+            )
+            .whitelist(whitelist)
+            .whitelist_user(whitelist_user)
+            .vault(vault)
+            .vault_admin(admin)
+            .payer(self.fee_payer()?.pubkey())
+            .user(user)
+            .reference(reference);
+
+        let mut ix = ix_builder.instruction();
+        ix.program_id = self.vault_whitelist_program_id;
+
+        Ok(ix)
+    }
+
+    /// Builds an `AddToBlacklist` instruction with rent paid by the fee payer
+    fn add_to_blacklist_ix(
+        &self,
+        vault: Pubkey,
+        whitelist: Pubkey,
+        admin: Pubkey,
+        user: Pubkey,
+    ) -> anyhow::Result<Instruction> {
+        let blacklisted =
+            Blacklisted::find_program_address(&self.vault_whitelist_program_id, &whitelist, &user)
+                .0;
+
+        let mut ix_builder = AddToBlacklistBuilder::new();
+        ix_builder
+            .config(
+                jito_vault_whitelist_core::config::Config::find_program_address(
+                    &self.vault_whitelist_program_id,
+                )
+                .0,
+            )
+            .vault(vault)
+            .whitelist(whitelist)
+            .blacklisted(blacklisted)
+            .vault_admin(admin)
+            .payer(self.fee_payer()?.pubkey())
+            .user(user);
+
+        let mut ix = ix_builder.instruction();
+        ix.program_id = self.vault_whitelist_program_id;
+
+        Ok(ix)
+    }
+
+    /// Returns the address of `user`'s WhitelistUser, or its Blacklisted PDA when `blacklist` is set
+    fn list_entry_address(&self, whitelist: &Pubkey, user: &Pubkey, blacklist: bool) -> Pubkey {
+        if blacklist {
+            Blacklisted::find_program_address(&self.vault_whitelist_program_id, whitelist, user).0
+        } else {
+            WhitelistUser::find_program_address(&self.vault_whitelist_program_id, whitelist, user).0
+        }
+    }
+
+    /// Returns the account passed as `whitelist_user` to mint and redeem: the user's
+    /// WhitelistUser in allow-list mode, or the user's Blacklisted PDA in deny-list mode
+    fn whitelist_user_address(&self, whitelist: &Pubkey, user: &Pubkey) -> anyhow::Result<Pubkey> {
+        let whitelist_acc =
+            self.get_account::<jito_vault_whitelist_client::accounts::Whitelist>(whitelist)?;
+        let deny_list = whitelist_acc.mode == WhitelistMode::DenyList as u8;
+
+        Ok(self.list_entry_address(whitelist, user, deny_list))
+    }
+
+    /// Reads user addresses and references from the first two columns of a CSV file
+    ///
+    /// Blank lines, `#` comments and a `user` header row are skipped. A missing or empty
+    /// reference column leaves the reference unset.
+    fn read_users(
+        path: &Path,
+    ) -> anyhow::Result<Vec<(Pubkey, [u8; WHITELIST_USER_REFERENCE_LEN])>> {
+        let contents = std::fs::read_to_string(path)?;
+
+        let mut users = Vec::new();
+        for line in contents.lines().map(str::trim) {
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let mut columns = line.split(',').map(str::trim);
+            let user = columns.next().unwrap_or_default();
+            if user.eq_ignore_ascii_case("user") {
+                continue;
+            }
+
+            let user =
+                Pubkey::from_str(user).map_err(|e| anyhow!("Invalid user address {user}: {e}"))?;
+            let reference = Self::parse_reference(columns.next().unwrap_or_default())?;
+            users.push((user, reference));
+        }
+
+        Ok(users)
+    }
+
+    /// Parses a user reference from 64 hex characters, an empty string leaving it unset
+    fn parse_reference(reference: &str) -> anyhow::Result<[u8; WHITELIST_USER_REFERENCE_LEN]> {
+        let mut bytes = [0; WHITELIST_USER_REFERENCE_LEN];
+        if !reference.is_empty() {
+            hex::decode_to_slice(reference, &mut bytes).map_err(|e| {
+                anyhow!(
+                    "Invalid reference {reference}, expected {} hex bytes: {e}",
+                    WHITELIST_USER_REFERENCE_LEN
+                )
+            })?;
+        }
+
+        Ok(bytes)
+    }
+
+    /// Formats a user reference as hex, an unset reference as an empty string
+    fn format_reference(reference: &[u8; WHITELIST_USER_REFERENCE_LEN]) -> String {
+        if reference.iter().all(|b| *b == 0) {
+            String::new()
+        } else {
+            hex::encode(reference)
+        }
+    }
 }
@@ -5,10 +5,14 @@
 //! <https://github.com/codama-idl/codama>
 //!
 
+pub(crate) mod r#blacklisted;
 pub(crate) mod r#config;
 pub(crate) mod r#whitelist;
+pub(crate) mod r#whitelist_metadata;
 pub(crate) mod r#whitelist_user;
 
+pub use self::r#blacklisted::*;
 pub use self::r#config::*;
 pub use self::r#whitelist::*;
+pub use self::r#whitelist_metadata::*;
 pub use self::r#whitelist_user::*;
@@ -12,18 +12,47 @@ use solana_program::pubkey::Pubkey;
 #[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Whitelist {
+    pub discriminator: u64,
     #[cfg_attr(
         feature = "serde",
         serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
     )]
     pub vault: Pubkey,
     pub bump: u8,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub payer: Pubkey,
+    pub generation: u64,
+    pub user_count: u64,
+    pub total_minted_in: u64,
+    pub total_vrt_enqueued: u64,
+    pub total_tickets_burned: u64,
+    pub mode: u8,
+    pub lockup_slots: u64,
+    pub rate_limit_epochs: u64,
+    pub mint_limit: u64,
+    pub enqueue_limit: u64,
+    pub user_mint_limit: u64,
+    pub user_enqueue_limit: u64,
+    pub window_start_epoch: u64,
+    pub window_minted: u64,
+    pub window_enqueued: u64,
+    pub min_deposit: u64,
+    pub max_deposit: u64,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub burn_crank: Pubkey,
+    pub version: u8,
     #[cfg_attr(feature = "serde", serde(with = "serde_with::As::<serde_with::Bytes>"))]
-    pub reserved: [u8; 263],
+    pub reserved: [u8; 69],
 }
 
 impl Whitelist {
-    pub const LEN: usize = 296;
+    pub const LEN: usize = 304;
 
     #[inline(always)]
     pub fn from_bytes(data: &[u8]) -> Result<Self, std::io::Error> {
@@ -12,6 +12,7 @@ use solana_program::pubkey::Pubkey;
 #[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct WhitelistUser {
+    pub discriminator: u64,
     #[cfg_attr(
         feature = "serde",
         serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
@@ -23,12 +24,38 @@ pub struct WhitelistUser {
     )]
     pub user: Pubkey,
     pub bump: u8,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub payer: Pubkey,
+    pub generation: u64,
+    pub total_deposited: u64,
+    pub total_vrt_minted: u64,
+    pub total_vrt_enqueued: u64,
+    pub total_tokens_withdrawn: u64,
+    pub last_activity_slot: u64,
+    pub last_mint_slot: u64,
+    pub window_start_epoch: u64,
+    pub window_minted: u64,
+    pub window_enqueued: u64,
+    pub min_deposit: u64,
+    pub max_deposit: u64,
+    pub custodian: bool,
+    pub permissionless_burn: bool,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub burn_delegate: Pubkey,
+    pub version: u8,
+    pub reference: [u8; 32],
     #[cfg_attr(feature = "serde", serde(with = "serde_with::As::<serde_with::Bytes>"))]
-    pub reserved: [u8; 263],
+    pub reserved: [u8; 68],
 }
 
 impl WhitelistUser {
-    pub const LEN: usize = 328;
+    pub const LEN: usize = 336;
 
     #[inline(always)]
     pub fn from_bytes(data: &[u8]) -> Result<Self, std::io::Error> {
@@ -28,6 +28,54 @@ pub enum JitoVaultWhitelistError {
     /// 3005 - InvalidWhitelistUser
     #[error("InvalidWhitelistUser")]
     InvalidWhitelistUser = 0xBBD,
+    /// 3006 - InvalidRentReceiver
+    #[error("InvalidRentReceiver")]
+    InvalidRentReceiver = 0xBBE,
+    /// 3007 - WhitelistNotClosed
+    #[error("WhitelistNotClosed")]
+    WhitelistNotClosed = 0xBBF,
+    /// 3008 - StaleWhitelistUser
+    #[error("StaleWhitelistUser")]
+    StaleWhitelistUser = 0xBC0,
+    /// 3009 - InvalidWhitelistMode
+    #[error("InvalidWhitelistMode")]
+    InvalidWhitelistMode = 0xBC1,
+    /// 3010 - UserBlacklisted
+    #[error("UserBlacklisted")]
+    UserBlacklisted = 0xBC2,
+    /// 3011 - WithdrawalLocked
+    #[error("WithdrawalLocked")]
+    WithdrawalLocked = 0xBC3,
+    /// 3012 - RateLimitExceeded
+    #[error("RateLimitExceeded")]
+    RateLimitExceeded = 0xBC4,
+    /// 3013 - DepositTooSmall
+    #[error("DepositTooSmall")]
+    DepositTooSmall = 0xBC5,
+    /// 3014 - DepositTooLarge
+    #[error("DepositTooLarge")]
+    DepositTooLarge = 0xBC6,
+    /// 3015 - InvalidDepositLimits
+    #[error("InvalidDepositLimits")]
+    InvalidDepositLimits = 0xBC7,
+    /// 3016 - NotCustodian
+    #[error("NotCustodian")]
+    NotCustodian = 0xBC8,
+    /// 3017 - InvalidBeneficiary
+    #[error("InvalidBeneficiary")]
+    InvalidBeneficiary = 0xBC9,
+    /// 3018 - InvalidBurnCrank
+    #[error("InvalidBurnCrank")]
+    InvalidBurnCrank = 0xBCA,
+    /// 3019 - InvalidStakerTokenAccount
+    #[error("InvalidStakerTokenAccount")]
+    InvalidStakerTokenAccount = 0xBCB,
+    /// 3020 - SlippageExceeded
+    #[error("SlippageExceeded")]
+    SlippageExceeded = 0xBCC,
+    /// 3021 - UnsupportedAccountVersion
+    #[error("UnsupportedAccountVersion")]
+    UnsupportedAccountVersion = 0xBCD,
 }
 
 impl solana_program::program_error::PrintProgramError for JitoVaultWhitelistError {
@@ -21,22 +21,28 @@ pub struct AddToWhitelist {
 
     pub vault_admin: solana_program::pubkey::Pubkey,
 
+    pub payer: solana_program::pubkey::Pubkey,
+
     pub user: solana_program::pubkey::Pubkey,
 
     pub system_program: solana_program::pubkey::Pubkey,
 }
 
 impl AddToWhitelist {
-    pub fn instruction(&self) -> solana_program::instruction::Instruction {
-        self.instruction_with_remaining_accounts(&[])
+    pub fn instruction(
+        &self,
+        args: AddToWhitelistInstructionArgs,
+    ) -> solana_program::instruction::Instruction {
+        self.instruction_with_remaining_accounts(args, &[])
     }
     #[allow(clippy::arithmetic_side_effects)]
     #[allow(clippy::vec_init_then_push)]
     pub fn instruction_with_remaining_accounts(
         &self,
+        args: AddToWhitelistInstructionArgs,
         remaining_accounts: &[solana_program::instruction::AccountMeta],
     ) -> solana_program::instruction::Instruction {
-        let mut accounts = Vec::with_capacity(7 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(8 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new_readonly(
             self.config,
             false,
@@ -44,7 +50,7 @@ impl AddToWhitelist {
         accounts.push(solana_program::instruction::AccountMeta::new_readonly(
             self.vault, false,
         ));
-        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+        accounts.push(solana_program::instruction::AccountMeta::new(
             self.whitelist,
             false,
         ));
@@ -56,6 +62,9 @@ impl AddToWhitelist {
             self.vault_admin,
             true,
         ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.payer, true,
+        ));
         accounts.push(solana_program::instruction::AccountMeta::new_readonly(
             self.user, false,
         ));
@@ -64,7 +73,9 @@ impl AddToWhitelist {
             false,
         ));
         accounts.extend_from_slice(remaining_accounts);
-        let data = borsh::to_vec(&AddToWhitelistInstructionData::new()).unwrap();
+        let mut data = borsh::to_vec(&AddToWhitelistInstructionData::new()).unwrap();
+        let mut args = borsh::to_vec(&args).unwrap();
+        data.append(&mut args);
 
         solana_program::instruction::Instruction {
             program_id: crate::JITO_VAULT_WHITELIST_ID,
@@ -92,17 +103,24 @@ impl Default for AddToWhitelistInstructionData {
     }
 }
 
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AddToWhitelistInstructionArgs {
+    pub reference: [u8; 32],
+}
+
 /// Instruction builder for `AddToWhitelist`.
 ///
 /// ### Accounts:
 ///
 ///   0. `[]` config
 ///   1. `[]` vault
-///   2. `[]` whitelist
+///   2. `[writable]` whitelist
 ///   3. `[writable]` whitelist_user
 ///   4. `[signer]` vault_admin
-///   5. `[]` user
-///   6. `[optional]` system_program (default to `11111111111111111111111111111111`)
+///   5. `[writable, signer]` payer
+///   6. `[]` user
+///   7. `[optional]` system_program (default to `11111111111111111111111111111111`)
 #[derive(Clone, Debug, Default)]
 pub struct AddToWhitelistBuilder {
     config: Option<solana_program::pubkey::Pubkey>,
@@ -110,8 +128,10 @@ pub struct AddToWhitelistBuilder {
     whitelist: Option<solana_program::pubkey::Pubkey>,
     whitelist_user: Option<solana_program::pubkey::Pubkey>,
     vault_admin: Option<solana_program::pubkey::Pubkey>,
+    payer: Option<solana_program::pubkey::Pubkey>,
     user: Option<solana_program::pubkey::Pubkey>,
     system_program: Option<solana_program::pubkey::Pubkey>,
+    reference: Option<[u8; 32]>,
     __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
 }
 
@@ -145,6 +165,11 @@ impl AddToWhitelistBuilder {
         self
     }
     #[inline(always)]
+    pub fn payer(&mut self, payer: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.payer = Some(payer);
+        self
+    }
+    #[inline(always)]
     pub fn user(&mut self, user: solana_program::pubkey::Pubkey) -> &mut Self {
         self.user = Some(user);
         self
@@ -155,6 +180,11 @@ impl AddToWhitelistBuilder {
         self.system_program = Some(system_program);
         self
     }
+    #[inline(always)]
+    pub fn reference(&mut self, reference: [u8; 32]) -> &mut Self {
+        self.reference = Some(reference);
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -181,13 +211,17 @@ impl AddToWhitelistBuilder {
             whitelist: self.whitelist.expect("whitelist is not set"),
             whitelist_user: self.whitelist_user.expect("whitelist_user is not set"),
             vault_admin: self.vault_admin.expect("vault_admin is not set"),
+            payer: self.payer.expect("payer is not set"),
             user: self.user.expect("user is not set"),
             system_program: self
                 .system_program
                 .unwrap_or(solana_program::pubkey!("11111111111111111111111111111111")),
         };
+        let args = AddToWhitelistInstructionArgs {
+            reference: self.reference.clone().expect("reference is not set"),
+        };
 
-        accounts.instruction_with_remaining_accounts(&self.__remaining_accounts)
+        accounts.instruction_with_remaining_accounts(args, &self.__remaining_accounts)
     }
 }
 
@@ -203,6 +237,8 @@ pub struct AddToWhitelistCpiAccounts<'a, 'b> {
 
     pub vault_admin: &'b solana_program::account_info::AccountInfo<'a>,
 
+    pub payer: &'b solana_program::account_info::AccountInfo<'a>,
+
     pub user: &'b solana_program::account_info::AccountInfo<'a>,
 
     pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
@@ -223,15 +259,20 @@ pub struct AddToWhitelistCpi<'a, 'b> {
 
     pub vault_admin: &'b solana_program::account_info::AccountInfo<'a>,
 
+    pub payer: &'b solana_program::account_info::AccountInfo<'a>,
+
     pub user: &'b solana_program::account_info::AccountInfo<'a>,
 
     pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The arguments for the instruction.
+    pub __args: AddToWhitelistInstructionArgs,
 }
 
 impl<'a, 'b> AddToWhitelistCpi<'a, 'b> {
     pub fn new(
         program: &'b solana_program::account_info::AccountInfo<'a>,
         accounts: AddToWhitelistCpiAccounts<'a, 'b>,
+        args: AddToWhitelistInstructionArgs,
     ) -> Self {
         Self {
             __program: program,
@@ -240,8 +281,10 @@ impl<'a, 'b> AddToWhitelistCpi<'a, 'b> {
             whitelist: accounts.whitelist,
             whitelist_user: accounts.whitelist_user,
             vault_admin: accounts.vault_admin,
+            payer: accounts.payer,
             user: accounts.user,
             system_program: accounts.system_program,
+            __args: args,
         }
     }
     #[inline(always)]
@@ -278,7 +321,7 @@ impl<'a, 'b> AddToWhitelistCpi<'a, 'b> {
             bool,
         )],
     ) -> solana_program::entrypoint::ProgramResult {
-        let mut accounts = Vec::with_capacity(7 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(8 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new_readonly(
             *self.config.key,
             false,
@@ -287,7 +330,7 @@ impl<'a, 'b> AddToWhitelistCpi<'a, 'b> {
             *self.vault.key,
             false,
         ));
-        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+        accounts.push(solana_program::instruction::AccountMeta::new(
             *self.whitelist.key,
             false,
         ));
@@ -299,6 +342,10 @@ impl<'a, 'b> AddToWhitelistCpi<'a, 'b> {
             *self.vault_admin.key,
             true,
         ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.payer.key,
+            true,
+        ));
         accounts.push(solana_program::instruction::AccountMeta::new_readonly(
             *self.user.key,
             false,
@@ -314,20 +361,23 @@ impl<'a, 'b> AddToWhitelistCpi<'a, 'b> {
                 is_writable: remaining_account.2,
             })
         });
-        let data = borsh::to_vec(&AddToWhitelistInstructionData::new()).unwrap();
+        let mut data = borsh::to_vec(&AddToWhitelistInstructionData::new()).unwrap();
+        let mut args = borsh::to_vec(&self.__args).unwrap();
+        data.append(&mut args);
 
         let instruction = solana_program::instruction::Instruction {
             program_id: crate::JITO_VAULT_WHITELIST_ID,
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(8 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(9 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.config.clone());
         account_infos.push(self.vault.clone());
         account_infos.push(self.whitelist.clone());
         account_infos.push(self.whitelist_user.clone());
         account_infos.push(self.vault_admin.clone());
+        account_infos.push(self.payer.clone());
         account_infos.push(self.user.clone());
         account_infos.push(self.system_program.clone());
         remaining_accounts
@@ -348,11 +398,12 @@ impl<'a, 'b> AddToWhitelistCpi<'a, 'b> {
 ///
 ///   0. `[]` config
 ///   1. `[]` vault
-///   2. `[]` whitelist
+///   2. `[writable]` whitelist
 ///   3. `[writable]` whitelist_user
 ///   4. `[signer]` vault_admin
-///   5. `[]` user
-///   6. `[]` system_program
+///   5. `[writable, signer]` payer
+///   6. `[]` user
+///   7. `[]` system_program
 #[derive(Clone, Debug)]
 pub struct AddToWhitelistCpiBuilder<'a, 'b> {
     instruction: Box<AddToWhitelistCpiBuilderInstruction<'a, 'b>>,
@@ -367,8 +418,10 @@ impl<'a, 'b> AddToWhitelistCpiBuilder<'a, 'b> {
             whitelist: None,
             whitelist_user: None,
             vault_admin: None,
+            payer: None,
             user: None,
             system_program: None,
+            reference: None,
             __remaining_accounts: Vec::new(),
         });
         Self { instruction }
@@ -411,6 +464,11 @@ impl<'a, 'b> AddToWhitelistCpiBuilder<'a, 'b> {
         self
     }
     #[inline(always)]
+    pub fn payer(&mut self, payer: &'b solana_program::account_info::AccountInfo<'a>) -> &mut Self {
+        self.instruction.payer = Some(payer);
+        self
+    }
+    #[inline(always)]
     pub fn user(&mut self, user: &'b solana_program::account_info::AccountInfo<'a>) -> &mut Self {
         self.instruction.user = Some(user);
         self
@@ -423,6 +481,11 @@ impl<'a, 'b> AddToWhitelistCpiBuilder<'a, 'b> {
         self.instruction.system_program = Some(system_program);
         self
     }
+    #[inline(always)]
+    pub fn reference(&mut self, reference: [u8; 32]) -> &mut Self {
+        self.instruction.reference = Some(reference);
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -464,6 +527,13 @@ impl<'a, 'b> AddToWhitelistCpiBuilder<'a, 'b> {
         &self,
         signers_seeds: &[&[&[u8]]],
     ) -> solana_program::entrypoint::ProgramResult {
+        let args = AddToWhitelistInstructionArgs {
+            reference: self
+                .instruction
+                .reference
+                .clone()
+                .expect("reference is not set"),
+        };
         let instruction = AddToWhitelistCpi {
             __program: self.instruction.__program,
 
@@ -483,12 +553,15 @@ impl<'a, 'b> AddToWhitelistCpiBuilder<'a, 'b> {
                 .vault_admin
                 .expect("vault_admin is not set"),
 
+            payer: self.instruction.payer.expect("payer is not set"),
+
             user: self.instruction.user.expect("user is not set"),
 
             system_program: self
                 .instruction
                 .system_program
                 .expect("system_program is not set"),
+            __args: args,
         };
         instruction.invoke_signed_with_remaining_accounts(
             signers_seeds,
@@ -505,8 +578,10 @@ struct AddToWhitelistCpiBuilderInstruction<'a, 'b> {
     whitelist: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     whitelist_user: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     vault_admin: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    payer: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     user: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     system_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    reference: Option<[u8; 32]>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(
         &'b solana_program::account_info::AccountInfo<'a>,
@@ -45,13 +45,17 @@ pub struct BurnWithdrawalTicket {
 }
 
 impl BurnWithdrawalTicket {
-    pub fn instruction(&self) -> solana_program::instruction::Instruction {
-        self.instruction_with_remaining_accounts(&[])
+    pub fn instruction(
+        &self,
+        args: BurnWithdrawalTicketInstructionArgs,
+    ) -> solana_program::instruction::Instruction {
+        self.instruction_with_remaining_accounts(args, &[])
     }
     #[allow(clippy::arithmetic_side_effects)]
     #[allow(clippy::vec_init_then_push)]
     pub fn instruction_with_remaining_accounts(
         &self,
+        args: BurnWithdrawalTicketInstructionArgs,
         remaining_accounts: &[solana_program::instruction::AccountMeta],
     ) -> solana_program::instruction::Instruction {
         let mut accounts = Vec::with_capacity(16 + remaining_accounts.len());
@@ -110,7 +114,7 @@ impl BurnWithdrawalTicket {
             self.whitelist,
             false,
         ));
-        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+        accounts.push(solana_program::instruction::AccountMeta::new(
             self.whitelist_user,
             false,
         ));
@@ -119,7 +123,9 @@ impl BurnWithdrawalTicket {
             false,
         ));
         accounts.extend_from_slice(remaining_accounts);
-        let data = borsh::to_vec(&BurnWithdrawalTicketInstructionData::new()).unwrap();
+        let mut data = borsh::to_vec(&BurnWithdrawalTicketInstructionData::new()).unwrap();
+        let mut args = borsh::to_vec(&args).unwrap();
+        data.append(&mut args);
 
         solana_program::instruction::Instruction {
             program_id: crate::JITO_VAULT_WHITELIST_ID,
@@ -147,6 +153,12 @@ impl Default for BurnWithdrawalTicketInstructionData {
     }
 }
 
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BurnWithdrawalTicketInstructionArgs {
+    pub min_amount_out: u64,
+}
+
 /// Instruction builder for `BurnWithdrawalTicket`.
 ///
 /// ### Accounts:
@@ -165,7 +177,7 @@ impl Default for BurnWithdrawalTicketInstructionData {
 ///   11. `[optional]` system_program (default to `11111111111111111111111111111111`)
 ///   12. `[]` config
 ///   13. `[writable]` whitelist
-///   14. `[]` whitelist_user
+///   14. `[writable]` whitelist_user
 ///   15. `[]` jito_vault_program
 #[derive(Clone, Debug, Default)]
 pub struct BurnWithdrawalTicketBuilder {
@@ -185,6 +197,7 @@ pub struct BurnWithdrawalTicketBuilder {
     whitelist: Option<solana_program::pubkey::Pubkey>,
     whitelist_user: Option<solana_program::pubkey::Pubkey>,
     jito_vault_program: Option<solana_program::pubkey::Pubkey>,
+    min_amount_out: Option<u64>,
     __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
 }
 
@@ -296,6 +309,11 @@ impl BurnWithdrawalTicketBuilder {
         self.jito_vault_program = Some(jito_vault_program);
         self
     }
+    #[inline(always)]
+    pub fn min_amount_out(&mut self, min_amount_out: u64) -> &mut Self {
+        self.min_amount_out = Some(min_amount_out);
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -352,8 +370,14 @@ impl BurnWithdrawalTicketBuilder {
                 .jito_vault_program
                 .expect("jito_vault_program is not set"),
         };
+        let args = BurnWithdrawalTicketInstructionArgs {
+            min_amount_out: self
+                .min_amount_out
+                .clone()
+                .expect("min_amount_out is not set"),
+        };
 
-        accounts.instruction_with_remaining_accounts(&self.__remaining_accounts)
+        accounts.instruction_with_remaining_accounts(args, &self.__remaining_accounts)
     }
 }
 
@@ -430,12 +454,15 @@ pub struct BurnWithdrawalTicketCpi<'a, 'b> {
     pub whitelist_user: &'b solana_program::account_info::AccountInfo<'a>,
 
     pub jito_vault_program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The arguments for the instruction.
+    pub __args: BurnWithdrawalTicketInstructionArgs,
 }
 
 impl<'a, 'b> BurnWithdrawalTicketCpi<'a, 'b> {
     pub fn new(
         program: &'b solana_program::account_info::AccountInfo<'a>,
         accounts: BurnWithdrawalTicketCpiAccounts<'a, 'b>,
+        args: BurnWithdrawalTicketInstructionArgs,
     ) -> Self {
         Self {
             __program: program,
@@ -456,6 +483,7 @@ impl<'a, 'b> BurnWithdrawalTicketCpi<'a, 'b> {
             whitelist: accounts.whitelist,
             whitelist_user: accounts.whitelist_user,
             jito_vault_program: accounts.jito_vault_program,
+            __args: args,
         }
     }
     #[inline(always)]
@@ -549,7 +577,7 @@ impl<'a, 'b> BurnWithdrawalTicketCpi<'a, 'b> {
             *self.whitelist.key,
             false,
         ));
-        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+        accounts.push(solana_program::instruction::AccountMeta::new(
             *self.whitelist_user.key,
             false,
         ));
@@ -564,7 +592,9 @@ impl<'a, 'b> BurnWithdrawalTicketCpi<'a, 'b> {
                 is_writable: remaining_account.2,
             })
         });
-        let data = borsh::to_vec(&BurnWithdrawalTicketInstructionData::new()).unwrap();
+        let mut data = borsh::to_vec(&BurnWithdrawalTicketInstructionData::new()).unwrap();
+        let mut args = borsh::to_vec(&self.__args).unwrap();
+        data.append(&mut args);
 
         let instruction = solana_program::instruction::Instruction {
             program_id: crate::JITO_VAULT_WHITELIST_ID,
@@ -619,7 +649,7 @@ impl<'a, 'b> BurnWithdrawalTicketCpi<'a, 'b> {
 ///   11. `[]` system_program
 ///   12. `[]` config
 ///   13. `[writable]` whitelist
-///   14. `[]` whitelist_user
+///   14. `[writable]` whitelist_user
 ///   15. `[]` jito_vault_program
 #[derive(Clone, Debug)]
 pub struct BurnWithdrawalTicketCpiBuilder<'a, 'b> {
@@ -646,6 +676,7 @@ impl<'a, 'b> BurnWithdrawalTicketCpiBuilder<'a, 'b> {
             whitelist: None,
             whitelist_user: None,
             jito_vault_program: None,
+            min_amount_out: None,
             __remaining_accounts: Vec::new(),
         });
         Self { instruction }
@@ -779,6 +810,11 @@ impl<'a, 'b> BurnWithdrawalTicketCpiBuilder<'a, 'b> {
         self.instruction.jito_vault_program = Some(jito_vault_program);
         self
     }
+    #[inline(always)]
+    pub fn min_amount_out(&mut self, min_amount_out: u64) -> &mut Self {
+        self.instruction.min_amount_out = Some(min_amount_out);
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -820,6 +856,13 @@ impl<'a, 'b> BurnWithdrawalTicketCpiBuilder<'a, 'b> {
         &self,
         signers_seeds: &[&[&[u8]]],
     ) -> solana_program::entrypoint::ProgramResult {
+        let args = BurnWithdrawalTicketInstructionArgs {
+            min_amount_out: self
+                .instruction
+                .min_amount_out
+                .clone()
+                .expect("min_amount_out is not set"),
+        };
         let instruction = BurnWithdrawalTicketCpi {
             __program: self.instruction.__program,
 
@@ -887,6 +930,7 @@ impl<'a, 'b> BurnWithdrawalTicketCpiBuilder<'a, 'b> {
                 .instruction
                 .jito_vault_program
                 .expect("jito_vault_program is not set"),
+            __args: args,
         };
         instruction.invoke_signed_with_remaining_accounts(
             signers_seeds,
@@ -915,6 +959,7 @@ struct BurnWithdrawalTicketCpiBuilderInstruction<'a, 'b> {
     whitelist: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     whitelist_user: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     jito_vault_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    min_amount_out: Option<u64>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(
         &'b solana_program::account_info::AccountInfo<'a>,
@@ -21,6 +21,8 @@ pub struct CloseWhitelist {
 
     pub vault_admin: solana_program::pubkey::Pubkey,
 
+    pub rent_receiver: solana_program::pubkey::Pubkey,
+
     pub jito_vault_program: solana_program::pubkey::Pubkey,
 
     pub system_program: solana_program::pubkey::Pubkey,
@@ -36,7 +38,7 @@ impl CloseWhitelist {
         &self,
         remaining_accounts: &[solana_program::instruction::AccountMeta],
     ) -> solana_program::instruction::Instruction {
-        let mut accounts = Vec::with_capacity(7 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(8 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new_readonly(
             self.config,
             false,
@@ -56,6 +58,10 @@ impl CloseWhitelist {
             self.vault_admin,
             true,
         ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.rent_receiver,
+            false,
+        ));
         accounts.push(solana_program::instruction::AccountMeta::new_readonly(
             self.jito_vault_program,
             false,
@@ -102,8 +108,9 @@ impl Default for CloseWhitelistInstructionData {
 ///   2. `[writable]` whitelist
 ///   3. `[writable]` vault
 ///   4. `[writable, signer]` vault_admin
-///   5. `[]` jito_vault_program
-///   6. `[optional]` system_program (default to `11111111111111111111111111111111`)
+///   5. `[writable]` rent_receiver
+///   6. `[]` jito_vault_program
+///   7. `[optional]` system_program (default to `11111111111111111111111111111111`)
 #[derive(Clone, Debug, Default)]
 pub struct CloseWhitelistBuilder {
     config: Option<solana_program::pubkey::Pubkey>,
@@ -111,6 +118,7 @@ pub struct CloseWhitelistBuilder {
     whitelist: Option<solana_program::pubkey::Pubkey>,
     vault: Option<solana_program::pubkey::Pubkey>,
     vault_admin: Option<solana_program::pubkey::Pubkey>,
+    rent_receiver: Option<solana_program::pubkey::Pubkey>,
     jito_vault_program: Option<solana_program::pubkey::Pubkey>,
     system_program: Option<solana_program::pubkey::Pubkey>,
     __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
@@ -146,6 +154,11 @@ impl CloseWhitelistBuilder {
         self
     }
     #[inline(always)]
+    pub fn rent_receiver(&mut self, rent_receiver: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.rent_receiver = Some(rent_receiver);
+        self
+    }
+    #[inline(always)]
     pub fn jito_vault_program(
         &mut self,
         jito_vault_program: solana_program::pubkey::Pubkey,
@@ -185,6 +198,7 @@ impl CloseWhitelistBuilder {
             whitelist: self.whitelist.expect("whitelist is not set"),
             vault: self.vault.expect("vault is not set"),
             vault_admin: self.vault_admin.expect("vault_admin is not set"),
+            rent_receiver: self.rent_receiver.expect("rent_receiver is not set"),
             jito_vault_program: self
                 .jito_vault_program
                 .expect("jito_vault_program is not set"),
@@ -209,6 +223,8 @@ pub struct CloseWhitelistCpiAccounts<'a, 'b> {
 
     pub vault_admin: &'b solana_program::account_info::AccountInfo<'a>,
 
+    pub rent_receiver: &'b solana_program::account_info::AccountInfo<'a>,
+
     pub jito_vault_program: &'b solana_program::account_info::AccountInfo<'a>,
 
     pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
@@ -229,6 +245,8 @@ pub struct CloseWhitelistCpi<'a, 'b> {
 
     pub vault_admin: &'b solana_program::account_info::AccountInfo<'a>,
 
+    pub rent_receiver: &'b solana_program::account_info::AccountInfo<'a>,
+
     pub jito_vault_program: &'b solana_program::account_info::AccountInfo<'a>,
 
     pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
@@ -246,6 +264,7 @@ impl<'a, 'b> CloseWhitelistCpi<'a, 'b> {
             whitelist: accounts.whitelist,
             vault: accounts.vault,
             vault_admin: accounts.vault_admin,
+            rent_receiver: accounts.rent_receiver,
             jito_vault_program: accounts.jito_vault_program,
             system_program: accounts.system_program,
         }
@@ -284,7 +303,7 @@ impl<'a, 'b> CloseWhitelistCpi<'a, 'b> {
             bool,
         )],
     ) -> solana_program::entrypoint::ProgramResult {
-        let mut accounts = Vec::with_capacity(7 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(8 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new_readonly(
             *self.config.key,
             false,
@@ -305,6 +324,10 @@ impl<'a, 'b> CloseWhitelistCpi<'a, 'b> {
             *self.vault_admin.key,
             true,
         ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.rent_receiver.key,
+            false,
+        ));
         accounts.push(solana_program::instruction::AccountMeta::new_readonly(
             *self.jito_vault_program.key,
             false,
@@ -327,13 +350,14 @@ impl<'a, 'b> CloseWhitelistCpi<'a, 'b> {
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(8 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(9 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.config.clone());
         account_infos.push(self.vault_config.clone());
         account_infos.push(self.whitelist.clone());
         account_infos.push(self.vault.clone());
         account_infos.push(self.vault_admin.clone());
+        account_infos.push(self.rent_receiver.clone());
         account_infos.push(self.jito_vault_program.clone());
         account_infos.push(self.system_program.clone());
         remaining_accounts
@@ -357,8 +381,9 @@ impl<'a, 'b> CloseWhitelistCpi<'a, 'b> {
 ///   2. `[writable]` whitelist
 ///   3. `[writable]` vault
 ///   4. `[writable, signer]` vault_admin
-///   5. `[]` jito_vault_program
-///   6. `[]` system_program
+///   5. `[writable]` rent_receiver
+///   6. `[]` jito_vault_program
+///   7. `[]` system_program
 #[derive(Clone, Debug)]
 pub struct CloseWhitelistCpiBuilder<'a, 'b> {
     instruction: Box<CloseWhitelistCpiBuilderInstruction<'a, 'b>>,
@@ -373,6 +398,7 @@ impl<'a, 'b> CloseWhitelistCpiBuilder<'a, 'b> {
             whitelist: None,
             vault: None,
             vault_admin: None,
+            rent_receiver: None,
             jito_vault_program: None,
             system_program: None,
             __remaining_accounts: Vec::new(),
@@ -417,6 +443,14 @@ impl<'a, 'b> CloseWhitelistCpiBuilder<'a, 'b> {
         self
     }
     #[inline(always)]
+    pub fn rent_receiver(
+        &mut self,
+        rent_receiver: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.rent_receiver = Some(rent_receiver);
+        self
+    }
+    #[inline(always)]
     pub fn jito_vault_program(
         &mut self,
         jito_vault_program: &'b solana_program::account_info::AccountInfo<'a>,
@@ -492,6 +526,11 @@ impl<'a, 'b> CloseWhitelistCpiBuilder<'a, 'b> {
                 .vault_admin
                 .expect("vault_admin is not set"),
 
+            rent_receiver: self
+                .instruction
+                .rent_receiver
+                .expect("rent_receiver is not set"),
+
             jito_vault_program: self
                 .instruction
                 .jito_vault_program
@@ -517,6 +556,7 @@ struct CloseWhitelistCpiBuilderInstruction<'a, 'b> {
     whitelist: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     vault: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     vault_admin: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    rent_receiver: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     jito_vault_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     system_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
@@ -95,7 +95,7 @@ impl EnqueueWithdrawal {
             self.whitelist,
             false,
         ));
-        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+        accounts.push(solana_program::instruction::AccountMeta::new(
             self.whitelist_user,
             false,
         ));
@@ -155,7 +155,7 @@ pub struct EnqueueWithdrawalInstructionArgs {
 ///   8. `[optional]` system_program (default to `11111111111111111111111111111111`)
 ///   9. `[]` config
 ///   10. `[writable]` whitelist
-///   11. `[]` whitelist_user
+///   11. `[writable]` whitelist_user
 ///   12. `[]` jito_vault_program
 #[derive(Clone, Debug, Default)]
 pub struct EnqueueWithdrawalBuilder {
@@ -489,7 +489,7 @@ impl<'a, 'b> EnqueueWithdrawalCpi<'a, 'b> {
             *self.whitelist.key,
             false,
         ));
-        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+        accounts.push(solana_program::instruction::AccountMeta::new(
             *self.whitelist_user.key,
             false,
         ));
@@ -555,7 +555,7 @@ impl<'a, 'b> EnqueueWithdrawalCpi<'a, 'b> {
 ///   8. `[]` system_program
 ///   9. `[]` config
 ///   10. `[writable]` whitelist
-///   11. `[]` whitelist_user
+///   11. `[writable]` whitelist_user
 ///   12. `[]` jito_vault_program
 #[derive(Clone, Debug)]
 pub struct EnqueueWithdrawalCpiBuilder<'a, 'b> {
@@ -19,21 +19,31 @@ pub struct InitializeWhitelist {
 
     pub vault_admin: solana_program::pubkey::Pubkey,
 
+    pub payer: solana_program::pubkey::Pubkey,
+
     pub system_program: solana_program::pubkey::Pubkey,
+
+    pub kyc_provider: solana_program::pubkey::Pubkey,
+
+    pub whitelist_metadata: solana_program::pubkey::Pubkey,
 }
 
 impl InitializeWhitelist {
-    pub fn instruction(&self) -> solana_program::instruction::Instruction {
-        self.instruction_with_remaining_accounts(&[])
+    pub fn instruction(
+        &self,
+        args: InitializeWhitelistInstructionArgs,
+    ) -> solana_program::instruction::Instruction {
+        self.instruction_with_remaining_accounts(args, &[])
     }
     #[allow(clippy::arithmetic_side_effects)]
     #[allow(clippy::vec_init_then_push)]
     pub fn instruction_with_remaining_accounts(
         &self,
+        args: InitializeWhitelistInstructionArgs,
         remaining_accounts: &[solana_program::instruction::AccountMeta],
     ) -> solana_program::instruction::Instruction {
-        let mut accounts = Vec::with_capacity(5 + remaining_accounts.len());
-        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+        let mut accounts = Vec::with_capacity(8 + remaining_accounts.len());
+        accounts.push(solana_program::instruction::AccountMeta::new(
             self.config,
             false,
         ));
@@ -44,16 +54,29 @@ impl InitializeWhitelist {
         accounts.push(solana_program::instruction::AccountMeta::new_readonly(
             self.vault, false,
         ));
-        accounts.push(solana_program::instruction::AccountMeta::new(
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
             self.vault_admin,
             true,
         ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.payer, true,
+        ));
         accounts.push(solana_program::instruction::AccountMeta::new_readonly(
             self.system_program,
             false,
         ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.kyc_provider,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.whitelist_metadata,
+            false,
+        ));
         accounts.extend_from_slice(remaining_accounts);
-        let data = borsh::to_vec(&InitializeWhitelistInstructionData::new()).unwrap();
+        let mut data = borsh::to_vec(&InitializeWhitelistInstructionData::new()).unwrap();
+        let mut args = borsh::to_vec(&args).unwrap();
+        data.append(&mut args);
 
         solana_program::instruction::Instruction {
             program_id: crate::JITO_VAULT_WHITELIST_ID,
@@ -81,22 +104,38 @@ impl Default for InitializeWhitelistInstructionData {
     }
 }
 
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct InitializeWhitelistInstructionArgs {
+    pub name: [u8; 32],
+    #[cfg_attr(feature = "serde", serde(with = "serde_with::As::<serde_with::Bytes>"))]
+    pub uri: [u8; 128],
+}
+
 /// Instruction builder for `InitializeWhitelist`.
 ///
 /// ### Accounts:
 ///
-///   0. `[]` config
+///   0. `[writable]` config
 ///   1. `[writable]` whitelist
 ///   2. `[]` vault
-///   3. `[writable, signer]` vault_admin
-///   4. `[optional]` system_program (default to `11111111111111111111111111111111`)
+///   3. `[signer]` vault_admin
+///   4. `[writable, signer]` payer
+///   5. `[optional]` system_program (default to `11111111111111111111111111111111`)
+///   6. `[]` kyc_provider
+///   7. `[writable]` whitelist_metadata
 #[derive(Clone, Debug, Default)]
 pub struct InitializeWhitelistBuilder {
     config: Option<solana_program::pubkey::Pubkey>,
     whitelist: Option<solana_program::pubkey::Pubkey>,
     vault: Option<solana_program::pubkey::Pubkey>,
     vault_admin: Option<solana_program::pubkey::Pubkey>,
+    payer: Option<solana_program::pubkey::Pubkey>,
     system_program: Option<solana_program::pubkey::Pubkey>,
+    kyc_provider: Option<solana_program::pubkey::Pubkey>,
+    whitelist_metadata: Option<solana_program::pubkey::Pubkey>,
+    name: Option<[u8; 32]>,
+    uri: Option<[u8; 128]>,
     __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
 }
 
@@ -124,12 +163,40 @@ impl InitializeWhitelistBuilder {
         self.vault_admin = Some(vault_admin);
         self
     }
+    #[inline(always)]
+    pub fn payer(&mut self, payer: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.payer = Some(payer);
+        self
+    }
     /// `[optional account, default to '11111111111111111111111111111111']`
     #[inline(always)]
     pub fn system_program(&mut self, system_program: solana_program::pubkey::Pubkey) -> &mut Self {
         self.system_program = Some(system_program);
         self
     }
+    #[inline(always)]
+    pub fn kyc_provider(&mut self, kyc_provider: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.kyc_provider = Some(kyc_provider);
+        self
+    }
+    #[inline(always)]
+    pub fn whitelist_metadata(
+        &mut self,
+        whitelist_metadata: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.whitelist_metadata = Some(whitelist_metadata);
+        self
+    }
+    #[inline(always)]
+    pub fn name(&mut self, name: [u8; 32]) -> &mut Self {
+        self.name = Some(name);
+        self
+    }
+    #[inline(always)]
+    pub fn uri(&mut self, uri: [u8; 128]) -> &mut Self {
+        self.uri = Some(uri);
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -155,12 +222,21 @@ impl InitializeWhitelistBuilder {
             whitelist: self.whitelist.expect("whitelist is not set"),
             vault: self.vault.expect("vault is not set"),
             vault_admin: self.vault_admin.expect("vault_admin is not set"),
+            payer: self.payer.expect("payer is not set"),
             system_program: self
                 .system_program
                 .unwrap_or(solana_program::pubkey!("11111111111111111111111111111111")),
+            kyc_provider: self.kyc_provider.expect("kyc_provider is not set"),
+            whitelist_metadata: self
+                .whitelist_metadata
+                .expect("whitelist_metadata is not set"),
+        };
+        let args = InitializeWhitelistInstructionArgs {
+            name: self.name.clone().expect("name is not set"),
+            uri: self.uri.clone().expect("uri is not set"),
         };
 
-        accounts.instruction_with_remaining_accounts(&self.__remaining_accounts)
+        accounts.instruction_with_remaining_accounts(args, &self.__remaining_accounts)
     }
 }
 
@@ -174,7 +250,13 @@ pub struct InitializeWhitelistCpiAccounts<'a, 'b> {
 
     pub vault_admin: &'b solana_program::account_info::AccountInfo<'a>,
 
+    pub payer: &'b solana_program::account_info::AccountInfo<'a>,
+
     pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub kyc_provider: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub whitelist_metadata: &'b solana_program::account_info::AccountInfo<'a>,
 }
 
 /// `initialize_whitelist` CPI instruction.
@@ -190,13 +272,22 @@ pub struct InitializeWhitelistCpi<'a, 'b> {
 
     pub vault_admin: &'b solana_program::account_info::AccountInfo<'a>,
 
+    pub payer: &'b solana_program::account_info::AccountInfo<'a>,
+
     pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub kyc_provider: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub whitelist_metadata: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The arguments for the instruction.
+    pub __args: InitializeWhitelistInstructionArgs,
 }
 
 impl<'a, 'b> InitializeWhitelistCpi<'a, 'b> {
     pub fn new(
         program: &'b solana_program::account_info::AccountInfo<'a>,
         accounts: InitializeWhitelistCpiAccounts<'a, 'b>,
+        args: InitializeWhitelistInstructionArgs,
     ) -> Self {
         Self {
             __program: program,
@@ -204,7 +295,11 @@ impl<'a, 'b> InitializeWhitelistCpi<'a, 'b> {
             whitelist: accounts.whitelist,
             vault: accounts.vault,
             vault_admin: accounts.vault_admin,
+            payer: accounts.payer,
             system_program: accounts.system_program,
+            kyc_provider: accounts.kyc_provider,
+            whitelist_metadata: accounts.whitelist_metadata,
+            __args: args,
         }
     }
     #[inline(always)]
@@ -241,8 +336,8 @@ impl<'a, 'b> InitializeWhitelistCpi<'a, 'b> {
             bool,
         )],
     ) -> solana_program::entrypoint::ProgramResult {
-        let mut accounts = Vec::with_capacity(5 + remaining_accounts.len());
-        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+        let mut accounts = Vec::with_capacity(8 + remaining_accounts.len());
+        accounts.push(solana_program::instruction::AccountMeta::new(
             *self.config.key,
             false,
         ));
@@ -254,14 +349,26 @@ impl<'a, 'b> InitializeWhitelistCpi<'a, 'b> {
             *self.vault.key,
             false,
         ));
-        accounts.push(solana_program::instruction::AccountMeta::new(
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
             *self.vault_admin.key,
             true,
         ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.payer.key,
+            true,
+        ));
         accounts.push(solana_program::instruction::AccountMeta::new_readonly(
             *self.system_program.key,
             false,
         ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.kyc_provider.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.whitelist_metadata.key,
+            false,
+        ));
         remaining_accounts.iter().for_each(|remaining_account| {
             accounts.push(solana_program::instruction::AccountMeta {
                 pubkey: *remaining_account.0.key,
@@ -269,20 +376,25 @@ impl<'a, 'b> InitializeWhitelistCpi<'a, 'b> {
                 is_writable: remaining_account.2,
             })
         });
-        let data = borsh::to_vec(&InitializeWhitelistInstructionData::new()).unwrap();
+        let mut data = borsh::to_vec(&InitializeWhitelistInstructionData::new()).unwrap();
+        let mut args = borsh::to_vec(&self.__args).unwrap();
+        data.append(&mut args);
 
         let instruction = solana_program::instruction::Instruction {
             program_id: crate::JITO_VAULT_WHITELIST_ID,
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(6 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(9 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.config.clone());
         account_infos.push(self.whitelist.clone());
         account_infos.push(self.vault.clone());
         account_infos.push(self.vault_admin.clone());
+        account_infos.push(self.payer.clone());
         account_infos.push(self.system_program.clone());
+        account_infos.push(self.kyc_provider.clone());
+        account_infos.push(self.whitelist_metadata.clone());
         remaining_accounts
             .iter()
             .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
@@ -299,11 +411,14 @@ impl<'a, 'b> InitializeWhitelistCpi<'a, 'b> {
 ///
 /// ### Accounts:
 ///
-///   0. `[]` config
+///   0. `[writable]` config
 ///   1. `[writable]` whitelist
 ///   2. `[]` vault
-///   3. `[writable, signer]` vault_admin
-///   4. `[]` system_program
+///   3. `[signer]` vault_admin
+///   4. `[writable, signer]` payer
+///   5. `[]` system_program
+///   6. `[]` kyc_provider
+///   7. `[writable]` whitelist_metadata
 #[derive(Clone, Debug)]
 pub struct InitializeWhitelistCpiBuilder<'a, 'b> {
     instruction: Box<InitializeWhitelistCpiBuilderInstruction<'a, 'b>>,
@@ -317,7 +432,12 @@ impl<'a, 'b> InitializeWhitelistCpiBuilder<'a, 'b> {
             whitelist: None,
             vault: None,
             vault_admin: None,
+            payer: None,
             system_program: None,
+            kyc_provider: None,
+            whitelist_metadata: None,
+            name: None,
+            uri: None,
             __remaining_accounts: Vec::new(),
         });
         Self { instruction }
@@ -352,6 +472,11 @@ impl<'a, 'b> InitializeWhitelistCpiBuilder<'a, 'b> {
         self
     }
     #[inline(always)]
+    pub fn payer(&mut self, payer: &'b solana_program::account_info::AccountInfo<'a>) -> &mut Self {
+        self.instruction.payer = Some(payer);
+        self
+    }
+    #[inline(always)]
     pub fn system_program(
         &mut self,
         system_program: &'b solana_program::account_info::AccountInfo<'a>,
@@ -359,6 +484,32 @@ impl<'a, 'b> InitializeWhitelistCpiBuilder<'a, 'b> {
         self.instruction.system_program = Some(system_program);
         self
     }
+    #[inline(always)]
+    pub fn kyc_provider(
+        &mut self,
+        kyc_provider: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.kyc_provider = Some(kyc_provider);
+        self
+    }
+    #[inline(always)]
+    pub fn whitelist_metadata(
+        &mut self,
+        whitelist_metadata: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.whitelist_metadata = Some(whitelist_metadata);
+        self
+    }
+    #[inline(always)]
+    pub fn name(&mut self, name: [u8; 32]) -> &mut Self {
+        self.instruction.name = Some(name);
+        self
+    }
+    #[inline(always)]
+    pub fn uri(&mut self, uri: [u8; 128]) -> &mut Self {
+        self.instruction.uri = Some(uri);
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -400,6 +551,10 @@ impl<'a, 'b> InitializeWhitelistCpiBuilder<'a, 'b> {
         &self,
         signers_seeds: &[&[&[u8]]],
     ) -> solana_program::entrypoint::ProgramResult {
+        let args = InitializeWhitelistInstructionArgs {
+            name: self.instruction.name.clone().expect("name is not set"),
+            uri: self.instruction.uri.clone().expect("uri is not set"),
+        };
         let instruction = InitializeWhitelistCpi {
             __program: self.instruction.__program,
 
@@ -414,10 +569,23 @@ impl<'a, 'b> InitializeWhitelistCpiBuilder<'a, 'b> {
                 .vault_admin
                 .expect("vault_admin is not set"),
 
+            payer: self.instruction.payer.expect("payer is not set"),
+
             system_program: self
                 .instruction
                 .system_program
                 .expect("system_program is not set"),
+
+            kyc_provider: self
+                .instruction
+                .kyc_provider
+                .expect("kyc_provider is not set"),
+
+            whitelist_metadata: self
+                .instruction
+                .whitelist_metadata
+                .expect("whitelist_metadata is not set"),
+            __args: args,
         };
         instruction.invoke_signed_with_remaining_accounts(
             signers_seeds,
@@ -433,7 +601,12 @@ struct InitializeWhitelistCpiBuilderInstruction<'a, 'b> {
     whitelist: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     vault: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     vault_admin: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    payer: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     system_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    kyc_provider: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    whitelist_metadata: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    name: Option<[u8; 32]>,
+    uri: Option<[u8; 128]>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(
         &'b solana_program::account_info::AccountInfo<'a>,
@@ -92,7 +92,7 @@ impl Mint {
             self.whitelist,
             false,
         ));
-        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+        accounts.push(solana_program::instruction::AccountMeta::new(
             self.whitelist_user,
             false,
         ));
@@ -156,7 +156,7 @@ pub struct MintInstructionArgs {
 ///   7. `[writable]` depositor_vrt_token_account
 ///   8. `[writable]` vault_fee_token_account
 ///   9. `[writable]` whitelist
-///   10. `[]` whitelist_user
+///   10. `[writable]` whitelist_user
 ///   11. `[]` jito_vault_program
 ///   12. `[optional]` token_program (default to `TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA`)
 #[derive(Clone, Debug, Default)]
@@ -495,7 +495,7 @@ impl<'a, 'b> MintCpi<'a, 'b> {
             *self.whitelist.key,
             false,
         ));
-        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+        accounts.push(solana_program::instruction::AccountMeta::new(
             *self.whitelist_user.key,
             false,
         ));
@@ -564,7 +564,7 @@ impl<'a, 'b> MintCpi<'a, 'b> {
 ///   7. `[writable]` depositor_vrt_token_account
 ///   8. `[writable]` vault_fee_token_account
 ///   9. `[writable]` whitelist
-///   10. `[]` whitelist_user
+///   10. `[writable]` whitelist_user
 ///   11. `[]` jito_vault_program
 ///   12. `[]` token_program
 #[derive(Clone, Debug)]
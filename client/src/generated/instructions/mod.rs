@@ -5,22 +5,54 @@
 //! <https://github.com/codama-idl/codama>
 //!
 
+pub(crate) mod r#add_to_blacklist;
 pub(crate) mod r#add_to_whitelist;
 pub(crate) mod r#burn_withdrawal_ticket;
+pub(crate) mod r#close_orphaned_whitelist_user;
 pub(crate) mod r#close_whitelist;
+pub(crate) mod r#crank_burn_withdrawal_ticket;
 pub(crate) mod r#enqueue_withdrawal;
 pub(crate) mod r#initialize_config;
 pub(crate) mod r#initialize_whitelist;
+pub(crate) mod r#migrate_account;
 pub(crate) mod r#mint;
+pub(crate) mod r#mint_for;
+pub(crate) mod r#remove_from_blacklist;
 pub(crate) mod r#remove_from_whitelist;
+pub(crate) mod r#set_burn_crank;
+pub(crate) mod r#set_burn_delegate;
+pub(crate) mod r#set_custodian;
+pub(crate) mod r#set_deposit_limits;
+pub(crate) mod r#set_lockup;
 pub(crate) mod r#set_mint_burn_admin;
+pub(crate) mod r#set_rate_limit;
+pub(crate) mod r#set_user_deposit_limits;
+pub(crate) mod r#set_user_reference;
+pub(crate) mod r#set_whitelist_metadata;
+pub(crate) mod r#set_whitelist_mode;
 
+pub use self::r#add_to_blacklist::*;
 pub use self::r#add_to_whitelist::*;
 pub use self::r#burn_withdrawal_ticket::*;
+pub use self::r#close_orphaned_whitelist_user::*;
 pub use self::r#close_whitelist::*;
+pub use self::r#crank_burn_withdrawal_ticket::*;
 pub use self::r#enqueue_withdrawal::*;
 pub use self::r#initialize_config::*;
 pub use self::r#initialize_whitelist::*;
+pub use self::r#migrate_account::*;
 pub use self::r#mint::*;
+pub use self::r#mint_for::*;
+pub use self::r#remove_from_blacklist::*;
 pub use self::r#remove_from_whitelist::*;
+pub use self::r#set_burn_crank::*;
+pub use self::r#set_burn_delegate::*;
+pub use self::r#set_custodian::*;
+pub use self::r#set_deposit_limits::*;
+pub use self::r#set_lockup::*;
 pub use self::r#set_mint_burn_admin::*;
+pub use self::r#set_rate_limit::*;
+pub use self::r#set_user_deposit_limits::*;
+pub use self::r#set_user_reference::*;
+pub use self::r#set_whitelist_metadata::*;
+pub use self::r#set_whitelist_mode::*;
@@ -21,6 +21,8 @@ pub struct RemoveFromWhitelist {
 
     pub vault_admin: solana_program::pubkey::Pubkey,
 
+    pub rent_receiver: solana_program::pubkey::Pubkey,
+
     pub user: solana_program::pubkey::Pubkey,
 
     pub system_program: solana_program::pubkey::Pubkey,
@@ -36,7 +38,7 @@ impl RemoveFromWhitelist {
         &self,
         remaining_accounts: &[solana_program::instruction::AccountMeta],
     ) -> solana_program::instruction::Instruction {
-        let mut accounts = Vec::with_capacity(7 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(8 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new_readonly(
             self.config,
             false,
@@ -44,7 +46,7 @@ impl RemoveFromWhitelist {
         accounts.push(solana_program::instruction::AccountMeta::new_readonly(
             self.vault, false,
         ));
-        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+        accounts.push(solana_program::instruction::AccountMeta::new(
             self.whitelist,
             false,
         ));
@@ -56,6 +58,10 @@ impl RemoveFromWhitelist {
             self.vault_admin,
             true,
         ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.rent_receiver,
+            false,
+        ));
         accounts.push(solana_program::instruction::AccountMeta::new_readonly(
             self.user, false,
         ));
@@ -98,11 +104,12 @@ impl Default for RemoveFromWhitelistInstructionData {
 ///
 ///   0. `[]` config
 ///   1. `[]` vault
-///   2. `[]` whitelist
+///   2. `[writable]` whitelist
 ///   3. `[writable]` whitelist_user
 ///   4. `[signer]` vault_admin
-///   5. `[]` user
-///   6. `[optional]` system_program (default to `11111111111111111111111111111111`)
+///   5. `[writable]` rent_receiver
+///   6. `[]` user
+///   7. `[optional]` system_program (default to `11111111111111111111111111111111`)
 #[derive(Clone, Debug, Default)]
 pub struct RemoveFromWhitelistBuilder {
     config: Option<solana_program::pubkey::Pubkey>,
@@ -110,6 +117,7 @@ pub struct RemoveFromWhitelistBuilder {
     whitelist: Option<solana_program::pubkey::Pubkey>,
     whitelist_user: Option<solana_program::pubkey::Pubkey>,
     vault_admin: Option<solana_program::pubkey::Pubkey>,
+    rent_receiver: Option<solana_program::pubkey::Pubkey>,
     user: Option<solana_program::pubkey::Pubkey>,
     system_program: Option<solana_program::pubkey::Pubkey>,
     __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
@@ -145,6 +153,11 @@ impl RemoveFromWhitelistBuilder {
         self
     }
     #[inline(always)]
+    pub fn rent_receiver(&mut self, rent_receiver: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.rent_receiver = Some(rent_receiver);
+        self
+    }
+    #[inline(always)]
     pub fn user(&mut self, user: solana_program::pubkey::Pubkey) -> &mut Self {
         self.user = Some(user);
         self
@@ -181,6 +194,7 @@ impl RemoveFromWhitelistBuilder {
             whitelist: self.whitelist.expect("whitelist is not set"),
             whitelist_user: self.whitelist_user.expect("whitelist_user is not set"),
             vault_admin: self.vault_admin.expect("vault_admin is not set"),
+            rent_receiver: self.rent_receiver.expect("rent_receiver is not set"),
             user: self.user.expect("user is not set"),
             system_program: self
                 .system_program
@@ -203,6 +217,8 @@ pub struct RemoveFromWhitelistCpiAccounts<'a, 'b> {
 
     pub vault_admin: &'b solana_program::account_info::AccountInfo<'a>,
 
+    pub rent_receiver: &'b solana_program::account_info::AccountInfo<'a>,
+
     pub user: &'b solana_program::account_info::AccountInfo<'a>,
 
     pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
@@ -223,6 +239,8 @@ pub struct RemoveFromWhitelistCpi<'a, 'b> {
 
     pub vault_admin: &'b solana_program::account_info::AccountInfo<'a>,
 
+    pub rent_receiver: &'b solana_program::account_info::AccountInfo<'a>,
+
     pub user: &'b solana_program::account_info::AccountInfo<'a>,
 
     pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
@@ -240,6 +258,7 @@ impl<'a, 'b> RemoveFromWhitelistCpi<'a, 'b> {
             whitelist: accounts.whitelist,
             whitelist_user: accounts.whitelist_user,
             vault_admin: accounts.vault_admin,
+            rent_receiver: accounts.rent_receiver,
             user: accounts.user,
             system_program: accounts.system_program,
         }
@@ -278,7 +297,7 @@ impl<'a, 'b> RemoveFromWhitelistCpi<'a, 'b> {
             bool,
         )],
     ) -> solana_program::entrypoint::ProgramResult {
-        let mut accounts = Vec::with_capacity(7 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(8 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new_readonly(
             *self.config.key,
             false,
@@ -287,7 +306,7 @@ impl<'a, 'b> RemoveFromWhitelistCpi<'a, 'b> {
             *self.vault.key,
             false,
         ));
-        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+        accounts.push(solana_program::instruction::AccountMeta::new(
             *self.whitelist.key,
             false,
         ));
@@ -299,6 +318,10 @@ impl<'a, 'b> RemoveFromWhitelistCpi<'a, 'b> {
             *self.vault_admin.key,
             true,
         ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.rent_receiver.key,
+            false,
+        ));
         accounts.push(solana_program::instruction::AccountMeta::new_readonly(
             *self.user.key,
             false,
@@ -321,13 +344,14 @@ impl<'a, 'b> RemoveFromWhitelistCpi<'a, 'b> {
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(8 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(9 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.config.clone());
         account_infos.push(self.vault.clone());
         account_infos.push(self.whitelist.clone());
         account_infos.push(self.whitelist_user.clone());
         account_infos.push(self.vault_admin.clone());
+        account_infos.push(self.rent_receiver.clone());
         account_infos.push(self.user.clone());
         account_infos.push(self.system_program.clone());
         remaining_accounts
@@ -348,11 +372,12 @@ impl<'a, 'b> RemoveFromWhitelistCpi<'a, 'b> {
 ///
 ///   0. `[]` config
 ///   1. `[]` vault
-///   2. `[]` whitelist
+///   2. `[writable]` whitelist
 ///   3. `[writable]` whitelist_user
 ///   4. `[signer]` vault_admin
-///   5. `[]` user
-///   6. `[]` system_program
+///   5. `[writable]` rent_receiver
+///   6. `[]` user
+///   7. `[]` system_program
 #[derive(Clone, Debug)]
 pub struct RemoveFromWhitelistCpiBuilder<'a, 'b> {
     instruction: Box<RemoveFromWhitelistCpiBuilderInstruction<'a, 'b>>,
@@ -367,6 +392,7 @@ impl<'a, 'b> RemoveFromWhitelistCpiBuilder<'a, 'b> {
             whitelist: None,
             whitelist_user: None,
             vault_admin: None,
+            rent_receiver: None,
             user: None,
             system_program: None,
             __remaining_accounts: Vec::new(),
@@ -411,6 +437,14 @@ impl<'a, 'b> RemoveFromWhitelistCpiBuilder<'a, 'b> {
         self
     }
     #[inline(always)]
+    pub fn rent_receiver(
+        &mut self,
+        rent_receiver: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.rent_receiver = Some(rent_receiver);
+        self
+    }
+    #[inline(always)]
     pub fn user(&mut self, user: &'b solana_program::account_info::AccountInfo<'a>) -> &mut Self {
         self.instruction.user = Some(user);
         self
@@ -483,6 +517,11 @@ impl<'a, 'b> RemoveFromWhitelistCpiBuilder<'a, 'b> {
                 .vault_admin
                 .expect("vault_admin is not set"),
 
+            rent_receiver: self
+                .instruction
+                .rent_receiver
+                .expect("rent_receiver is not set"),
+
             user: self.instruction.user.expect("user is not set"),
 
             system_program: self
@@ -505,6 +544,7 @@ struct RemoveFromWhitelistCpiBuilderInstruction<'a, 'b> {
     whitelist: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     whitelist_user: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     vault_admin: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    rent_receiver: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     user: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     system_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
@@ -6,6 +6,8 @@
 #![allow(clippy::perf)]
 mod generated;
 mod log;
+pub mod quote;
+pub mod withdrawal_ticket;
 
 use generated::*;
 
@@ -0,0 +1,19 @@
+use jito_restaking_client_common::log::{account_header, field, section_header, PrettyDisplay};
+
+use crate::accounts::Blacklisted;
+
+impl PrettyDisplay for Blacklisted {
+    fn pretty_display(&self) -> String {
+        let mut output = String::new();
+
+        output.push_str(&account_header("Blacklisted Account"));
+
+        output.push_str(&section_header("Basic Information"));
+        output.push_str(&field("Whitelist", self.whitelist));
+        output.push_str(&field("User", self.user));
+        output.push_str(&field("Bump", self.bump));
+        output.push_str(&field("Payer", self.payer));
+
+        output
+    }
+}
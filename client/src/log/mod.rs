@@ -1,2 +1,11 @@
+pub(crate) mod blacklisted;
 pub(crate) mod config;
 pub(crate) mod whitelist;
+pub(crate) mod whitelist_metadata;
+pub(crate) mod whitelist_user;
+
+/// Decode UTF-8 bytes padded with zeros, such as a whitelist name
+pub(crate) fn padded_str(bytes: &[u8]) -> String {
+    let len = bytes.iter().rposition(|b| *b != 0).map_or(0, |i| i + 1);
+    String::from_utf8_lossy(&bytes[..len]).into_owned()
+}
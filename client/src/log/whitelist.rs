@@ -11,6 +11,39 @@ impl PrettyDisplay for Whitelist {
         output.push_str(&section_header("Basic Information"));
         output.push_str(&field("Vault", self.vault));
         output.push_str(&field("Bump", self.bump));
+        output.push_str(&field("Payer", self.payer));
+        output.push_str(&field("Generation", self.generation));
+        output.push_str(&field("Version", self.version));
+        output.push_str(&field(
+            "Mode",
+            if self.mode == 1 {
+                "Deny List"
+            } else {
+                "Allow List"
+            },
+        ));
+        output.push_str(&field("Lockup Slots", self.lockup_slots));
+        output.push_str(&field("Burn Crank", self.burn_crank));
+
+        output.push_str(&section_header("Deposit Limits"));
+        output.push_str(&field("Min Deposit", self.min_deposit));
+        output.push_str(&field("Max Deposit", self.max_deposit));
+
+        output.push_str(&section_header("Rate Limits"));
+        output.push_str(&field("Window Epochs", self.rate_limit_epochs));
+        output.push_str(&field("Mint Limit", self.mint_limit));
+        output.push_str(&field("Enqueue Limit", self.enqueue_limit));
+        output.push_str(&field("User Mint Limit", self.user_mint_limit));
+        output.push_str(&field("User Enqueue Limit", self.user_enqueue_limit));
+        output.push_str(&field("Window Start Epoch", self.window_start_epoch));
+        output.push_str(&field("Window Minted", self.window_minted));
+        output.push_str(&field("Window Enqueued", self.window_enqueued));
+
+        output.push_str(&section_header("Statistics"));
+        output.push_str(&field("User Count", self.user_count));
+        output.push_str(&field("Total Minted In", self.total_minted_in));
+        output.push_str(&field("Total VRT Enqueued", self.total_vrt_enqueued));
+        output.push_str(&field("Total Tickets Burned", self.total_tickets_burned));
 
         output
     }
@@ -0,0 +1,23 @@
+use jito_restaking_client_common::log::{account_header, field, section_header, PrettyDisplay};
+
+use super::padded_str;
+use crate::accounts::WhitelistMetadata;
+
+impl PrettyDisplay for WhitelistMetadata {
+    fn pretty_display(&self) -> String {
+        let mut output = String::new();
+
+        output.push_str(&account_header("Whitelist Metadata Account"));
+
+        output.push_str(&section_header("Basic Information"));
+        output.push_str(&field("Whitelist", self.whitelist));
+        output.push_str(&field("Bump", self.bump));
+
+        output.push_str(&section_header("Metadata"));
+        output.push_str(&field("Name", padded_str(&self.name)));
+        output.push_str(&field("KYC Provider", self.kyc_provider));
+        output.push_str(&field("URI", padded_str(&self.uri)));
+
+        output
+    }
+}
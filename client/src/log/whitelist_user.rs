@@ -0,0 +1,41 @@
+use jito_restaking_client_common::log::{account_header, field, section_header, PrettyDisplay};
+
+use crate::accounts::WhitelistUser;
+
+impl PrettyDisplay for WhitelistUser {
+    fn pretty_display(&self) -> String {
+        let mut output = String::new();
+
+        output.push_str(&account_header("Whitelist User Account"));
+
+        output.push_str(&section_header("Basic Information"));
+        output.push_str(&field("Whitelist", self.whitelist));
+        output.push_str(&field("User", self.user));
+        output.push_str(&field("Bump", self.bump));
+        output.push_str(&field("Payer", self.payer));
+        output.push_str(&field("Generation", self.generation));
+        output.push_str(&field("Reference", hex::encode(self.reference)));
+        output.push_str(&field("Version", self.version));
+
+        output.push_str(&section_header("Activity"));
+        output.push_str(&field("Total Deposited", self.total_deposited));
+        output.push_str(&field("Total VRT Minted", self.total_vrt_minted));
+        output.push_str(&field("Total VRT Enqueued", self.total_vrt_enqueued));
+        output.push_str(&field(
+            "Total Tokens Withdrawn",
+            self.total_tokens_withdrawn,
+        ));
+        output.push_str(&field("Last Activity Slot", self.last_activity_slot));
+        output.push_str(&field("Last Mint Slot", self.last_mint_slot));
+        output.push_str(&field("Custodian", self.custodian));
+        output.push_str(&field("Permissionless Burn", self.permissionless_burn));
+        output.push_str(&field("Burn Delegate", self.burn_delegate));
+        output.push_str(&field("Min Deposit", self.min_deposit));
+        output.push_str(&field("Max Deposit", self.max_deposit));
+        output.push_str(&field("Window Start Epoch", self.window_start_epoch));
+        output.push_str(&field("Window Minted", self.window_minted));
+        output.push_str(&field("Window Enqueued", self.window_enqueued));
+
+        output
+    }
+}
@@ -0,0 +1,113 @@
+//! Exchange-rate quotes for minting and redeeming VRT at a vault's current state
+
+use jito_vault_client::accounts::{Config, Vault};
+
+const MAX_BPS: u128 = 10_000;
+
+/// Expected VRT received for depositing `amount_in` supported tokens, after the deposit fee
+pub fn quote_mint(vault: &Vault, amount_in: u64) -> Option<u64> {
+    vrt_out(
+        amount_in,
+        vault.tokens_deposited,
+        vault.vrt_supply,
+        vault.deposit_fee_bps,
+    )
+}
+
+/// Expected supported tokens received for burning a withdrawal ticket of `vrt_amount`, after the
+/// vault withdrawal fee and the program fee
+pub fn quote_withdrawal(vault: &Vault, config: &Config, vrt_amount: u64) -> Option<u64> {
+    tokens_out(
+        vrt_amount,
+        vault.tokens_deposited,
+        vault.vrt_supply,
+        vault.withdrawal_fee_bps,
+        config.program_fee_bps,
+    )
+}
+
+/// Lowest acceptable amount for a quote of `expected`, allowing `slippage_bps` of slippage
+pub fn min_amount_out(expected: u64, slippage_bps: u16) -> Option<u64> {
+    let remaining_bps = MAX_BPS.checked_sub(u128::from(slippage_bps))?;
+
+    u128::from(expected)
+        .checked_mul(remaining_bps)?
+        .checked_div(MAX_BPS)?
+        .try_into()
+        .ok()
+}
+
+fn fee(amount: u128, fee_bps: u16) -> Option<u128> {
+    Some(amount.checked_mul(u128::from(fee_bps))?.div_ceil(MAX_BPS))
+}
+
+fn vrt_out(
+    amount_in: u64,
+    tokens_deposited: u64,
+    vrt_supply: u64,
+    deposit_fee_bps: u16,
+) -> Option<u64> {
+    let vrt_minted = if vrt_supply == 0 {
+        u128::from(amount_in)
+    } else {
+        u128::from(amount_in)
+            .checked_mul(u128::from(vrt_supply))?
+            .checked_div(u128::from(tokens_deposited))?
+    };
+
+    vrt_minted
+        .checked_sub(fee(vrt_minted, deposit_fee_bps)?)?
+        .try_into()
+        .ok()
+}
+
+fn tokens_out(
+    vrt_amount: u64,
+    tokens_deposited: u64,
+    vrt_supply: u64,
+    withdrawal_fee_bps: u16,
+    program_fee_bps: u16,
+) -> Option<u64> {
+    if vrt_supply == 0 {
+        return Some(0);
+    }
+
+    let vrt_amount = u128::from(vrt_amount);
+    let vrt_burned = vrt_amount
+        .checked_sub(fee(vrt_amount, withdrawal_fee_bps)?)?
+        .checked_sub(fee(vrt_amount, program_fee_bps)?)?;
+
+    vrt_burned
+        .checked_mul(u128::from(tokens_deposited))?
+        .checked_div(u128::from(vrt_supply))?
+        .try_into()
+        .ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_vrt_out() {
+        assert_eq!(vrt_out(1_000, 0, 0, 0), Some(1_000));
+        assert_eq!(vrt_out(1_000, 2_000, 1_000, 0), Some(500));
+        assert_eq!(vrt_out(1_000, 2_000, 1_000, 100), Some(495));
+        assert_eq!(vrt_out(1_000, 0, 1_000, 0), None);
+    }
+
+    #[test]
+    fn test_tokens_out() {
+        assert_eq!(tokens_out(1_000, 0, 0, 0, 0), Some(0));
+        assert_eq!(tokens_out(1_000, 2_000, 1_000, 0, 0), Some(2_000));
+        assert_eq!(tokens_out(1_000, 2_000, 1_000, 100, 10), Some(1_978));
+    }
+
+    #[test]
+    fn test_min_amount_out() {
+        assert_eq!(min_amount_out(1_000, 0), Some(1_000));
+        assert_eq!(min_amount_out(1_000, 50), Some(995));
+        assert_eq!(min_amount_out(1_000, 10_000), Some(0));
+        assert_eq!(min_amount_out(1_000, 10_001), None);
+    }
+}
@@ -0,0 +1,22 @@
+//! Maturity of Jito Vault withdrawal tickets
+
+/// First epoch in which a ticket enqueued at `slot_unstaked` can be burned, the vault releases a
+/// ticket once a full epoch has passed after the one it was enqueued in
+pub fn burnable_epoch(slot_unstaked: u64, epoch_length: u64) -> Option<u64> {
+    slot_unstaked.checked_div(epoch_length)?.checked_add(2)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_burnable_epoch() {
+        assert_eq!(burnable_epoch(0, 100), Some(2));
+        assert_eq!(burnable_epoch(99, 100), Some(2));
+        assert_eq!(burnable_epoch(100, 100), Some(3));
+        assert_eq!(burnable_epoch(250, 100), Some(4));
+        assert_eq!(burnable_epoch(100, 0), None);
+        assert_eq!(burnable_epoch(u64::MAX, 1), None);
+    }
+}
@@ -0,0 +1,245 @@
+use bytemuck::{Pod, Zeroable};
+use jito_bytemuck::{types::PodU64, AccountDeserialize, Discriminator};
+use jito_vault_whitelist_sdk::error::VaultWhitelistError;
+use shank::ShankAccount;
+use solana_program::{account_info::AccountInfo, msg, program_error::ProgramError, pubkey::Pubkey};
+
+use crate::version;
+
+const RESERVED_SPACE_LEN: usize = 254;
+
+/// Marks a user as denied on a whitelist in deny-list mode.
+///
+/// Like `WhitelistUser`, a `Blacklisted` account is tied to the whitelist generation it was
+/// created in, so a marker left behind by a closed whitelist no longer denies the user.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Pod, Zeroable, AccountDeserialize, ShankAccount)]
+#[repr(C)]
+pub struct Blacklisted {
+    /// The base whitelist account that this entry is derived from
+    pub whitelist: Pubkey,
+
+    /// The address of the denied user
+    pub user: Pubkey,
+
+    /// Bump seed for the PDA
+    pub bump: u8,
+
+    /// The account that funded the rent, refunded when the user is removed
+    pub payer: Pubkey,
+
+    /// The generation of the whitelist this user was denied on
+    generation: PodU64,
+
+    /// Layout version of this account
+    version: u8,
+
+    /// Reserved space
+    reserved: [u8; 254],
+}
+
+impl Blacklisted {
+    /// Layout version written to new accounts
+    pub const VERSION: u8 = 1;
+
+    pub fn new(whitelist: Pubkey, user: Pubkey, payer: Pubkey, generation: u64, bump: u8) -> Self {
+        Self {
+            whitelist,
+            user,
+            bump,
+            payer,
+            generation: PodU64::from(generation),
+            version: Self::VERSION,
+            reserved: [0; RESERVED_SPACE_LEN],
+        }
+    }
+
+    /// The generation of the whitelist this user was denied on
+    pub fn generation(&self) -> u64 {
+        self.generation.into()
+    }
+
+    /// The layout version of this account
+    pub const fn version(&self) -> u8 {
+        self.version
+    }
+
+    /// Upgrade this account to the current layout version, returning whether it changed
+    pub fn migrate(&mut self) -> Result<bool, VaultWhitelistError> {
+        version::migrate(&mut self.version, Self::VERSION)
+    }
+
+    /// Check whitelist pubkey
+    pub fn check_whitelist(&self, whitelist: &Pubkey) -> Result<(), VaultWhitelistError> {
+        if self.whitelist.ne(whitelist) {
+            msg!("Whitelist pubkey does not match the provided whitelist pubkey");
+            return Err(VaultWhitelistError::InvalidWhitelist);
+        }
+
+        Ok(())
+    }
+
+    /// Check user pubkey
+    pub fn check_user(&self, user: &Pubkey) -> Result<(), VaultWhitelistError> {
+        if self.user.ne(user) {
+            msg!("User pubkey does not match the provided user pubkey");
+            return Err(VaultWhitelistError::InvalidWhitelistUser);
+        }
+
+        Ok(())
+    }
+
+    /// Check that the rent refund goes to the recorded payer
+    pub fn check_rent_receiver(&self, rent_receiver: &Pubkey) -> Result<(), VaultWhitelistError> {
+        if self.payer.ne(rent_receiver) {
+            msg!("Rent receiver does not match the blacklisted payer");
+            return Err(VaultWhitelistError::InvalidRentReceiver);
+        }
+
+        Ok(())
+    }
+
+    /// Seeds of Blacklisted Account
+    pub fn seeds(whitelist: &Pubkey, user: &Pubkey) -> Vec<Vec<u8>> {
+        vec![
+            b"blacklisted".to_vec(),
+            whitelist.to_bytes().to_vec(),
+            user.to_bytes().to_vec(),
+        ]
+    }
+
+    /// Find the program address of Blacklisted Account
+    pub fn find_program_address(
+        program_id: &Pubkey,
+        whitelist: &Pubkey,
+        user: &Pubkey,
+    ) -> (Pubkey, u8, Vec<Vec<u8>>) {
+        let seeds = Self::seeds(whitelist, user);
+        let seeds_iter: Vec<_> = seeds.iter().map(|s| s.as_slice()).collect();
+        let (pda, bump) = Pubkey::find_program_address(&seeds_iter, program_id);
+        (pda, bump, seeds)
+    }
+
+    /// Load Blacklisted Account
+    pub fn load(
+        program_id: &Pubkey,
+        account: &AccountInfo,
+        whitelist: &Pubkey,
+        user: &Pubkey,
+        expect_writable: bool,
+    ) -> Result<(), ProgramError> {
+        if account.owner.ne(program_id) {
+            msg!("Blacklisted account has an invalid owner");
+            return Err(ProgramError::InvalidAccountOwner);
+        }
+        if account.data_is_empty() {
+            msg!("Blacklisted account data is empty");
+            return Err(ProgramError::InvalidAccountData);
+        }
+        if expect_writable && !account.is_writable {
+            msg!("Blacklisted account is not writable");
+            return Err(ProgramError::InvalidAccountData);
+        }
+        if account.data.borrow()[0].ne(&Self::DISCRIMINATOR) {
+            msg!("Blacklisted account discriminator is invalid");
+            return Err(ProgramError::InvalidAccountData);
+        }
+        if account
+            .key
+            .ne(&Self::find_program_address(program_id, whitelist, user).0)
+        {
+            msg!("Blacklisted account is not at the correct PDA");
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        Ok(())
+    }
+
+    /// Check that `user` is not denied on the whitelist
+    ///
+    /// The account must be at the user's Blacklisted PDA, and the user is denied when it exists and
+    /// belongs to the current `generation` of the whitelist.
+    pub fn check_not_blacklisted(
+        program_id: &Pubkey,
+        account: &AccountInfo,
+        whitelist: &Pubkey,
+        user: &Pubkey,
+        generation: u64,
+    ) -> Result<(), ProgramError> {
+        if account
+            .key
+            .ne(&Self::find_program_address(program_id, whitelist, user).0)
+        {
+            msg!("Blacklisted account is not at the correct PDA");
+            return Err(ProgramError::InvalidAccountData);
+        }
+        if account.owner.ne(program_id) || account.data_is_empty() {
+            return Ok(());
+        }
+
+        let account_data = account.data.borrow();
+        let blacklisted = Self::try_from_slice_unchecked(&account_data)?;
+
+        // A marker left behind by an earlier whitelist at the same PDA does not deny the user
+        if blacklisted.generation() == generation {
+            msg!("User {} is blacklisted", user);
+            return Err(VaultWhitelistError::UserBlacklisted.into());
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_blacklisted_no_padding() {
+        let blacklisted = std::mem::size_of::<Blacklisted>();
+        let sum_of_fields = size_of::<Pubkey>() + // whitelist
+            size_of::<Pubkey>() + // user
+            size_of::<u8>() + // bump
+            size_of::<Pubkey>() + // payer
+            size_of::<PodU64>() + // generation
+            size_of::<u8>() + // version
+            RESERVED_SPACE_LEN; // reserved
+        assert_eq!(blacklisted, sum_of_fields);
+    }
+
+    #[test]
+    fn test_check_rent_receiver() {
+        let payer = Pubkey::new_unique();
+        let blacklisted =
+            Blacklisted::new(Pubkey::new_unique(), Pubkey::new_unique(), payer, 0, 255);
+
+        assert!(blacklisted.check_rent_receiver(&payer).is_ok());
+        assert_eq!(
+            blacklisted.check_rent_receiver(&Pubkey::new_unique()),
+            Err(VaultWhitelistError::InvalidRentReceiver)
+        );
+    }
+
+    #[test]
+    fn test_migrate_blacklisted() {
+        let blacklisted = Blacklisted::new(
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            1,
+            255,
+        );
+        assert_eq!(blacklisted.version(), Blacklisted::VERSION);
+
+        let mut data = vec![0; 8];
+        data[0] = Blacklisted::DISCRIMINATOR;
+        data.extend_from_slice(bytemuck::bytes_of(&blacklisted));
+        data[8 + std::mem::offset_of!(Blacklisted, version)] = version::LEGACY_VERSION;
+
+        let older = Blacklisted::try_from_slice_unchecked_mut(&mut data).unwrap();
+        assert_eq!(older.version(), version::LEGACY_VERSION);
+
+        assert_eq!(older.migrate(), Ok(true));
+        assert_eq!(older.migrate(), Ok(false));
+        assert_eq!(*older, blacklisted);
+    }
+}
@@ -1,11 +1,14 @@
 use bytemuck::{Pod, Zeroable};
-use jito_bytemuck::{AccountDeserialize, Discriminator};
+use jito_bytemuck::{types::PodU64, AccountDeserialize, Discriminator};
+use jito_vault_whitelist_sdk::error::VaultWhitelistError;
 use shank::ShankAccount;
 use solana_program::msg;
 use solana_program::pubkey::Pubkey;
 use solana_program::{account_info::AccountInfo, program_error::ProgramError};
 
-const RESERVED_SPACE_LEN: usize = 263;
+use crate::version;
+
+const RESERVED_SPACE_LEN: usize = 254;
 
 /// The vault whitelist configuration for the vault whitelist program
 /// Manages program-wide settings and state.
@@ -21,21 +24,58 @@ pub struct Config {
     /// The bump seed for the PDA
     pub bump: u8,
 
+    /// The generation assigned to the most recently initialized whitelist
+    whitelist_generation: PodU64,
+
+    /// Layout version of this account, zero for accounts created before versioning
+    version: u8,
+
     /// Reserved space
-    reserved: [u8; 263],
+    reserved: [u8; 254],
 }
 
 impl Config {
+    /// Layout version written to new accounts
+    pub const VERSION: u8 = 1;
+
     /// Initialize Config
-    pub const fn new(admin: Pubkey, vault_program: Pubkey, bump: u8) -> Self {
+    pub fn new(admin: Pubkey, vault_program: Pubkey, bump: u8) -> Self {
         Self {
             admin,
             vault_program,
             bump,
+            whitelist_generation: PodU64::from(0),
+            version: Self::VERSION,
             reserved: [0; RESERVED_SPACE_LEN],
         }
     }
 
+    /// The generation assigned to the most recently initialized whitelist
+    pub fn whitelist_generation(&self) -> u64 {
+        self.whitelist_generation.into()
+    }
+
+    /// Advance and return the generation for a newly initialized whitelist
+    pub fn next_whitelist_generation(&mut self) -> Result<u64, VaultWhitelistError> {
+        let generation = self
+            .whitelist_generation()
+            .checked_add(1)
+            .ok_or(VaultWhitelistError::ArithmeticOverflow)?;
+        self.whitelist_generation = PodU64::from(generation);
+
+        Ok(generation)
+    }
+
+    /// The layout version of this account
+    pub const fn version(&self) -> u8 {
+        self.version
+    }
+
+    /// Upgrade this account to the current layout version, returning whether it changed
+    pub fn migrate(&mut self) -> Result<bool, VaultWhitelistError> {
+        version::migrate(&mut self.version, Self::VERSION)
+    }
+
     /// Seeds of Config Account
     pub fn seeds() -> Vec<Vec<u8>> {
         vec![b"config".to_vec()]
@@ -90,7 +130,40 @@ mod tests {
         let sum_of_fields = size_of::<Pubkey>() + // admin
             size_of::<Pubkey>() + // vault_program
             size_of::<u8>() + // bump
+            size_of::<PodU64>() + // whitelist_generation
+            size_of::<u8>() + // version
             RESERVED_SPACE_LEN; // reserved
         assert_eq!(config, sum_of_fields);
     }
+
+    #[test]
+    fn test_next_whitelist_generation() {
+        let mut config = Config::new(Pubkey::new_unique(), Pubkey::new_unique(), 255);
+        assert_eq!(config.whitelist_generation(), 0);
+
+        assert_eq!(config.next_whitelist_generation(), Ok(1));
+        assert_eq!(config.next_whitelist_generation(), Ok(2));
+        assert_eq!(config.whitelist_generation(), 2);
+    }
+
+    #[test]
+    fn test_migrate_legacy_config() {
+        let admin = Pubkey::new_unique();
+        let config = Config::new(admin, Pubkey::new_unique(), 255);
+        assert_eq!(config.version(), Config::VERSION);
+
+        // Accounts created before versioning hold zero in the version byte
+        let mut data = vec![0; 8];
+        data[0] = Config::DISCRIMINATOR;
+        data.extend_from_slice(bytemuck::bytes_of(&config));
+        data[8 + std::mem::offset_of!(Config, version)] = version::LEGACY_VERSION;
+
+        let legacy = Config::try_from_slice_unchecked_mut(&mut data).unwrap();
+        assert_eq!(legacy.version(), version::LEGACY_VERSION);
+        assert_eq!(legacy.admin, admin);
+
+        assert_eq!(legacy.migrate(), Ok(true));
+        assert_eq!(legacy.migrate(), Ok(false));
+        assert!(*legacy == config);
+    }
 }
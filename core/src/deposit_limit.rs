@@ -0,0 +1,73 @@
+use jito_vault_whitelist_sdk::error::VaultWhitelistError;
+use solana_program::msg;
+
+/// Check that a pair of deposit limits is consistent. A limit of zero is unbounded.
+pub fn check_limits(min_deposit: u64, max_deposit: u64) -> Result<(), VaultWhitelistError> {
+    if max_deposit != 0 && min_deposit > max_deposit {
+        msg!(
+            "Minimum deposit {} exceeds maximum deposit {}",
+            min_deposit,
+            max_deposit
+        );
+        return Err(VaultWhitelistError::InvalidDepositLimits);
+    }
+
+    Ok(())
+}
+
+/// Check that `amount_in` is within the deposit limits. A limit of zero is unbounded.
+pub fn check_deposit(
+    amount_in: u64,
+    min_deposit: u64,
+    max_deposit: u64,
+) -> Result<(), VaultWhitelistError> {
+    if amount_in < min_deposit {
+        msg!(
+            "Deposit of {} is below the minimum of {}",
+            amount_in,
+            min_deposit
+        );
+        return Err(VaultWhitelistError::DepositTooSmall);
+    }
+    if max_deposit != 0 && amount_in > max_deposit {
+        msg!(
+            "Deposit of {} is above the maximum of {}",
+            amount_in,
+            max_deposit
+        );
+        return Err(VaultWhitelistError::DepositTooLarge);
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_check_limits() {
+        assert!(check_limits(0, 0).is_ok());
+        assert!(check_limits(100, 0).is_ok());
+        assert!(check_limits(100, 100).is_ok());
+        assert_eq!(
+            check_limits(101, 100),
+            Err(VaultWhitelistError::InvalidDepositLimits)
+        );
+    }
+
+    #[test]
+    fn test_check_deposit() {
+        assert!(check_deposit(1, 0, 0).is_ok());
+        assert!(check_deposit(100, 100, 200).is_ok());
+        assert!(check_deposit(200, 100, 200).is_ok());
+        assert_eq!(
+            check_deposit(99, 100, 200),
+            Err(VaultWhitelistError::DepositTooSmall)
+        );
+        assert_eq!(
+            check_deposit(201, 100, 200),
+            Err(VaultWhitelistError::DepositTooLarge)
+        );
+    }
+}
@@ -1,6 +1,9 @@
 use jito_bytemuck::Discriminator;
 
-use crate::{config::Config, whitelist::Whitelist, whitelist_user::WhitelistUser};
+use crate::{
+    blacklisted::Blacklisted, config::Config, whitelist::Whitelist,
+    whitelist_metadata::WhitelistMetadata, whitelist_user::WhitelistUser,
+};
 
 /// Discriminators for Vault Whitelist accounts
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -8,6 +11,8 @@ pub enum VaultWhitelistDiscriminator {
     Config = 0,
     Whitelist = 1,
     WhitelistUser = 2,
+    Blacklisted = 3,
+    WhitelistMetadata = 4,
 }
 
 impl Discriminator for Config {
@@ -21,3 +26,11 @@ impl Discriminator for Whitelist {
 impl Discriminator for WhitelistUser {
     const DISCRIMINATOR: u8 = VaultWhitelistDiscriminator::WhitelistUser as u8;
 }
+
+impl Discriminator for Blacklisted {
+    const DISCRIMINATOR: u8 = VaultWhitelistDiscriminator::Blacklisted as u8;
+}
+
+impl Discriminator for WhitelistMetadata {
+    const DISCRIMINATOR: u8 = VaultWhitelistDiscriminator::WhitelistMetadata as u8;
+}
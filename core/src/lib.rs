@@ -1,4 +1,9 @@
+pub mod blacklisted;
 pub mod config;
+pub mod deposit_limit;
 pub mod discriminator;
+pub mod rate_limit;
+pub mod version;
 pub mod whitelist;
+pub mod whitelist_metadata;
 pub mod whitelist_user;
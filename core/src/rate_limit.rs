@@ -0,0 +1,53 @@
+use jito_vault_whitelist_sdk::error::VaultWhitelistError;
+use solana_program::msg;
+
+/// Returns the first epoch of the rate limit window of `window_epochs` epochs containing `epoch`
+///
+/// Windows are aligned to multiples of `window_epochs`, so every account shares the same windows.
+pub const fn window_start_epoch(window_epochs: u64, epoch: u64) -> u64 {
+    match epoch.checked_rem(window_epochs) {
+        Some(offset) => epoch.saturating_sub(offset),
+        None => epoch,
+    }
+}
+
+/// Returns the usage of a rate limit window after adding `amount`, failing when it exceeds
+/// `limit`. A `limit` of zero is unlimited.
+pub fn consume(used: u64, amount: u64, limit: u64) -> Result<u64, VaultWhitelistError> {
+    let used = used
+        .checked_add(amount)
+        .ok_or(VaultWhitelistError::ArithmeticOverflow)?;
+
+    if limit != 0 && used > limit {
+        msg!("Rate limit of {} exceeded", limit);
+        return Err(VaultWhitelistError::RateLimitExceeded);
+    }
+
+    Ok(used)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_window_start_epoch() {
+        assert_eq!(window_start_epoch(1, 7), 7);
+        assert_eq!(window_start_epoch(3, 7), 6);
+        assert_eq!(window_start_epoch(3, 9), 9);
+    }
+
+    #[test]
+    fn test_consume() {
+        assert_eq!(consume(0, 100, 0), Ok(100));
+        assert_eq!(consume(50, 50, 100), Ok(100));
+        assert_eq!(
+            consume(50, 51, 100),
+            Err(VaultWhitelistError::RateLimitExceeded)
+        );
+        assert_eq!(
+            consume(u64::MAX, 1, 0),
+            Err(VaultWhitelistError::ArithmeticOverflow)
+        );
+    }
+}
@@ -0,0 +1,46 @@
+use jito_vault_whitelist_sdk::error::VaultWhitelistError;
+
+/// Version of accounts created before the version field was carved from reserved space
+pub const LEGACY_VERSION: u8 = 0;
+
+/// Upgrade `version` to `current`, returning whether it changed
+///
+/// Every field added so far was carved from zeroed reserved space, so an older account already
+/// reads as the current layout with those fields at their defaults.
+pub fn migrate(version: &mut u8, current: u8) -> Result<bool, VaultWhitelistError> {
+    if *version > current {
+        return Err(VaultWhitelistError::UnsupportedAccountVersion);
+    }
+    if *version == current {
+        return Ok(false);
+    }
+
+    *version = current;
+
+    Ok(true)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_migrate() {
+        let mut version = LEGACY_VERSION;
+        assert_eq!(migrate(&mut version, 2), Ok(true));
+        assert_eq!(version, 2);
+
+        assert_eq!(migrate(&mut version, 2), Ok(false));
+        assert_eq!(version, 2);
+
+        let mut version = 1;
+        assert_eq!(migrate(&mut version, 2), Ok(true));
+        assert_eq!(version, 2);
+
+        let mut version = 3;
+        assert_eq!(
+            migrate(&mut version, 2),
+            Err(VaultWhitelistError::UnsupportedAccountVersion)
+        );
+    }
+}
@@ -1,12 +1,37 @@
 use bytemuck::{Pod, Zeroable};
-use jito_bytemuck::{AccountDeserialize, Discriminator};
+use jito_bytemuck::{types::PodU64, AccountDeserialize, Discriminator};
 use jito_vault_whitelist_sdk::error::VaultWhitelistError;
 use shank::ShankAccount;
 use solana_program::msg;
 use solana_program::pubkey::Pubkey;
 use solana_program::{account_info::AccountInfo, program_error::ProgramError};
 
-const RESERVED_SPACE_LEN: usize = 263;
+use crate::{deposit_limit, rate_limit, version, whitelist_user::WhitelistUser};
+
+const RESERVED_SPACE_LEN: usize = 69;
+
+/// Who may use a whitelist to mint and redeem
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u8)]
+pub enum WhitelistMode {
+    /// Only users with a `WhitelistUser` account
+    AllowList = 0,
+
+    /// Everyone except users with a `Blacklisted` account
+    DenyList = 1,
+}
+
+impl TryFrom<u8> for WhitelistMode {
+    type Error = VaultWhitelistError;
+
+    fn try_from(mode: u8) -> Result<Self, Self::Error> {
+        match mode {
+            0 => Ok(Self::AllowList),
+            1 => Ok(Self::DenyList),
+            _ => Err(VaultWhitelistError::InvalidWhitelistMode),
+        }
+    }
+}
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Pod, Zeroable, AccountDeserialize, ShankAccount)]
 #[repr(C)]
@@ -17,20 +42,366 @@ pub struct Whitelist {
     /// Bump seed for the PDA
     bump: u8,
 
+    /// The account that funded the rent, refunded when the whitelist is closed
+    pub payer: Pubkey,
+
+    /// The generation of this whitelist, distinguishing it from earlier whitelists at the same PDA
+    generation: PodU64,
+
+    /// Number of users currently on the whitelist
+    user_count: PodU64,
+
+    /// Total supported tokens deposited into the vault through this whitelist
+    total_minted_in: PodU64,
+
+    /// Total VRT enqueued for withdrawal through this whitelist
+    total_vrt_enqueued: PodU64,
+
+    /// Number of withdrawal tickets burned through this whitelist
+    total_tickets_burned: PodU64,
+
+    /// The [`WhitelistMode`] of this whitelist
+    mode: u8,
+
+    /// Number of slots after a user's last mint before the user may enqueue a withdrawal
+    lockup_slots: PodU64,
+
+    /// Length of a rate limit window in epochs, zero disables rate limits
+    rate_limit_epochs: PodU64,
+
+    /// Maximum supported tokens minted through this whitelist per window, zero is unlimited
+    mint_limit: PodU64,
+
+    /// Maximum VRT enqueued through this whitelist per window, zero is unlimited
+    enqueue_limit: PodU64,
+
+    /// Maximum supported tokens minted by each user per window, zero is unlimited
+    user_mint_limit: PodU64,
+
+    /// Maximum VRT enqueued by each user per window, zero is unlimited
+    user_enqueue_limit: PodU64,
+
+    /// First epoch of the current rate limit window
+    window_start_epoch: PodU64,
+
+    /// Supported tokens minted through this whitelist in the current window
+    window_minted: PodU64,
+
+    /// VRT enqueued through this whitelist in the current window
+    window_enqueued: PodU64,
+
+    /// Smallest supported token amount accepted by a single mint, zero is unbounded
+    min_deposit: PodU64,
+
+    /// Largest supported token amount accepted by a single mint, zero is unbounded
+    max_deposit: PodU64,
+
+    /// Key allowed to burn matured withdrawal tickets on behalf of stakers, default disables it
+    pub burn_crank: Pubkey,
+
+    /// Layout version of this account, zero for accounts created before versioning
+    version: u8,
+
     /// Reserved space
-    reserved: [u8; 263],
+    reserved: [u8; 69],
 }
 
 impl Whitelist {
+    /// Layout version written to new accounts
+    pub const VERSION: u8 = 1;
+
     /// Initiallize Whitelist
-    pub const fn new(vault: Pubkey, bump: u8) -> Self {
+    pub fn new(vault: Pubkey, payer: Pubkey, generation: u64, bump: u8) -> Self {
         Self {
             vault,
             bump,
+            payer,
+            generation: PodU64::from(generation),
+            user_count: PodU64::from(0),
+            total_minted_in: PodU64::from(0),
+            total_vrt_enqueued: PodU64::from(0),
+            total_tickets_burned: PodU64::from(0),
+            mode: WhitelistMode::AllowList as u8,
+            lockup_slots: PodU64::from(0),
+            rate_limit_epochs: PodU64::from(0),
+            mint_limit: PodU64::from(0),
+            enqueue_limit: PodU64::from(0),
+            user_mint_limit: PodU64::from(0),
+            user_enqueue_limit: PodU64::from(0),
+            window_start_epoch: PodU64::from(0),
+            window_minted: PodU64::from(0),
+            window_enqueued: PodU64::from(0),
+            min_deposit: PodU64::from(0),
+            max_deposit: PodU64::from(0),
+            burn_crank: Pubkey::default(),
+            version: Self::VERSION,
             reserved: [0; RESERVED_SPACE_LEN],
         }
     }
 
+    /// The generation of this whitelist
+    pub fn generation(&self) -> u64 {
+        self.generation.into()
+    }
+
+    /// Number of users currently on the whitelist
+    pub fn user_count(&self) -> u64 {
+        self.user_count.into()
+    }
+
+    /// Total supported tokens deposited into the vault through this whitelist
+    pub fn total_minted_in(&self) -> u64 {
+        self.total_minted_in.into()
+    }
+
+    /// Total VRT enqueued for withdrawal through this whitelist
+    pub fn total_vrt_enqueued(&self) -> u64 {
+        self.total_vrt_enqueued.into()
+    }
+
+    /// Number of withdrawal tickets burned through this whitelist
+    pub fn total_tickets_burned(&self) -> u64 {
+        self.total_tickets_burned.into()
+    }
+
+    /// The mode of this whitelist
+    pub fn mode(&self) -> Result<WhitelistMode, VaultWhitelistError> {
+        WhitelistMode::try_from(self.mode)
+    }
+
+    /// Set the mode of this whitelist
+    pub fn set_mode(&mut self, mode: WhitelistMode) {
+        self.mode = mode as u8;
+    }
+
+    /// Number of slots after a user's last mint before the user may enqueue a withdrawal
+    pub fn lockup_slots(&self) -> u64 {
+        self.lockup_slots.into()
+    }
+
+    /// Set the lockup after a user's last mint
+    pub fn set_lockup_slots(&mut self, lockup_slots: u64) {
+        self.lockup_slots = PodU64::from(lockup_slots);
+    }
+
+    /// Length of a rate limit window in epochs, zero disables rate limits
+    pub fn rate_limit_epochs(&self) -> u64 {
+        self.rate_limit_epochs.into()
+    }
+
+    /// Maximum supported tokens minted through this whitelist per window
+    pub fn mint_limit(&self) -> u64 {
+        self.mint_limit.into()
+    }
+
+    /// Maximum VRT enqueued through this whitelist per window
+    pub fn enqueue_limit(&self) -> u64 {
+        self.enqueue_limit.into()
+    }
+
+    /// Maximum supported tokens minted by each user per window
+    pub fn user_mint_limit(&self) -> u64 {
+        self.user_mint_limit.into()
+    }
+
+    /// Maximum VRT enqueued by each user per window
+    pub fn user_enqueue_limit(&self) -> u64 {
+        self.user_enqueue_limit.into()
+    }
+
+    /// First epoch of the current rate limit window
+    pub fn window_start_epoch(&self) -> u64 {
+        self.window_start_epoch.into()
+    }
+
+    /// Supported tokens minted through this whitelist in the current window
+    pub fn window_minted(&self) -> u64 {
+        self.window_minted.into()
+    }
+
+    /// VRT enqueued through this whitelist in the current window
+    pub fn window_enqueued(&self) -> u64 {
+        self.window_enqueued.into()
+    }
+
+    /// Set the rate limits, usage in the current window is kept
+    pub fn set_rate_limit(
+        &mut self,
+        rate_limit_epochs: u64,
+        mint_limit: u64,
+        enqueue_limit: u64,
+        user_mint_limit: u64,
+        user_enqueue_limit: u64,
+    ) {
+        self.rate_limit_epochs = PodU64::from(rate_limit_epochs);
+        self.mint_limit = PodU64::from(mint_limit);
+        self.enqueue_limit = PodU64::from(enqueue_limit);
+        self.user_mint_limit = PodU64::from(user_mint_limit);
+        self.user_enqueue_limit = PodU64::from(user_enqueue_limit);
+    }
+
+    /// Returns the first epoch of the rate limit window containing `epoch`, or `None` when rate
+    /// limits are disabled
+    pub fn rate_limit_window(&self, epoch: u64) -> Option<u64> {
+        match self.rate_limit_epochs() {
+            0 => None,
+            rate_limit_epochs => Some(rate_limit::window_start_epoch(rate_limit_epochs, epoch)),
+        }
+    }
+
+    /// Start a new rate limit window when `window_start_epoch` differs from the current one
+    fn roll_window(&mut self, window_start_epoch: u64) {
+        if self.window_start_epoch() != window_start_epoch {
+            self.window_start_epoch = PodU64::from(window_start_epoch);
+            self.window_minted = PodU64::from(0);
+            self.window_enqueued = PodU64::from(0);
+        }
+    }
+
+    /// Count supported tokens minted against the rate limit window starting at
+    /// `window_start_epoch`
+    pub fn consume_mint_limit(
+        &mut self,
+        amount_in: u64,
+        window_start_epoch: u64,
+    ) -> Result<(), VaultWhitelistError> {
+        self.roll_window(window_start_epoch);
+
+        let window_minted =
+            rate_limit::consume(self.window_minted(), amount_in, self.mint_limit())?;
+        self.window_minted = PodU64::from(window_minted);
+
+        Ok(())
+    }
+
+    /// Count VRT enqueued against the rate limit window starting at `window_start_epoch`
+    pub fn consume_enqueue_limit(
+        &mut self,
+        vrt_amount: u64,
+        window_start_epoch: u64,
+    ) -> Result<(), VaultWhitelistError> {
+        self.roll_window(window_start_epoch);
+
+        let window_enqueued =
+            rate_limit::consume(self.window_enqueued(), vrt_amount, self.enqueue_limit())?;
+        self.window_enqueued = PodU64::from(window_enqueued);
+
+        Ok(())
+    }
+
+    /// Smallest supported token amount accepted by a single mint
+    pub fn min_deposit(&self) -> u64 {
+        self.min_deposit.into()
+    }
+
+    /// Largest supported token amount accepted by a single mint
+    pub fn max_deposit(&self) -> u64 {
+        self.max_deposit.into()
+    }
+
+    /// Set the deposit limits applied to every user without an override
+    pub fn set_deposit_limits(
+        &mut self,
+        min_deposit: u64,
+        max_deposit: u64,
+    ) -> Result<(), VaultWhitelistError> {
+        deposit_limit::check_limits(min_deposit, max_deposit)?;
+
+        self.min_deposit = PodU64::from(min_deposit);
+        self.max_deposit = PodU64::from(max_deposit);
+
+        Ok(())
+    }
+
+    /// Check that `amount_in` is within the deposit limits, preferring the non-zero limits of
+    /// `whitelist_user` over those of the whitelist
+    pub fn check_deposit(
+        &self,
+        amount_in: u64,
+        whitelist_user: Option<&WhitelistUser>,
+    ) -> Result<(), VaultWhitelistError> {
+        let (mut min_deposit, mut max_deposit) = (self.min_deposit(), self.max_deposit());
+        if let Some(whitelist_user) = whitelist_user {
+            if whitelist_user.min_deposit() != 0 {
+                min_deposit = whitelist_user.min_deposit();
+            }
+            if whitelist_user.max_deposit() != 0 {
+                max_deposit = whitelist_user.max_deposit();
+            }
+        }
+
+        deposit_limit::check_deposit(amount_in, min_deposit, max_deposit)
+    }
+
+    /// Set the key allowed to burn withdrawal tickets on behalf of stakers
+    pub fn set_burn_crank(&mut self, burn_crank: Pubkey) {
+        self.burn_crank = burn_crank;
+    }
+
+    /// Check that `burn_crank` may burn withdrawal tickets on behalf of stakers
+    pub fn check_burn_crank(&self, burn_crank: &Pubkey) -> Result<(), VaultWhitelistError> {
+        if self.burn_crank.eq(&Pubkey::default()) || self.burn_crank.ne(burn_crank) {
+            msg!("Burn crank does not match the whitelist burn crank");
+            return Err(VaultWhitelistError::InvalidBurnCrank);
+        }
+
+        Ok(())
+    }
+
+    /// Record a user added to the whitelist
+    pub fn increment_user_count(&mut self) -> Result<(), VaultWhitelistError> {
+        let user_count = self
+            .user_count()
+            .checked_add(1)
+            .ok_or(VaultWhitelistError::ArithmeticOverflow)?;
+        self.user_count = PodU64::from(user_count);
+
+        Ok(())
+    }
+
+    /// Record a user removed from the whitelist
+    ///
+    /// Saturates at zero, since users added before the counter existed were never counted.
+    pub fn decrement_user_count(&mut self) {
+        self.user_count = PodU64::from(self.user_count().saturating_sub(1));
+    }
+
+    /// Record supported tokens deposited through a mint
+    pub fn record_mint(&mut self, amount_in: u64) -> Result<(), VaultWhitelistError> {
+        let total_minted_in = self
+            .total_minted_in()
+            .checked_add(amount_in)
+            .ok_or(VaultWhitelistError::ArithmeticOverflow)?;
+        self.total_minted_in = PodU64::from(total_minted_in);
+
+        Ok(())
+    }
+
+    /// Record VRT enqueued for withdrawal
+    pub fn record_enqueue_withdrawal(
+        &mut self,
+        vrt_amount: u64,
+    ) -> Result<(), VaultWhitelistError> {
+        let total_vrt_enqueued = self
+            .total_vrt_enqueued()
+            .checked_add(vrt_amount)
+            .ok_or(VaultWhitelistError::ArithmeticOverflow)?;
+        self.total_vrt_enqueued = PodU64::from(total_vrt_enqueued);
+
+        Ok(())
+    }
+
+    /// Record a burned withdrawal ticket
+    pub fn record_burn_withdrawal_ticket(&mut self) -> Result<(), VaultWhitelistError> {
+        let total_tickets_burned = self
+            .total_tickets_burned()
+            .checked_add(1)
+            .ok_or(VaultWhitelistError::ArithmeticOverflow)?;
+        self.total_tickets_burned = PodU64::from(total_tickets_burned);
+
+        Ok(())
+    }
+
     /// Check Vault
     pub fn check_vault(&self, vault: &Pubkey) -> Result<(), VaultWhitelistError> {
         if self.vault.ne(vault) {
@@ -41,6 +412,38 @@ impl Whitelist {
         Ok(())
     }
 
+    /// Check that the rent refund goes to the recorded payer.
+    ///
+    /// Whitelists created before the payer was recorded were funded by the vault admin.
+    pub fn check_rent_receiver(
+        &self,
+        rent_receiver: &Pubkey,
+        vault_admin: &Pubkey,
+    ) -> Result<(), VaultWhitelistError> {
+        let payer = if self.payer.eq(&Pubkey::default()) {
+            vault_admin
+        } else {
+            &self.payer
+        };
+
+        if payer.ne(rent_receiver) {
+            msg!("Rent receiver does not match the whitelist payer");
+            return Err(VaultWhitelistError::InvalidRentReceiver);
+        }
+
+        Ok(())
+    }
+
+    /// The layout version of this account
+    pub const fn version(&self) -> u8 {
+        self.version
+    }
+
+    /// Upgrade this account to the current layout version, returning whether it changed
+    pub fn migrate(&mut self) -> Result<bool, VaultWhitelistError> {
+        version::migrate(&mut self.version, Self::VERSION)
+    }
+
     /// Seeds of Whitelist Account
     pub fn seeds(vault: &Pubkey) -> Vec<Vec<u8>> {
         vec![b"whitelist".to_vec(), vault.to_bytes().to_vec()]
@@ -98,7 +501,193 @@ mod tests {
         let whitelist = std::mem::size_of::<Whitelist>();
         let sum_of_fields = size_of::<Pubkey>() + // vault
             size_of::<u8>() + // bump
+            size_of::<Pubkey>() + // payer
+            size_of::<PodU64>() + // generation
+            size_of::<PodU64>() + // user_count
+            size_of::<PodU64>() + // total_minted_in
+            size_of::<PodU64>() + // total_vrt_enqueued
+            size_of::<PodU64>() + // total_tickets_burned
+            size_of::<u8>() + // mode
+            size_of::<PodU64>() + // lockup_slots
+            size_of::<PodU64>() + // rate_limit_epochs
+            size_of::<PodU64>() + // mint_limit
+            size_of::<PodU64>() + // enqueue_limit
+            size_of::<PodU64>() + // user_mint_limit
+            size_of::<PodU64>() + // user_enqueue_limit
+            size_of::<PodU64>() + // window_start_epoch
+            size_of::<PodU64>() + // window_minted
+            size_of::<PodU64>() + // window_enqueued
+            size_of::<PodU64>() + // min_deposit
+            size_of::<PodU64>() + // max_deposit
+            size_of::<Pubkey>() + // burn_crank
+            size_of::<u8>() + // version
             RESERVED_SPACE_LEN; // reserved
         assert_eq!(whitelist, sum_of_fields);
     }
+
+    #[test]
+    fn test_check_rent_receiver() {
+        let vault_admin = Pubkey::new_unique();
+        let payer = Pubkey::new_unique();
+
+        let whitelist = Whitelist::new(Pubkey::new_unique(), payer, 1, 255);
+        assert!(whitelist.check_rent_receiver(&payer, &vault_admin).is_ok());
+        assert_eq!(
+            whitelist.check_rent_receiver(&vault_admin, &vault_admin),
+            Err(VaultWhitelistError::InvalidRentReceiver)
+        );
+
+        let legacy_whitelist = Whitelist::new(Pubkey::new_unique(), Pubkey::default(), 0, 255);
+        assert!(legacy_whitelist
+            .check_rent_receiver(&vault_admin, &vault_admin)
+            .is_ok());
+    }
+
+    #[test]
+    fn test_whitelist_statistics() {
+        let mut whitelist = Whitelist::new(Pubkey::new_unique(), Pubkey::new_unique(), 1, 255);
+
+        whitelist.increment_user_count().unwrap();
+        whitelist.increment_user_count().unwrap();
+        whitelist.decrement_user_count();
+        assert_eq!(whitelist.user_count(), 1);
+
+        whitelist.decrement_user_count();
+        whitelist.decrement_user_count();
+        assert_eq!(whitelist.user_count(), 0);
+
+        whitelist.record_mint(100).unwrap();
+        whitelist.record_mint(50).unwrap();
+        assert_eq!(whitelist.total_minted_in(), 150);
+
+        whitelist.record_enqueue_withdrawal(40).unwrap();
+        assert_eq!(whitelist.total_vrt_enqueued(), 40);
+
+        whitelist.record_burn_withdrawal_ticket().unwrap();
+        assert_eq!(whitelist.total_tickets_burned(), 1);
+
+        assert_eq!(
+            whitelist.record_mint(u64::MAX),
+            Err(VaultWhitelistError::ArithmeticOverflow)
+        );
+    }
+
+    #[test]
+    fn test_whitelist_mode() {
+        let mut whitelist = Whitelist::new(Pubkey::new_unique(), Pubkey::new_unique(), 1, 255);
+        assert_eq!(whitelist.mode(), Ok(WhitelistMode::AllowList));
+
+        whitelist.set_mode(WhitelistMode::DenyList);
+        assert_eq!(whitelist.mode(), Ok(WhitelistMode::DenyList));
+
+        assert_eq!(
+            WhitelistMode::try_from(2),
+            Err(VaultWhitelistError::InvalidWhitelistMode)
+        );
+    }
+
+    #[test]
+    fn test_whitelist_rate_limit() {
+        let mut whitelist = Whitelist::new(Pubkey::new_unique(), Pubkey::new_unique(), 1, 255);
+        assert_eq!(whitelist.rate_limit_window(10), None);
+
+        whitelist.set_rate_limit(2, 100, 50, 0, 0);
+        assert_eq!(whitelist.rate_limit_window(11), Some(10));
+
+        whitelist.consume_mint_limit(60, 10).unwrap();
+        whitelist.consume_mint_limit(40, 10).unwrap();
+        assert_eq!(
+            whitelist.consume_mint_limit(1, 10),
+            Err(VaultWhitelistError::RateLimitExceeded)
+        );
+        assert_eq!(whitelist.window_minted(), 100);
+
+        whitelist.consume_enqueue_limit(50, 10).unwrap();
+        assert_eq!(
+            whitelist.consume_enqueue_limit(1, 10),
+            Err(VaultWhitelistError::RateLimitExceeded)
+        );
+
+        // a new window resets the usage
+        whitelist.consume_mint_limit(100, 12).unwrap();
+        assert_eq!(whitelist.window_start_epoch(), 12);
+        assert_eq!(whitelist.window_minted(), 100);
+        assert_eq!(whitelist.window_enqueued(), 0);
+    }
+
+    #[test]
+    fn test_whitelist_check_burn_crank() {
+        let mut whitelist = Whitelist::new(Pubkey::new_unique(), Pubkey::new_unique(), 1, 255);
+        assert_eq!(
+            whitelist.check_burn_crank(&Pubkey::default()),
+            Err(VaultWhitelistError::InvalidBurnCrank)
+        );
+
+        let burn_crank = Pubkey::new_unique();
+        whitelist.set_burn_crank(burn_crank);
+        assert!(whitelist.check_burn_crank(&burn_crank).is_ok());
+        assert_eq!(
+            whitelist.check_burn_crank(&Pubkey::new_unique()),
+            Err(VaultWhitelistError::InvalidBurnCrank)
+        );
+    }
+
+    #[test]
+    fn test_whitelist_check_deposit() {
+        let mut whitelist = Whitelist::new(Pubkey::new_unique(), Pubkey::new_unique(), 1, 255);
+        assert!(whitelist.check_deposit(1, None).is_ok());
+
+        assert_eq!(
+            whitelist.set_deposit_limits(200, 100),
+            Err(VaultWhitelistError::InvalidDepositLimits)
+        );
+        whitelist.set_deposit_limits(100, 1_000).unwrap();
+        assert_eq!(
+            whitelist.check_deposit(99, None),
+            Err(VaultWhitelistError::DepositTooSmall)
+        );
+        assert_eq!(
+            whitelist.check_deposit(1_001, None),
+            Err(VaultWhitelistError::DepositTooLarge)
+        );
+
+        let mut whitelist_user = WhitelistUser::new(
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            1,
+            255,
+        );
+        whitelist_user.set_deposit_limits(0, 5_000).unwrap();
+        assert_eq!(
+            whitelist.check_deposit(99, Some(&whitelist_user)),
+            Err(VaultWhitelistError::DepositTooSmall)
+        );
+        assert!(whitelist
+            .check_deposit(5_000, Some(&whitelist_user))
+            .is_ok());
+    }
+
+    #[test]
+    fn test_migrate_legacy_whitelist() {
+        let vault = Pubkey::new_unique();
+        let mut whitelist = Whitelist::new(vault, Pubkey::new_unique(), 1, 255);
+        whitelist.set_burn_crank(Pubkey::new_unique());
+        assert_eq!(whitelist.version(), Whitelist::VERSION);
+
+        // Accounts created before versioning hold zero in the version byte
+        let mut data = vec![0; 8];
+        data[0] = Whitelist::DISCRIMINATOR;
+        data.extend_from_slice(bytemuck::bytes_of(&whitelist));
+        data[8 + std::mem::offset_of!(Whitelist, version)] = version::LEGACY_VERSION;
+
+        let legacy = Whitelist::try_from_slice_unchecked_mut(&mut data).unwrap();
+        assert_eq!(legacy.version(), version::LEGACY_VERSION);
+        assert_eq!(legacy.vault, vault);
+        assert_eq!(legacy.burn_crank, whitelist.burn_crank);
+
+        assert_eq!(legacy.migrate(), Ok(true));
+        assert_eq!(legacy.migrate(), Ok(false));
+        assert_eq!(*legacy, whitelist);
+    }
 }
@@ -0,0 +1,202 @@
+use bytemuck::{Pod, Zeroable};
+use jito_bytemuck::{AccountDeserialize, Discriminator};
+use jito_vault_whitelist_sdk::error::VaultWhitelistError;
+use shank::ShankAccount;
+use solana_program::{account_info::AccountInfo, msg, program_error::ProgramError, pubkey::Pubkey};
+
+use crate::version;
+
+const RESERVED_SPACE_LEN: usize = 262;
+
+/// Length of a whitelist name in bytes
+pub const WHITELIST_NAME_LEN: usize = 32;
+
+/// Length of a whitelist policy URI in bytes
+pub const WHITELIST_URI_LEN: usize = 128;
+
+/// Metadata telling a whitelist apart, kept in its own account so the `Whitelist` layout is
+/// unchanged.
+///
+/// The account outlives a closed whitelist and is overwritten when the whitelist is re-created.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Pod, Zeroable, AccountDeserialize, ShankAccount)]
+#[repr(C)]
+pub struct WhitelistMetadata {
+    /// The whitelist this metadata describes
+    pub whitelist: Pubkey,
+
+    /// Bump seed for the PDA
+    pub bump: u8,
+
+    /// Name telling the whitelist apart, UTF-8 padded with zeros
+    name: [u8; 32],
+
+    /// Key of the KYC provider vetting the whitelist's users, default is none
+    pub kyc_provider: Pubkey,
+
+    /// URI of the off-chain policy document, UTF-8 padded with zeros
+    uri: [u8; 128],
+
+    /// Layout version of this account
+    version: u8,
+
+    /// Reserved space
+    reserved: [u8; 262],
+}
+
+impl WhitelistMetadata {
+    /// Layout version written to new accounts
+    pub const VERSION: u8 = 1;
+
+    /// Initialize WhitelistMetadata
+    pub fn new(whitelist: Pubkey, bump: u8) -> Self {
+        Self {
+            whitelist,
+            bump,
+            name: [0; WHITELIST_NAME_LEN],
+            kyc_provider: Pubkey::default(),
+            uri: [0; WHITELIST_URI_LEN],
+            version: Self::VERSION,
+            reserved: [0; RESERVED_SPACE_LEN],
+        }
+    }
+
+    /// Name of the whitelist, padded with zeros
+    pub const fn name(&self) -> &[u8; WHITELIST_NAME_LEN] {
+        &self.name
+    }
+
+    /// URI of the off-chain policy document, padded with zeros
+    pub const fn uri(&self) -> &[u8; WHITELIST_URI_LEN] {
+        &self.uri
+    }
+
+    /// The layout version of this account
+    pub const fn version(&self) -> u8 {
+        self.version
+    }
+
+    /// Upgrade this account to the current layout version, returning whether it changed
+    pub fn migrate(&mut self) -> Result<bool, VaultWhitelistError> {
+        version::migrate(&mut self.version, Self::VERSION)
+    }
+
+    /// Set the metadata telling the whitelist apart
+    pub fn set_metadata(
+        &mut self,
+        name: [u8; WHITELIST_NAME_LEN],
+        kyc_provider: Pubkey,
+        uri: [u8; WHITELIST_URI_LEN],
+    ) {
+        self.name = name;
+        self.kyc_provider = kyc_provider;
+        self.uri = uri;
+    }
+
+    /// Seeds of WhitelistMetadata Account
+    pub fn seeds(whitelist: &Pubkey) -> Vec<Vec<u8>> {
+        vec![
+            b"whitelist_metadata".to_vec(),
+            whitelist.to_bytes().to_vec(),
+        ]
+    }
+
+    /// Find the program address of WhitelistMetadata Account
+    pub fn find_program_address(
+        program_id: &Pubkey,
+        whitelist: &Pubkey,
+    ) -> (Pubkey, u8, Vec<Vec<u8>>) {
+        let seeds = Self::seeds(whitelist);
+        let seeds_iter: Vec<_> = seeds.iter().map(|s| s.as_slice()).collect();
+        let (pda, bump) = Pubkey::find_program_address(&seeds_iter, program_id);
+        (pda, bump, seeds)
+    }
+
+    /// Load WhitelistMetadata Account
+    pub fn load(
+        program_id: &Pubkey,
+        account: &AccountInfo,
+        whitelist: &Pubkey,
+        expect_writable: bool,
+    ) -> Result<(), ProgramError> {
+        if account.owner.ne(program_id) {
+            msg!("WhitelistMetadata account has an invalid owner");
+            return Err(ProgramError::InvalidAccountOwner);
+        }
+        if account.data_is_empty() {
+            msg!("WhitelistMetadata account data is empty");
+            return Err(ProgramError::InvalidAccountData);
+        }
+        if expect_writable && !account.is_writable {
+            msg!("WhitelistMetadata account is not writable");
+            return Err(ProgramError::InvalidAccountData);
+        }
+        if account.data.borrow()[0].ne(&Self::DISCRIMINATOR) {
+            msg!("WhitelistMetadata account discriminator is invalid");
+            return Err(ProgramError::InvalidAccountData);
+        }
+        if account
+            .key
+            .ne(&Self::find_program_address(program_id, whitelist).0)
+        {
+            msg!("WhitelistMetadata account is not at the correct PDA");
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_whitelist_metadata_no_padding() {
+        let whitelist_metadata = std::mem::size_of::<WhitelistMetadata>();
+        let sum_of_fields = size_of::<Pubkey>() + // whitelist
+            size_of::<u8>() + // bump
+            WHITELIST_NAME_LEN + // name
+            size_of::<Pubkey>() + // kyc_provider
+            WHITELIST_URI_LEN + // uri
+            size_of::<u8>() + // version
+            RESERVED_SPACE_LEN; // reserved
+        assert_eq!(whitelist_metadata, sum_of_fields);
+    }
+
+    #[test]
+    fn test_set_metadata() {
+        let mut whitelist_metadata = WhitelistMetadata::new(Pubkey::new_unique(), 255);
+        assert_eq!(whitelist_metadata.name(), &[0; WHITELIST_NAME_LEN]);
+        assert_eq!(whitelist_metadata.kyc_provider, Pubkey::default());
+        assert_eq!(whitelist_metadata.uri(), &[0; WHITELIST_URI_LEN]);
+
+        let mut name = [0; WHITELIST_NAME_LEN];
+        name[..5].copy_from_slice(b"Alpha");
+        let kyc_provider = Pubkey::new_unique();
+        let mut uri = [0; WHITELIST_URI_LEN];
+        uri[..19].copy_from_slice(b"https://example.com");
+
+        whitelist_metadata.set_metadata(name, kyc_provider, uri);
+        assert_eq!(whitelist_metadata.name(), &name);
+        assert_eq!(whitelist_metadata.kyc_provider, kyc_provider);
+        assert_eq!(whitelist_metadata.uri(), &uri);
+    }
+
+    #[test]
+    fn test_migrate_whitelist_metadata() {
+        let whitelist_metadata = WhitelistMetadata::new(Pubkey::new_unique(), 255);
+        assert_eq!(whitelist_metadata.version(), WhitelistMetadata::VERSION);
+
+        let mut data = vec![0; 8];
+        data[0] = WhitelistMetadata::DISCRIMINATOR;
+        data.extend_from_slice(bytemuck::bytes_of(&whitelist_metadata));
+        data[8 + std::mem::offset_of!(WhitelistMetadata, version)] = version::LEGACY_VERSION;
+
+        let older = WhitelistMetadata::try_from_slice_unchecked_mut(&mut data).unwrap();
+        assert_eq!(older.version(), version::LEGACY_VERSION);
+
+        assert_eq!(older.migrate(), Ok(true));
+        assert_eq!(older.migrate(), Ok(false));
+        assert_eq!(*older, whitelist_metadata);
+    }
+}
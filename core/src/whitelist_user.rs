@@ -1,9 +1,17 @@
 use bytemuck::{Pod, Zeroable};
-use jito_bytemuck::{AccountDeserialize, Discriminator};
+use jito_bytemuck::{
+    types::{PodBool, PodU64},
+    AccountDeserialize, Discriminator,
+};
 use jito_vault_whitelist_sdk::error::VaultWhitelistError;
 use shank::ShankAccount;
 use solana_program::{account_info::AccountInfo, msg, program_error::ProgramError, pubkey::Pubkey};
 
+use crate::{deposit_limit, rate_limit, version};
+
+/// Length of a whitelist user reference in bytes
+pub const WHITELIST_USER_REFERENCE_LEN: usize = 32;
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Pod, Zeroable, AccountDeserialize, ShankAccount)]
 #[repr(C)]
 pub struct WhitelistUser {
@@ -16,26 +24,349 @@ pub struct WhitelistUser {
     /// Bump seed for the PDA
     pub bump: u8,
 
+    /// The account that funded the rent, refunded when the user is removed
+    pub payer: Pubkey,
+
+    /// The generation of the whitelist this user was added to
+    generation: PodU64,
+
+    /// Total supported tokens deposited by this user
+    total_deposited: PodU64,
+
+    /// Total VRT minted to this user
+    total_vrt_minted: PodU64,
+
+    /// Total VRT enqueued for withdrawal by this user
+    total_vrt_enqueued: PodU64,
+
+    /// Total supported tokens returned to this user by burned withdrawal tickets
+    total_tokens_withdrawn: PodU64,
+
+    /// The slot of this user's last mint, enqueue or burn
+    last_activity_slot: PodU64,
+
+    /// The slot of this user's last mint
+    last_mint_slot: PodU64,
+
+    /// First epoch of this user's current rate limit window
+    window_start_epoch: PodU64,
+
+    /// Supported tokens minted by this user in the current window
+    window_minted: PodU64,
+
+    /// VRT enqueued by this user in the current window
+    window_enqueued: PodU64,
+
+    /// Overrides the whitelist's minimum deposit when non-zero
+    min_deposit: PodU64,
+
+    /// Overrides the whitelist's maximum deposit when non-zero
+    max_deposit: PodU64,
+
+    /// Whether this user may mint VRT on behalf of other whitelisted users
+    custodian: PodBool,
+
+    /// Whether anyone may burn this user's matured withdrawal tickets on the user's behalf
+    permissionless_burn: PodBool,
+
+    /// Key allowed to burn this user's matured withdrawal tickets on the user's behalf, default
+    /// is none
+    pub burn_delegate: Pubkey,
+
+    /// Layout version of this account, zero for accounts created before versioning
+    version: u8,
+
+    /// Opaque tag linking this user to an off-chain record, such as a hash of a KYC case ID
+    reference: [u8; WHITELIST_USER_REFERENCE_LEN],
+
     /// Reserved space
-    reserved: [u8; 263],
+    reserved: [u8; 68],
 }
 
 impl WhitelistUser {
-    pub const fn new(whitelist: Pubkey, user: Pubkey, bump: u8) -> Self {
+    /// Layout version written to new accounts
+    pub const VERSION: u8 = 1;
+
+    pub fn new(whitelist: Pubkey, user: Pubkey, payer: Pubkey, generation: u64, bump: u8) -> Self {
         Self {
             whitelist,
             user,
             bump,
-            reserved: [0; 263],
+            payer,
+            generation: PodU64::from(generation),
+            total_deposited: PodU64::from(0),
+            total_vrt_minted: PodU64::from(0),
+            total_vrt_enqueued: PodU64::from(0),
+            total_tokens_withdrawn: PodU64::from(0),
+            last_activity_slot: PodU64::from(0),
+            last_mint_slot: PodU64::from(0),
+            window_start_epoch: PodU64::from(0),
+            window_minted: PodU64::from(0),
+            window_enqueued: PodU64::from(0),
+            min_deposit: PodU64::from(0),
+            max_deposit: PodU64::from(0),
+            custodian: PodBool::from(false),
+            permissionless_burn: PodBool::from(false),
+            burn_delegate: Pubkey::default(),
+            version: Self::VERSION,
+            reference: [0; WHITELIST_USER_REFERENCE_LEN],
+            reserved: [0; 68],
+        }
+    }
+
+    /// The generation of the whitelist this user was added to
+    pub fn generation(&self) -> u64 {
+        self.generation.into()
+    }
+
+    /// Total supported tokens deposited by this user
+    pub fn total_deposited(&self) -> u64 {
+        self.total_deposited.into()
+    }
+
+    /// Total VRT minted to this user
+    pub fn total_vrt_minted(&self) -> u64 {
+        self.total_vrt_minted.into()
+    }
+
+    /// Total VRT enqueued for withdrawal by this user
+    pub fn total_vrt_enqueued(&self) -> u64 {
+        self.total_vrt_enqueued.into()
+    }
+
+    /// Total supported tokens returned to this user by burned withdrawal tickets
+    pub fn total_tokens_withdrawn(&self) -> u64 {
+        self.total_tokens_withdrawn.into()
+    }
+
+    /// The slot of this user's last mint, enqueue or burn
+    pub fn last_activity_slot(&self) -> u64 {
+        self.last_activity_slot.into()
+    }
+
+    /// The slot of this user's last mint
+    pub fn last_mint_slot(&self) -> u64 {
+        self.last_mint_slot.into()
+    }
+
+    /// First epoch of this user's current rate limit window
+    pub fn window_start_epoch(&self) -> u64 {
+        self.window_start_epoch.into()
+    }
+
+    /// Supported tokens minted by this user in the current window
+    pub fn window_minted(&self) -> u64 {
+        self.window_minted.into()
+    }
+
+    /// VRT enqueued by this user in the current window
+    pub fn window_enqueued(&self) -> u64 {
+        self.window_enqueued.into()
+    }
+
+    /// Minimum deposit override, zero uses the whitelist's limit
+    pub fn min_deposit(&self) -> u64 {
+        self.min_deposit.into()
+    }
+
+    /// Maximum deposit override, zero uses the whitelist's limit
+    pub fn max_deposit(&self) -> u64 {
+        self.max_deposit.into()
+    }
+
+    /// Set the deposit limits overriding the whitelist's for this user
+    pub fn set_deposit_limits(
+        &mut self,
+        min_deposit: u64,
+        max_deposit: u64,
+    ) -> Result<(), VaultWhitelistError> {
+        deposit_limit::check_limits(min_deposit, max_deposit)?;
+
+        self.min_deposit = PodU64::from(min_deposit);
+        self.max_deposit = PodU64::from(max_deposit);
+
+        Ok(())
+    }
+
+    /// Whether this user may mint VRT on behalf of other whitelisted users
+    pub fn is_custodian(&self) -> bool {
+        self.custodian.into()
+    }
+
+    /// Set whether this user may mint VRT on behalf of other whitelisted users
+    pub fn set_custodian(&mut self, custodian: bool) {
+        self.custodian = PodBool::from(custodian);
+    }
+
+    /// Check that this user may mint VRT on behalf of other whitelisted users
+    pub fn check_custodian(&self) -> Result<(), VaultWhitelistError> {
+        if !self.is_custodian() {
+            msg!("User {} is not a custodian", self.user);
+            return Err(VaultWhitelistError::NotCustodian);
+        }
+
+        Ok(())
+    }
+
+    /// Whether anyone may burn this user's matured withdrawal tickets on the user's behalf
+    pub fn permissionless_burn(&self) -> bool {
+        self.permissionless_burn.into()
+    }
+
+    /// Set who may burn this user's matured withdrawal tickets on the user's behalf
+    pub fn set_burn_delegate(&mut self, burn_delegate: Pubkey, permissionless_burn: bool) {
+        self.burn_delegate = burn_delegate;
+        self.permissionless_burn = PodBool::from(permissionless_burn);
+    }
+
+    /// Whether `burner` may burn this user's matured withdrawal tickets on the user's behalf
+    pub fn is_burn_delegate(&self, burner: &Pubkey) -> bool {
+        self.permissionless_burn()
+            || (self.burn_delegate.ne(&Pubkey::default()) && self.burn_delegate.eq(burner))
+    }
+
+    /// Opaque tag linking this user to an off-chain record, zero when unset
+    pub const fn reference(&self) -> &[u8; WHITELIST_USER_REFERENCE_LEN] {
+        &self.reference
+    }
+
+    /// Set the tag linking this user to an off-chain record
+    pub fn set_reference(&mut self, reference: [u8; WHITELIST_USER_REFERENCE_LEN]) {
+        self.reference = reference;
+    }
+
+    /// Start a new rate limit window when `window_start_epoch` differs from the current one
+    fn roll_window(&mut self, window_start_epoch: u64) {
+        if self.window_start_epoch() != window_start_epoch {
+            self.window_start_epoch = PodU64::from(window_start_epoch);
+            self.window_minted = PodU64::from(0);
+            self.window_enqueued = PodU64::from(0);
         }
     }
 
-    /// Check whitelist pubkey
-    pub fn check_whitelist(&self, whitelist: &Pubkey) -> Result<(), VaultWhitelistError> {
+    /// Count supported tokens minted against the rate limit window starting at
+    /// `window_start_epoch`
+    pub fn consume_mint_limit(
+        &mut self,
+        amount_in: u64,
+        window_start_epoch: u64,
+        limit: u64,
+    ) -> Result<(), VaultWhitelistError> {
+        self.roll_window(window_start_epoch);
+
+        let window_minted = rate_limit::consume(self.window_minted(), amount_in, limit)?;
+        self.window_minted = PodU64::from(window_minted);
+
+        Ok(())
+    }
+
+    /// Count VRT enqueued against the rate limit window starting at `window_start_epoch`
+    pub fn consume_enqueue_limit(
+        &mut self,
+        vrt_amount: u64,
+        window_start_epoch: u64,
+        limit: u64,
+    ) -> Result<(), VaultWhitelistError> {
+        self.roll_window(window_start_epoch);
+
+        let window_enqueued = rate_limit::consume(self.window_enqueued(), vrt_amount, limit)?;
+        self.window_enqueued = PodU64::from(window_enqueued);
+
+        Ok(())
+    }
+
+    /// Record supported tokens deposited and VRT minted
+    pub fn record_mint(
+        &mut self,
+        deposited: u64,
+        vrt_minted: u64,
+        slot: u64,
+    ) -> Result<(), VaultWhitelistError> {
+        let total_deposited = self
+            .total_deposited()
+            .checked_add(deposited)
+            .ok_or(VaultWhitelistError::ArithmeticOverflow)?;
+        let total_vrt_minted = self
+            .total_vrt_minted()
+            .checked_add(vrt_minted)
+            .ok_or(VaultWhitelistError::ArithmeticOverflow)?;
+
+        self.total_deposited = PodU64::from(total_deposited);
+        self.total_vrt_minted = PodU64::from(total_vrt_minted);
+        self.last_activity_slot = PodU64::from(slot);
+        self.last_mint_slot = PodU64::from(slot);
+
+        Ok(())
+    }
+
+    /// Record supported tokens deposited on behalf of another user. The VRT is recorded on the
+    /// beneficiary, so this user's lockup is left untouched
+    pub fn record_deposit_for(
+        &mut self,
+        deposited: u64,
+        slot: u64,
+    ) -> Result<(), VaultWhitelistError> {
+        let total_deposited = self
+            .total_deposited()
+            .checked_add(deposited)
+            .ok_or(VaultWhitelistError::ArithmeticOverflow)?;
+
+        self.total_deposited = PodU64::from(total_deposited);
+        self.last_activity_slot = PodU64::from(slot);
+
+        Ok(())
+    }
+
+    /// Record VRT enqueued for withdrawal
+    pub fn record_enqueue_withdrawal(
+        &mut self,
+        vrt_amount: u64,
+        slot: u64,
+    ) -> Result<(), VaultWhitelistError> {
+        let total_vrt_enqueued = self
+            .total_vrt_enqueued()
+            .checked_add(vrt_amount)
+            .ok_or(VaultWhitelistError::ArithmeticOverflow)?;
+
+        self.total_vrt_enqueued = PodU64::from(total_vrt_enqueued);
+        self.last_activity_slot = PodU64::from(slot);
+
+        Ok(())
+    }
+
+    /// Record supported tokens returned by a burned withdrawal ticket
+    pub fn record_burn_withdrawal_ticket(
+        &mut self,
+        tokens_withdrawn: u64,
+        slot: u64,
+    ) -> Result<(), VaultWhitelistError> {
+        let total_tokens_withdrawn = self
+            .total_tokens_withdrawn()
+            .checked_add(tokens_withdrawn)
+            .ok_or(VaultWhitelistError::ArithmeticOverflow)?;
+
+        self.total_tokens_withdrawn = PodU64::from(total_tokens_withdrawn);
+        self.last_activity_slot = PodU64::from(slot);
+
+        Ok(())
+    }
+
+    /// Check whitelist pubkey and generation
+    ///
+    /// A WhitelistUser created for an earlier whitelist at the same PDA is stale.
+    pub fn check_whitelist(
+        &self,
+        whitelist: &Pubkey,
+        generation: u64,
+    ) -> Result<(), VaultWhitelistError> {
         if self.whitelist.ne(whitelist) {
             msg!("Whitelist pubkey does not match the provided whitelist pubkey");
             return Err(VaultWhitelistError::InvalidWhitelist);
         }
+        if self.generation().ne(&generation) {
+            msg!("WhitelistUser belongs to an earlier generation of the whitelist");
+            return Err(VaultWhitelistError::StaleWhitelistUser);
+        }
 
         Ok(())
     }
@@ -50,6 +381,49 @@ impl WhitelistUser {
         Ok(())
     }
 
+    /// Check that `lockup_slots` have passed since this user's last mint
+    pub fn check_lockup(&self, lockup_slots: u64, slot: u64) -> Result<(), VaultWhitelistError> {
+        let unlock_slot = self.last_mint_slot().saturating_add(lockup_slots);
+        if slot < unlock_slot {
+            msg!("Withdrawal is locked until slot {}", unlock_slot);
+            return Err(VaultWhitelistError::WithdrawalLocked);
+        }
+
+        Ok(())
+    }
+
+    /// Check that the rent refund goes to the recorded payer.
+    ///
+    /// Users added before the payer was recorded were funded by the vault admin.
+    pub fn check_rent_receiver(
+        &self,
+        rent_receiver: &Pubkey,
+        vault_admin: &Pubkey,
+    ) -> Result<(), VaultWhitelistError> {
+        let payer = if self.payer.eq(&Pubkey::default()) {
+            vault_admin
+        } else {
+            &self.payer
+        };
+
+        if payer.ne(rent_receiver) {
+            msg!("Rent receiver does not match the whitelist user payer");
+            return Err(VaultWhitelistError::InvalidRentReceiver);
+        }
+
+        Ok(())
+    }
+
+    /// The layout version of this account
+    pub const fn version(&self) -> u8 {
+        self.version
+    }
+
+    /// Upgrade this account to the current layout version, returning whether it changed
+    pub fn migrate(&mut self) -> Result<bool, VaultWhitelistError> {
+        version::migrate(&mut self.version, Self::VERSION)
+    }
+
     /// Seeds of WhitelistUser Account
     pub fn seeds(whitelist: &Pubkey, user: &Pubkey) -> Vec<Vec<u8>> {
         vec![
@@ -106,3 +480,172 @@ impl WhitelistUser {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_whitelist_user_no_padding() {
+        let whitelist_user = std::mem::size_of::<WhitelistUser>();
+        let sum_of_fields = size_of::<Pubkey>() + // whitelist
+            size_of::<Pubkey>() + // user
+            size_of::<u8>() + // bump
+            size_of::<Pubkey>() + // payer
+            size_of::<PodU64>() + // generation
+            size_of::<PodU64>() + // total_deposited
+            size_of::<PodU64>() + // total_vrt_minted
+            size_of::<PodU64>() + // total_vrt_enqueued
+            size_of::<PodU64>() + // total_tokens_withdrawn
+            size_of::<PodU64>() + // last_activity_slot
+            size_of::<PodU64>() + // last_mint_slot
+            size_of::<PodU64>() + // window_start_epoch
+            size_of::<PodU64>() + // window_minted
+            size_of::<PodU64>() + // window_enqueued
+            size_of::<PodU64>() + // min_deposit
+            size_of::<PodU64>() + // max_deposit
+            size_of::<PodBool>() + // custodian
+            size_of::<PodBool>() + // permissionless_burn
+            size_of::<Pubkey>() + // burn_delegate
+            size_of::<u8>() + // version
+            WHITELIST_USER_REFERENCE_LEN + // reference
+            68; // reserved
+        assert_eq!(whitelist_user, sum_of_fields);
+    }
+
+    #[test]
+    fn test_whitelist_user_activity() {
+        let mut whitelist_user = WhitelistUser::new(
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            1,
+            255,
+        );
+
+        whitelist_user.record_mint(100, 99, 10).unwrap();
+        whitelist_user.record_mint(50, 49, 11).unwrap();
+        assert_eq!(whitelist_user.total_deposited(), 150);
+        assert_eq!(whitelist_user.total_vrt_minted(), 148);
+        assert_eq!(whitelist_user.last_activity_slot(), 11);
+
+        whitelist_user.record_enqueue_withdrawal(148, 12).unwrap();
+        assert_eq!(whitelist_user.total_vrt_enqueued(), 148);
+        assert_eq!(whitelist_user.last_activity_slot(), 12);
+
+        whitelist_user
+            .record_burn_withdrawal_ticket(145, 20)
+            .unwrap();
+        assert_eq!(whitelist_user.total_tokens_withdrawn(), 145);
+        assert_eq!(whitelist_user.last_activity_slot(), 20);
+        assert_eq!(whitelist_user.last_mint_slot(), 11);
+    }
+
+    #[test]
+    fn test_check_lockup() {
+        let mut whitelist_user = WhitelistUser::new(
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            1,
+            255,
+        );
+        assert!(whitelist_user.check_lockup(100, 0).is_ok());
+
+        whitelist_user.record_mint(100, 99, 50).unwrap();
+        assert_eq!(
+            whitelist_user.check_lockup(100, 149),
+            Err(VaultWhitelistError::WithdrawalLocked)
+        );
+        assert!(whitelist_user.check_lockup(100, 150).is_ok());
+        assert!(whitelist_user.check_lockup(0, 50).is_ok());
+        assert!(whitelist_user.check_lockup(u64::MAX, u64::MAX).is_ok());
+    }
+
+    #[test]
+    fn test_whitelist_user_rate_limit() {
+        let mut whitelist_user = WhitelistUser::new(
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            1,
+            255,
+        );
+
+        whitelist_user.consume_mint_limit(100, 5, 100).unwrap();
+        assert_eq!(
+            whitelist_user.consume_mint_limit(1, 5, 100),
+            Err(VaultWhitelistError::RateLimitExceeded)
+        );
+        whitelist_user.consume_enqueue_limit(30, 5, 0).unwrap();
+
+        whitelist_user.consume_enqueue_limit(10, 6, 0).unwrap();
+        assert_eq!(whitelist_user.window_start_epoch(), 6);
+        assert_eq!(whitelist_user.window_minted(), 0);
+        assert_eq!(whitelist_user.window_enqueued(), 10);
+    }
+
+    #[test]
+    fn test_check_custodian() {
+        let mut whitelist_user = WhitelistUser::new(
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            1,
+            255,
+        );
+        assert_eq!(
+            whitelist_user.check_custodian(),
+            Err(VaultWhitelistError::NotCustodian)
+        );
+
+        whitelist_user.set_custodian(true);
+        assert!(whitelist_user.check_custodian().is_ok());
+    }
+
+    #[test]
+    fn test_is_burn_delegate() {
+        let mut whitelist_user = WhitelistUser::new(
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            1,
+            255,
+        );
+        assert!(!whitelist_user.is_burn_delegate(&Pubkey::default()));
+
+        let burn_delegate = Pubkey::new_unique();
+        whitelist_user.set_burn_delegate(burn_delegate, false);
+        assert!(whitelist_user.is_burn_delegate(&burn_delegate));
+        assert!(!whitelist_user.is_burn_delegate(&Pubkey::new_unique()));
+
+        whitelist_user.set_burn_delegate(Pubkey::default(), true);
+        assert!(whitelist_user.is_burn_delegate(&Pubkey::new_unique()));
+    }
+
+    #[test]
+    fn test_migrate_legacy_whitelist_user() {
+        let user = Pubkey::new_unique();
+        let mut whitelist_user =
+            WhitelistUser::new(Pubkey::new_unique(), user, Pubkey::new_unique(), 1, 255);
+        whitelist_user.set_custodian(true);
+        whitelist_user.set_reference([7; WHITELIST_USER_REFERENCE_LEN]);
+        assert_eq!(whitelist_user.version(), WhitelistUser::VERSION);
+
+        // Accounts created before versioning hold zero in the version byte
+        let mut data = vec![0; 8];
+        data[0] = WhitelistUser::DISCRIMINATOR;
+        data.extend_from_slice(bytemuck::bytes_of(&whitelist_user));
+        data[8 + std::mem::offset_of!(WhitelistUser, version)] = version::LEGACY_VERSION;
+
+        let legacy = WhitelistUser::try_from_slice_unchecked_mut(&mut data).unwrap();
+        assert_eq!(legacy.version(), version::LEGACY_VERSION);
+        assert_eq!(legacy.user, user);
+        assert!(legacy.check_custodian().is_ok());
+        assert_eq!(legacy.reference(), &[7; WHITELIST_USER_REFERENCE_LEN]);
+
+        assert_eq!(legacy.migrate(), Ok(true));
+        assert_eq!(legacy.migrate(), Ok(false));
+        assert_eq!(*legacy, whitelist_user);
+    }
+}
@@ -4,12 +4,21 @@ use jito_vault_core::{
     vault_staker_withdrawal_ticket::VaultStakerWithdrawalTicket,
 };
 use jito_vault_whitelist_client::instructions::{
-    AddToWhitelistBuilder, BurnWithdrawalTicketBuilder, CloseWhitelistBuilder,
-    EnqueueWithdrawalBuilder, InitializeConfigBuilder, InitializeWhitelistBuilder, MintBuilder,
-    RemoveFromWhitelistBuilder, SetMintBurnAdminBuilder,
+    AddToBlacklistBuilder, AddToWhitelistBuilder, BurnWithdrawalTicketBuilder,
+    CloseOrphanedWhitelistUserBuilder, CloseWhitelistBuilder, CrankBurnWithdrawalTicketBuilder,
+    EnqueueWithdrawalBuilder, InitializeConfigBuilder, InitializeWhitelistBuilder,
+    MigrateAccountBuilder, MintBuilder, MintForBuilder, RemoveFromBlacklistBuilder,
+    RemoveFromWhitelistBuilder, SetBurnCrankBuilder, SetBurnDelegateBuilder, SetCustodianBuilder,
+    SetDepositLimitsBuilder, SetLockupBuilder, SetMintBurnAdminBuilder, SetRateLimitBuilder,
+    SetUserDepositLimitsBuilder, SetUserReferenceBuilder, SetWhitelistMetadataBuilder,
+    SetWhitelistModeBuilder,
 };
 use jito_vault_whitelist_core::{
-    config::Config, whitelist::Whitelist, whitelist_user::WhitelistUser,
+    blacklisted::Blacklisted,
+    config::Config,
+    whitelist::{Whitelist, WhitelistMode},
+    whitelist_metadata::{WhitelistMetadata, WHITELIST_NAME_LEN, WHITELIST_URI_LEN},
+    whitelist_user::{WhitelistUser, WHITELIST_USER_REFERENCE_LEN},
 };
 use jito_vault_whitelist_sdk::error::VaultWhitelistError;
 use solana_program_test::BanksClient;
@@ -21,6 +30,7 @@ use solana_sdk::{
     signature::Keypair,
     signer::Signer,
     system_instruction::transfer,
+    system_program,
     transaction::{Transaction, TransactionError},
 };
 use spl_associated_token_account::{
@@ -115,6 +125,17 @@ impl VaultWhitelistClient {
         Ok(*whitelist)
     }
 
+    pub async fn get_whitelist_metadata(
+        &mut self,
+        account: &Pubkey,
+    ) -> TestResult<WhitelistMetadata> {
+        let account = self.banks_client.get_account(*account).await?.unwrap();
+        let whitelist_metadata =
+            WhitelistMetadata::try_from_slice_unchecked(account.data.as_slice()).unwrap();
+
+        Ok(*whitelist_metadata)
+    }
+
     pub async fn get_whitelist_user(
         &mut self,
         account: &Pubkey,
@@ -133,6 +154,45 @@ impl VaultWhitelistClient {
         Ok(*whitelist)
     }
 
+    pub async fn get_blacklisted(&mut self, account: &Pubkey) -> TestResult<Blacklisted> {
+        let account = self
+            .banks_client
+            .get_account(*account)
+            .await?
+            .ok_or(TestError::AccountNotFound)?;
+        let blacklisted = Blacklisted::try_from_slice_unchecked(account.data.as_slice()).unwrap();
+
+        Ok(*blacklisted)
+    }
+
+    /// The account passed as `whitelist_user` to mint and redeem: the user's WhitelistUser in
+    /// allow-list mode, or the user's Blacklisted PDA in deny-list mode
+    pub async fn whitelist_user_address(
+        &mut self,
+        whitelist: &Pubkey,
+        user: &Pubkey,
+    ) -> TestResult<Pubkey> {
+        let deny_list = match self.banks_client.get_account(*whitelist).await? {
+            Some(account) => Whitelist::try_from_slice_unchecked(account.data.as_slice())
+                .is_ok_and(|whitelist| whitelist.mode() == Ok(WhitelistMode::DenyList)),
+            None => false,
+        };
+
+        let whitelist_user = if deny_list {
+            Blacklisted::find_program_address(&jito_vault_whitelist_program::id(), whitelist, user)
+                .0
+        } else {
+            WhitelistUser::find_program_address(
+                &jito_vault_whitelist_program::id(),
+                whitelist,
+                user,
+            )
+            .0
+        };
+
+        Ok(whitelist_user)
+    }
+
     pub async fn do_initialize_config(&mut self) -> TestResult<()> {
         self.airdrop(&self.payer.pubkey(), 100.0).await.unwrap();
         self.initialize_config().await?;
@@ -164,24 +224,93 @@ impl VaultWhitelistClient {
     }
 
     pub async fn do_initialize_whitelist(&mut self, vault_root: &VaultRoot) -> TestResult<()> {
-        self.initialize_whitelist(vault_root).await?;
+        self.initialize_whitelist(vault_root, &vault_root.vault_admin)
+            .await?;
 
         Ok(())
     }
 
-    pub async fn initialize_whitelist(&mut self, vault_root: &VaultRoot) -> TestResult<()> {
+    pub async fn initialize_whitelist(
+        &mut self,
+        vault_root: &VaultRoot,
+        payer: &Keypair,
+    ) -> TestResult<()> {
         let config = Config::find_program_address(&jito_vault_whitelist_program::id()).0;
         let whitelist = Whitelist::find_program_address(
             &jito_vault_whitelist_program::id(),
             &vault_root.vault_pubkey,
         )
         .0;
+        let whitelist_metadata = WhitelistMetadata::find_program_address(
+            &jito_vault_whitelist_program::id(),
+            &whitelist,
+        )
+        .0;
 
         let mut ix = InitializeWhitelistBuilder::new()
             .config(config)
             .whitelist(whitelist)
             .vault(vault_root.vault_pubkey)
             .vault_admin(vault_root.vault_admin.pubkey())
+            .payer(payer.pubkey())
+            .kyc_provider(system_program::id())
+            .whitelist_metadata(whitelist_metadata)
+            .name([0; WHITELIST_NAME_LEN])
+            .uri([0; WHITELIST_URI_LEN])
+            .instruction();
+        ix.program_id = jito_vault_whitelist_program::id();
+
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&payer.pubkey()),
+            &[payer, &vault_root.vault_admin],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn do_set_whitelist_metadata(
+        &mut self,
+        vault_root: &VaultRoot,
+        name: [u8; WHITELIST_NAME_LEN],
+        kyc_provider: &Pubkey,
+        uri: [u8; WHITELIST_URI_LEN],
+    ) -> TestResult<()> {
+        self.set_whitelist_metadata(vault_root, name, kyc_provider, uri)
+            .await
+    }
+
+    pub async fn set_whitelist_metadata(
+        &mut self,
+        vault_root: &VaultRoot,
+        name: [u8; WHITELIST_NAME_LEN],
+        kyc_provider: &Pubkey,
+        uri: [u8; WHITELIST_URI_LEN],
+    ) -> TestResult<()> {
+        let config = Config::find_program_address(&jito_vault_whitelist_program::id()).0;
+        let whitelist = Whitelist::find_program_address(
+            &jito_vault_whitelist_program::id(),
+            &vault_root.vault_pubkey,
+        )
+        .0;
+        let whitelist_metadata = WhitelistMetadata::find_program_address(
+            &jito_vault_whitelist_program::id(),
+            &whitelist,
+        )
+        .0;
+
+        let mut ix = SetWhitelistMetadataBuilder::new()
+            .config(config)
+            .vault(vault_root.vault_pubkey)
+            .whitelist(whitelist)
+            .whitelist_metadata(whitelist_metadata)
+            .vault_admin(vault_root.vault_admin.pubkey())
+            .payer(vault_root.vault_admin.pubkey())
+            .kyc_provider(*kyc_provider)
+            .name(name)
+            .uri(uri)
             .instruction();
         ix.program_id = jito_vault_whitelist_program::id();
 
@@ -238,7 +367,13 @@ impl VaultWhitelistClient {
         vault_root: &VaultRoot,
         user: &Pubkey,
     ) -> TestResult<()> {
-        self.add_to_whitelist(vault_root, user).await?;
+        self.add_to_whitelist(
+            vault_root,
+            user,
+            &vault_root.vault_admin,
+            [0; WHITELIST_USER_REFERENCE_LEN],
+        )
+        .await?;
 
         Ok(())
     }
@@ -247,6 +382,8 @@ impl VaultWhitelistClient {
         &mut self,
         vault_root: &VaultRoot,
         user: &Pubkey,
+        payer: &Keypair,
+        reference: [u8; WHITELIST_USER_REFERENCE_LEN],
     ) -> TestResult<()> {
         let config = Config::find_program_address(&jito_vault_whitelist_program::id()).0;
         let whitelist = Whitelist::find_program_address(
@@ -266,8 +403,10 @@ impl VaultWhitelistClient {
             .whitelist(whitelist)
             .vault(vault_root.vault_pubkey)
             .vault_admin(vault_root.vault_admin.pubkey())
+            .payer(payer.pubkey())
             .whitelist_user(whitelist_user)
             .user(*user)
+            .reference(reference)
             .instruction();
         ix.program_id = jito_vault_whitelist_program::id();
 
@@ -275,8 +414,8 @@ impl VaultWhitelistClient {
 
         self.process_transaction(&Transaction::new_signed_with_payer(
             &[ix],
-            Some(&vault_root.vault_admin.pubkey()),
-            &[&vault_root.vault_admin],
+            Some(&payer.pubkey()),
+            &[payer, &vault_root.vault_admin],
             blockhash,
         ))
         .await
@@ -287,7 +426,8 @@ impl VaultWhitelistClient {
         vault_root: &VaultRoot,
         user: &Pubkey,
     ) -> TestResult<()> {
-        self.remove_from_whitelist(vault_root, user).await?;
+        self.remove_from_whitelist(vault_root, user, &vault_root.vault_admin.pubkey())
+            .await?;
 
         Ok(())
     }
@@ -296,6 +436,7 @@ impl VaultWhitelistClient {
         &mut self,
         vault_root: &VaultRoot,
         user: &Pubkey,
+        rent_receiver: &Pubkey,
     ) -> TestResult<()> {
         let config = Config::find_program_address(&jito_vault_whitelist_program::id()).0;
         let whitelist = Whitelist::find_program_address(
@@ -315,6 +456,7 @@ impl VaultWhitelistClient {
             .whitelist(whitelist)
             .vault(vault_root.vault_pubkey)
             .vault_admin(vault_root.vault_admin.pubkey())
+            .rent_receiver(*rent_receiver)
             .whitelist_user(whitelist_user)
             .user(*user)
             .instruction();
@@ -371,14 +513,98 @@ impl VaultWhitelistClient {
         let signers = vec![depositor];
         let whitelist =
             Whitelist::find_program_address(&jito_vault_whitelist_program::id(), &vault_pubkey).0;
+        let whitelist_user = self
+            .whitelist_user_address(&whitelist, &depositor.pubkey())
+            .await?;
+
+        let mut ix = MintBuilder::new()
+            .config(config)
+            .vault_config(
+                jito_vault_core::config::Config::find_program_address(&jito_vault_program::id()).0,
+            )
+            .vault(*vault_pubkey)
+            .vrt_mint(*vrt_mint)
+            .depositor(depositor.pubkey())
+            .depositor_token_account(*depositor_token_account)
+            .vault_token_account(*vault_token_account)
+            .depositor_vrt_token_account(*depositor_vrt_token_account)
+            .vault_fee_token_account(*vault_fee_token_account)
+            .whitelist(whitelist)
+            .whitelist_user(whitelist_user)
+            .jito_vault_program(jito_vault_program::id())
+            .token_program(spl_token::id())
+            .amount_in(amount_in)
+            .min_amount_out(min_amount_out)
+            .instruction();
+        ix.program_id = jito_vault_whitelist_program::id();
+
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&depositor.pubkey()),
+            &signers,
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn do_mint_for(
+        &mut self,
+        vault_root: &VaultRoot,
+        vault: &Vault,
+        custodian: &Keypair,
+        beneficiary: &Pubkey,
+        amount_in: u64,
+        min_amount_out: u64,
+    ) -> TestResult<()> {
+        self.create_ata(&vault.vrt_mint, beneficiary).await?;
+
+        self.mint_for(
+            &vault_root.vault_pubkey,
+            &vault.vrt_mint,
+            custodian,
+            &get_associated_token_address(&custodian.pubkey(), &vault.supported_mint),
+            &get_associated_token_address(&vault_root.vault_pubkey, &vault.supported_mint),
+            &get_associated_token_address(beneficiary, &vault.vrt_mint),
+            &get_associated_token_address(&vault.fee_wallet, &vault.vrt_mint),
+            beneficiary,
+            amount_in,
+            min_amount_out,
+        )
+        .await
+    }
+
+    pub async fn mint_for(
+        &mut self,
+        vault_pubkey: &Pubkey,
+        vrt_mint: &Pubkey,
+        depositor: &Keypair,
+        depositor_token_account: &Pubkey,
+        vault_token_account: &Pubkey,
+        depositor_vrt_token_account: &Pubkey,
+        vault_fee_token_account: &Pubkey,
+        beneficiary: &Pubkey,
+        amount_in: u64,
+        min_amount_out: u64,
+    ) -> TestResult<()> {
+        let config = Config::find_program_address(&jito_vault_whitelist_program::id()).0;
+        let whitelist =
+            Whitelist::find_program_address(&jito_vault_whitelist_program::id(), vault_pubkey).0;
         let whitelist_user = WhitelistUser::find_program_address(
             &jito_vault_whitelist_program::id(),
             &whitelist,
             &depositor.pubkey(),
         )
         .0;
+        let beneficiary_whitelist_user = WhitelistUser::find_program_address(
+            &jito_vault_whitelist_program::id(),
+            &whitelist,
+            beneficiary,
+        )
+        .0;
 
-        let mut ix = MintBuilder::new()
+        let mut ix = MintForBuilder::new()
             .config(config)
             .vault_config(
                 jito_vault_core::config::Config::find_program_address(&jito_vault_program::id()).0,
@@ -394,6 +620,8 @@ impl VaultWhitelistClient {
             .whitelist_user(whitelist_user)
             .jito_vault_program(jito_vault_program::id())
             .token_program(spl_token::id())
+            .beneficiary(*beneficiary)
+            .beneficiary_whitelist_user(beneficiary_whitelist_user)
             .amount_in(amount_in)
             .min_amount_out(min_amount_out)
             .instruction();
@@ -404,7 +632,7 @@ impl VaultWhitelistClient {
         self.process_transaction(&Transaction::new_signed_with_payer(
             &[ix],
             Some(&depositor.pubkey()),
-            &signers,
+            &[depositor],
             blockhash,
         ))
         .await
@@ -465,12 +693,9 @@ impl VaultWhitelistClient {
         let signers = vec![staker, base];
         let whitelist =
             Whitelist::find_program_address(&jito_vault_whitelist_program::id(), &vault).0;
-        let whitelist_user = WhitelistUser::find_program_address(
-            &jito_vault_whitelist_program::id(),
-            &whitelist,
-            &staker.pubkey(),
-        )
-        .0;
+        let whitelist_user = self
+            .whitelist_user_address(&whitelist, &staker.pubkey())
+            .await?;
 
         let mut ix = EnqueueWithdrawalBuilder::new()
             .config(config)
@@ -512,6 +737,7 @@ impl VaultWhitelistClient {
         vault: &Vault,
         depositor: &Keypair,
         vault_staker_withdrawal_ticket_base: &Pubkey,
+        min_amount_out: u64,
     ) -> TestResult<VaultStakerWithdrawalTicketRoot> {
         let base = Keypair::new();
         let vault_staker_withdrawal_ticket = VaultStakerWithdrawalTicket::find_program_address(
@@ -539,6 +765,7 @@ impl VaultWhitelistClient {
             &vault_staker_withdrawal_ticket_token_account,
             &get_associated_token_address(&vault.fee_wallet, &vault.vrt_mint),
             &get_associated_token_address(&config.program_fee_wallet, &vault.vrt_mint),
+            min_amount_out,
         )
         .await?;
 
@@ -559,16 +786,14 @@ impl VaultWhitelistClient {
         vault_staker_withdrawal_ticket_token_account: &Pubkey,
         vault_fee_token_account: &Pubkey,
         program_fee_token_account: &Pubkey,
+        min_amount_out: u64,
     ) -> TestResult<()> {
         let signers = vec![staker];
         let whitelist =
             Whitelist::find_program_address(&jito_vault_whitelist_program::id(), &vault).0;
-        let whitelist_user = WhitelistUser::find_program_address(
-            &jito_vault_whitelist_program::id(),
-            &whitelist,
-            &staker.pubkey(),
-        )
-        .0;
+        let whitelist_user = self
+            .whitelist_user_address(&whitelist, &staker.pubkey())
+            .await?;
         let config = Config::find_program_address(&jito_vault_whitelist_program::id()).0;
 
         let mut ix = BurnWithdrawalTicketBuilder::new()
@@ -589,6 +814,7 @@ impl VaultWhitelistClient {
             .whitelist(whitelist)
             .whitelist_user(whitelist_user)
             .jito_vault_program(jito_vault_program::id())
+            .min_amount_out(min_amount_out)
             .instruction();
         ix.program_id = jito_vault_whitelist_program::id();
 
@@ -603,13 +829,106 @@ impl VaultWhitelistClient {
         .await
     }
 
+    pub async fn do_crank_burn_withdrawal_ticket(
+        &mut self,
+        config: &VaultConfig,
+        vault_root: &VaultRoot,
+        vault: &Vault,
+        staker: &Pubkey,
+        vault_staker_withdrawal_ticket_base: &Pubkey,
+        burn_crank: &Keypair,
+    ) -> TestResult<()> {
+        let vault_staker_withdrawal_ticket = VaultStakerWithdrawalTicket::find_program_address(
+            &jito_vault_program::id(),
+            &vault_root.vault_pubkey,
+            vault_staker_withdrawal_ticket_base,
+        )
+        .0;
+        let vault_token_account =
+            get_associated_token_address(&vault_root.vault_pubkey, &vault.supported_mint);
+        let vault_staker_withdrawal_ticket_token_account =
+            get_associated_token_address(&vault_staker_withdrawal_ticket, &vault.vrt_mint);
+
+        self.crank_burn_withdrawal_ticket(
+            &Config::find_program_address(&jito_vault_program::id()).0,
+            &vault_root.vault_pubkey,
+            &vault_token_account,
+            &vault.vrt_mint,
+            staker,
+            &get_associated_token_address(staker, &vault.supported_mint),
+            &vault_staker_withdrawal_ticket,
+            &vault_staker_withdrawal_ticket_token_account,
+            &get_associated_token_address(&vault.fee_wallet, &vault.vrt_mint),
+            &get_associated_token_address(&config.program_fee_wallet, &vault.vrt_mint),
+            burn_crank,
+        )
+        .await
+    }
+
+    pub async fn crank_burn_withdrawal_ticket(
+        &mut self,
+        vault_config: &Pubkey,
+        vault: &Pubkey,
+        vault_token_account: &Pubkey,
+        vrt_mint: &Pubkey,
+        staker: &Pubkey,
+        staker_token_account: &Pubkey,
+        vault_staker_withdrawal_ticket: &Pubkey,
+        vault_staker_withdrawal_ticket_token_account: &Pubkey,
+        vault_fee_token_account: &Pubkey,
+        program_fee_token_account: &Pubkey,
+        burn_crank: &Keypair,
+    ) -> TestResult<()> {
+        let whitelist =
+            Whitelist::find_program_address(&jito_vault_whitelist_program::id(), vault).0;
+        let whitelist_user = self.whitelist_user_address(&whitelist, staker).await?;
+        let config = Config::find_program_address(&jito_vault_whitelist_program::id()).0;
+
+        let mut ix = CrankBurnWithdrawalTicketBuilder::new()
+            .vault_config(*vault_config)
+            .vault(*vault)
+            .vault_token_account(*vault_token_account)
+            .vrt_mint(*vrt_mint)
+            .staker(*staker)
+            .staker_token_account(*staker_token_account)
+            .vault_staker_withdrawal_ticket(*vault_staker_withdrawal_ticket)
+            .vault_staker_withdrawal_ticket_token_account(
+                *vault_staker_withdrawal_ticket_token_account,
+            )
+            .vault_fee_token_account(*vault_fee_token_account)
+            .program_fee_token_account(*program_fee_token_account)
+            .token_program(spl_token::id())
+            .config(config)
+            .whitelist(whitelist)
+            .whitelist_user(whitelist_user)
+            .jito_vault_program(jito_vault_program::id())
+            .burn_crank(burn_crank.pubkey())
+            .instruction();
+        ix.program_id = jito_vault_whitelist_program::id();
+
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&self.payer.pubkey()),
+            &[&self.payer, burn_crank],
+            blockhash,
+        ))
+        .await
+    }
+
     pub async fn do_close_whitelist(&mut self, vault_root: &VaultRoot) -> TestResult<()> {
-        self.close_whitelist(vault_root).await?;
+        self.close_whitelist(vault_root, &vault_root.vault_admin.pubkey())
+            .await?;
 
         Ok(())
     }
 
-    pub async fn close_whitelist(&mut self, vault_root: &VaultRoot) -> TestResult<()> {
+    pub async fn close_whitelist(
+        &mut self,
+        vault_root: &VaultRoot,
+        rent_receiver: &Pubkey,
+    ) -> TestResult<()> {
         let config = Config::find_program_address(&jito_vault_whitelist_program::id()).0;
         let whitelist = Whitelist::find_program_address(
             &jito_vault_whitelist_program::id(),
@@ -625,6 +944,7 @@ impl VaultWhitelistClient {
             .whitelist(whitelist)
             .vault(vault_root.vault_pubkey)
             .vault_admin(vault_root.vault_admin.pubkey())
+            .rent_receiver(*rent_receiver)
             .jito_vault_program(jito_vault_program::id())
             .instruction();
         ix.program_id = jito_vault_whitelist_program::id();
@@ -639,6 +959,603 @@ impl VaultWhitelistClient {
         ))
         .await
     }
+
+    pub async fn do_close_orphaned_whitelist_user(
+        &mut self,
+        vault_root: &VaultRoot,
+        user: &Pubkey,
+    ) -> TestResult<()> {
+        self.close_orphaned_whitelist_user(vault_root, user, &vault_root.vault_admin.pubkey())
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn close_orphaned_whitelist_user(
+        &mut self,
+        vault_root: &VaultRoot,
+        user: &Pubkey,
+        rent_receiver: &Pubkey,
+    ) -> TestResult<()> {
+        let config = Config::find_program_address(&jito_vault_whitelist_program::id()).0;
+        let whitelist = Whitelist::find_program_address(
+            &jito_vault_whitelist_program::id(),
+            &vault_root.vault_pubkey,
+        )
+        .0;
+        let whitelist_user = WhitelistUser::find_program_address(
+            &jito_vault_whitelist_program::id(),
+            &whitelist,
+            user,
+        )
+        .0;
+
+        let mut ix = CloseOrphanedWhitelistUserBuilder::new()
+            .config(config)
+            .vault(vault_root.vault_pubkey)
+            .whitelist(whitelist)
+            .whitelist_user(whitelist_user)
+            .rent_receiver(*rent_receiver)
+            .user(*user)
+            .instruction();
+        ix.program_id = jito_vault_whitelist_program::id();
+
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&self.payer.pubkey()),
+            &[&self.payer],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn do_set_whitelist_mode(
+        &mut self,
+        vault_root: &VaultRoot,
+        mode: WhitelistMode,
+    ) -> TestResult<()> {
+        self.set_whitelist_mode(vault_root, mode as u8).await
+    }
+
+    pub async fn set_whitelist_mode(&mut self, vault_root: &VaultRoot, mode: u8) -> TestResult<()> {
+        let config = Config::find_program_address(&jito_vault_whitelist_program::id()).0;
+        let whitelist = Whitelist::find_program_address(
+            &jito_vault_whitelist_program::id(),
+            &vault_root.vault_pubkey,
+        )
+        .0;
+
+        let mut ix = SetWhitelistModeBuilder::new()
+            .config(config)
+            .vault(vault_root.vault_pubkey)
+            .whitelist(whitelist)
+            .vault_admin(vault_root.vault_admin.pubkey())
+            .mode(mode)
+            .instruction();
+        ix.program_id = jito_vault_whitelist_program::id();
+
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&vault_root.vault_admin.pubkey()),
+            &[&vault_root.vault_admin],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn do_set_lockup(
+        &mut self,
+        vault_root: &VaultRoot,
+        lockup_slots: u64,
+    ) -> TestResult<()> {
+        self.set_lockup(vault_root, lockup_slots).await
+    }
+
+    pub async fn set_lockup(
+        &mut self,
+        vault_root: &VaultRoot,
+        lockup_slots: u64,
+    ) -> TestResult<()> {
+        let config = Config::find_program_address(&jito_vault_whitelist_program::id()).0;
+        let whitelist = Whitelist::find_program_address(
+            &jito_vault_whitelist_program::id(),
+            &vault_root.vault_pubkey,
+        )
+        .0;
+
+        let mut ix = SetLockupBuilder::new()
+            .config(config)
+            .vault(vault_root.vault_pubkey)
+            .whitelist(whitelist)
+            .vault_admin(vault_root.vault_admin.pubkey())
+            .lockup_slots(lockup_slots)
+            .instruction();
+        ix.program_id = jito_vault_whitelist_program::id();
+
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&vault_root.vault_admin.pubkey()),
+            &[&vault_root.vault_admin],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn do_set_burn_crank(
+        &mut self,
+        vault_root: &VaultRoot,
+        burn_crank: &Pubkey,
+    ) -> TestResult<()> {
+        self.set_burn_crank(vault_root, burn_crank).await
+    }
+
+    pub async fn set_burn_crank(
+        &mut self,
+        vault_root: &VaultRoot,
+        burn_crank: &Pubkey,
+    ) -> TestResult<()> {
+        let config = Config::find_program_address(&jito_vault_whitelist_program::id()).0;
+        let whitelist = Whitelist::find_program_address(
+            &jito_vault_whitelist_program::id(),
+            &vault_root.vault_pubkey,
+        )
+        .0;
+
+        let mut ix = SetBurnCrankBuilder::new()
+            .config(config)
+            .vault(vault_root.vault_pubkey)
+            .whitelist(whitelist)
+            .vault_admin(vault_root.vault_admin.pubkey())
+            .burn_crank(*burn_crank)
+            .instruction();
+        ix.program_id = jito_vault_whitelist_program::id();
+
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&vault_root.vault_admin.pubkey()),
+            &[&vault_root.vault_admin],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn do_set_rate_limit(
+        &mut self,
+        vault_root: &VaultRoot,
+        rate_limit_epochs: u64,
+        mint_limit: u64,
+        enqueue_limit: u64,
+        user_mint_limit: u64,
+        user_enqueue_limit: u64,
+    ) -> TestResult<()> {
+        self.set_rate_limit(
+            vault_root,
+            rate_limit_epochs,
+            mint_limit,
+            enqueue_limit,
+            user_mint_limit,
+            user_enqueue_limit,
+        )
+        .await
+    }
+
+    pub async fn set_rate_limit(
+        &mut self,
+        vault_root: &VaultRoot,
+        rate_limit_epochs: u64,
+        mint_limit: u64,
+        enqueue_limit: u64,
+        user_mint_limit: u64,
+        user_enqueue_limit: u64,
+    ) -> TestResult<()> {
+        let config = Config::find_program_address(&jito_vault_whitelist_program::id()).0;
+        let whitelist = Whitelist::find_program_address(
+            &jito_vault_whitelist_program::id(),
+            &vault_root.vault_pubkey,
+        )
+        .0;
+
+        let mut ix = SetRateLimitBuilder::new()
+            .config(config)
+            .vault(vault_root.vault_pubkey)
+            .whitelist(whitelist)
+            .vault_admin(vault_root.vault_admin.pubkey())
+            .rate_limit_epochs(rate_limit_epochs)
+            .mint_limit(mint_limit)
+            .enqueue_limit(enqueue_limit)
+            .user_mint_limit(user_mint_limit)
+            .user_enqueue_limit(user_enqueue_limit)
+            .instruction();
+        ix.program_id = jito_vault_whitelist_program::id();
+
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&vault_root.vault_admin.pubkey()),
+            &[&vault_root.vault_admin],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn do_set_deposit_limits(
+        &mut self,
+        vault_root: &VaultRoot,
+        min_deposit: u64,
+        max_deposit: u64,
+    ) -> TestResult<()> {
+        self.set_deposit_limits(vault_root, min_deposit, max_deposit)
+            .await
+    }
+
+    pub async fn set_deposit_limits(
+        &mut self,
+        vault_root: &VaultRoot,
+        min_deposit: u64,
+        max_deposit: u64,
+    ) -> TestResult<()> {
+        let config = Config::find_program_address(&jito_vault_whitelist_program::id()).0;
+        let whitelist = Whitelist::find_program_address(
+            &jito_vault_whitelist_program::id(),
+            &vault_root.vault_pubkey,
+        )
+        .0;
+
+        let mut ix = SetDepositLimitsBuilder::new()
+            .config(config)
+            .vault(vault_root.vault_pubkey)
+            .whitelist(whitelist)
+            .vault_admin(vault_root.vault_admin.pubkey())
+            .min_deposit(min_deposit)
+            .max_deposit(max_deposit)
+            .instruction();
+        ix.program_id = jito_vault_whitelist_program::id();
+
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&vault_root.vault_admin.pubkey()),
+            &[&vault_root.vault_admin],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn do_set_user_deposit_limits(
+        &mut self,
+        vault_root: &VaultRoot,
+        user: &Pubkey,
+        min_deposit: u64,
+        max_deposit: u64,
+    ) -> TestResult<()> {
+        self.set_user_deposit_limits(vault_root, user, min_deposit, max_deposit)
+            .await
+    }
+
+    pub async fn set_user_deposit_limits(
+        &mut self,
+        vault_root: &VaultRoot,
+        user: &Pubkey,
+        min_deposit: u64,
+        max_deposit: u64,
+    ) -> TestResult<()> {
+        let config = Config::find_program_address(&jito_vault_whitelist_program::id()).0;
+        let whitelist = Whitelist::find_program_address(
+            &jito_vault_whitelist_program::id(),
+            &vault_root.vault_pubkey,
+        )
+        .0;
+        let whitelist_user = WhitelistUser::find_program_address(
+            &jito_vault_whitelist_program::id(),
+            &whitelist,
+            user,
+        )
+        .0;
+
+        let mut ix = SetUserDepositLimitsBuilder::new()
+            .config(config)
+            .vault(vault_root.vault_pubkey)
+            .whitelist(whitelist)
+            .whitelist_user(whitelist_user)
+            .vault_admin(vault_root.vault_admin.pubkey())
+            .user(*user)
+            .min_deposit(min_deposit)
+            .max_deposit(max_deposit)
+            .instruction();
+        ix.program_id = jito_vault_whitelist_program::id();
+
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&vault_root.vault_admin.pubkey()),
+            &[&vault_root.vault_admin],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn do_set_custodian(
+        &mut self,
+        vault_root: &VaultRoot,
+        user: &Pubkey,
+        custodian: bool,
+    ) -> TestResult<()> {
+        self.set_custodian(vault_root, user, custodian).await
+    }
+
+    pub async fn set_custodian(
+        &mut self,
+        vault_root: &VaultRoot,
+        user: &Pubkey,
+        custodian: bool,
+    ) -> TestResult<()> {
+        let config = Config::find_program_address(&jito_vault_whitelist_program::id()).0;
+        let whitelist = Whitelist::find_program_address(
+            &jito_vault_whitelist_program::id(),
+            &vault_root.vault_pubkey,
+        )
+        .0;
+        let whitelist_user = WhitelistUser::find_program_address(
+            &jito_vault_whitelist_program::id(),
+            &whitelist,
+            user,
+        )
+        .0;
+
+        let mut ix = SetCustodianBuilder::new()
+            .config(config)
+            .vault(vault_root.vault_pubkey)
+            .whitelist(whitelist)
+            .whitelist_user(whitelist_user)
+            .vault_admin(vault_root.vault_admin.pubkey())
+            .user(*user)
+            .custodian(custodian)
+            .instruction();
+        ix.program_id = jito_vault_whitelist_program::id();
+
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&vault_root.vault_admin.pubkey()),
+            &[&vault_root.vault_admin],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn do_set_user_reference(
+        &mut self,
+        vault_root: &VaultRoot,
+        user: &Pubkey,
+        reference: [u8; WHITELIST_USER_REFERENCE_LEN],
+    ) -> TestResult<()> {
+        self.set_user_reference(vault_root, user, reference).await
+    }
+
+    pub async fn set_user_reference(
+        &mut self,
+        vault_root: &VaultRoot,
+        user: &Pubkey,
+        reference: [u8; WHITELIST_USER_REFERENCE_LEN],
+    ) -> TestResult<()> {
+        let config = Config::find_program_address(&jito_vault_whitelist_program::id()).0;
+        let whitelist = Whitelist::find_program_address(
+            &jito_vault_whitelist_program::id(),
+            &vault_root.vault_pubkey,
+        )
+        .0;
+        let whitelist_user = WhitelistUser::find_program_address(
+            &jito_vault_whitelist_program::id(),
+            &whitelist,
+            user,
+        )
+        .0;
+
+        let mut ix = SetUserReferenceBuilder::new()
+            .config(config)
+            .vault(vault_root.vault_pubkey)
+            .whitelist(whitelist)
+            .whitelist_user(whitelist_user)
+            .vault_admin(vault_root.vault_admin.pubkey())
+            .user(*user)
+            .reference(reference)
+            .instruction();
+        ix.program_id = jito_vault_whitelist_program::id();
+
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&vault_root.vault_admin.pubkey()),
+            &[&vault_root.vault_admin],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn do_set_burn_delegate(
+        &mut self,
+        vault_root: &VaultRoot,
+        user: &Keypair,
+        burn_delegate: &Pubkey,
+        permissionless: bool,
+    ) -> TestResult<()> {
+        self.set_burn_delegate(vault_root, user, burn_delegate, permissionless)
+            .await
+    }
+
+    pub async fn set_burn_delegate(
+        &mut self,
+        vault_root: &VaultRoot,
+        user: &Keypair,
+        burn_delegate: &Pubkey,
+        permissionless: bool,
+    ) -> TestResult<()> {
+        let config = Config::find_program_address(&jito_vault_whitelist_program::id()).0;
+        let whitelist = Whitelist::find_program_address(
+            &jito_vault_whitelist_program::id(),
+            &vault_root.vault_pubkey,
+        )
+        .0;
+        let whitelist_user = WhitelistUser::find_program_address(
+            &jito_vault_whitelist_program::id(),
+            &whitelist,
+            &user.pubkey(),
+        )
+        .0;
+
+        let mut ix = SetBurnDelegateBuilder::new()
+            .config(config)
+            .vault(vault_root.vault_pubkey)
+            .whitelist(whitelist)
+            .whitelist_user(whitelist_user)
+            .user(user.pubkey())
+            .burn_delegate(*burn_delegate)
+            .permissionless(permissionless)
+            .instruction();
+        ix.program_id = jito_vault_whitelist_program::id();
+
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&self.payer.pubkey()),
+            &[&self.payer, user],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn do_add_to_blacklist(
+        &mut self,
+        vault_root: &VaultRoot,
+        user: &Pubkey,
+    ) -> TestResult<()> {
+        self.add_to_blacklist(vault_root, user, &vault_root.vault_admin)
+            .await
+    }
+
+    pub async fn add_to_blacklist(
+        &mut self,
+        vault_root: &VaultRoot,
+        user: &Pubkey,
+        payer: &Keypair,
+    ) -> TestResult<()> {
+        let config = Config::find_program_address(&jito_vault_whitelist_program::id()).0;
+        let whitelist = Whitelist::find_program_address(
+            &jito_vault_whitelist_program::id(),
+            &vault_root.vault_pubkey,
+        )
+        .0;
+        let blacklisted = Blacklisted::find_program_address(
+            &jito_vault_whitelist_program::id(),
+            &whitelist,
+            user,
+        )
+        .0;
+
+        let mut ix = AddToBlacklistBuilder::new()
+            .config(config)
+            .vault(vault_root.vault_pubkey)
+            .whitelist(whitelist)
+            .blacklisted(blacklisted)
+            .vault_admin(vault_root.vault_admin.pubkey())
+            .payer(payer.pubkey())
+            .user(*user)
+            .instruction();
+        ix.program_id = jito_vault_whitelist_program::id();
+
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&payer.pubkey()),
+            &[payer, &vault_root.vault_admin],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn do_remove_from_blacklist(
+        &mut self,
+        vault_root: &VaultRoot,
+        user: &Pubkey,
+    ) -> TestResult<()> {
+        self.remove_from_blacklist(vault_root, user, &vault_root.vault_admin.pubkey())
+            .await
+    }
+
+    pub async fn remove_from_blacklist(
+        &mut self,
+        vault_root: &VaultRoot,
+        user: &Pubkey,
+        rent_receiver: &Pubkey,
+    ) -> TestResult<()> {
+        let config = Config::find_program_address(&jito_vault_whitelist_program::id()).0;
+        let whitelist = Whitelist::find_program_address(
+            &jito_vault_whitelist_program::id(),
+            &vault_root.vault_pubkey,
+        )
+        .0;
+        let blacklisted = Blacklisted::find_program_address(
+            &jito_vault_whitelist_program::id(),
+            &whitelist,
+            user,
+        )
+        .0;
+
+        let mut ix = RemoveFromBlacklistBuilder::new()
+            .config(config)
+            .vault(vault_root.vault_pubkey)
+            .whitelist(whitelist)
+            .blacklisted(blacklisted)
+            .vault_admin(vault_root.vault_admin.pubkey())
+            .rent_receiver(*rent_receiver)
+            .user(*user)
+            .instruction();
+        ix.program_id = jito_vault_whitelist_program::id();
+
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&vault_root.vault_admin.pubkey()),
+            &[&vault_root.vault_admin],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn do_migrate_account(&mut self, account: &Pubkey) -> TestResult<()> {
+        self.migrate_account(account).await
+    }
+
+    pub async fn migrate_account(&mut self, account: &Pubkey) -> TestResult<()> {
+        let mut ix = MigrateAccountBuilder::new().account(*account).instruction();
+        ix.program_id = jito_vault_whitelist_program::id();
+
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&self.payer.pubkey()),
+            &[&self.payer],
+            blockhash,
+        ))
+        .await
+    }
 }
 
 #[inline(always)]
@@ -0,0 +1,174 @@
+#[cfg(test)]
+mod tests {
+    use jito_vault_sdk::error::VaultError;
+    use jito_vault_whitelist_core::{blacklisted::Blacklisted, whitelist::Whitelist};
+    use solana_sdk::{
+        instruction::InstructionError, pubkey::Pubkey, signature::Keypair, signer::Signer,
+    };
+
+    use crate::{
+        client::vault_client::assert_vault_error,
+        fixtures::{assert_ix_error, fixture::TestBuilder},
+    };
+
+    #[tokio::test]
+    async fn test_add_to_blacklist() {
+        let fixture = TestBuilder::new().await;
+        let mut vault_program_client = fixture.vault_program_client();
+        vault_program_client.do_initialize_config().await.unwrap();
+        let vault_root = vault_program_client
+            .do_initialize_vault(1000, 1000, 1000, 9, &Pubkey::new_unique())
+            .await
+            .unwrap();
+
+        let mut vault_whitelist_client = fixture.vault_whitelist_program_client();
+        vault_whitelist_client.do_initialize_config().await.unwrap();
+
+        vault_whitelist_client
+            .do_initialize_whitelist(&vault_root)
+            .await
+            .unwrap();
+
+        let payer = Keypair::new();
+        vault_whitelist_client
+            .airdrop(&payer.pubkey(), 1.0)
+            .await
+            .unwrap();
+
+        let user = Pubkey::new_unique();
+
+        vault_whitelist_client
+            .add_to_blacklist(&vault_root, &user, &payer)
+            .await
+            .unwrap();
+
+        let whitelist_pubkey = Whitelist::find_program_address(
+            &jito_vault_whitelist_program::id(),
+            &vault_root.vault_pubkey,
+        )
+        .0;
+        let blacklisted_pubkey = Blacklisted::find_program_address(
+            &jito_vault_whitelist_program::id(),
+            &whitelist_pubkey,
+            &user,
+        )
+        .0;
+        let blacklisted = vault_whitelist_client
+            .get_blacklisted(&blacklisted_pubkey)
+            .await
+            .unwrap();
+
+        assert_eq!(blacklisted.whitelist, whitelist_pubkey);
+        assert_eq!(blacklisted.user, user);
+        assert_eq!(blacklisted.payer, payer.pubkey());
+        assert_eq!(blacklisted.generation(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_add_to_blacklist_reinitializes_stale_marker() {
+        let mut fixture = TestBuilder::new().await;
+        let mut vault_program_client = fixture.vault_program_client();
+        vault_program_client.do_initialize_config().await.unwrap();
+        let vault_root = vault_program_client
+            .do_initialize_vault(1000, 1000, 1000, 9, &Pubkey::new_unique())
+            .await
+            .unwrap();
+
+        let mut vault_whitelist_client = fixture.vault_whitelist_program_client();
+        vault_whitelist_client.do_initialize_config().await.unwrap();
+
+        vault_whitelist_client
+            .do_initialize_whitelist(&vault_root)
+            .await
+            .unwrap();
+
+        let payer = Keypair::new();
+        vault_whitelist_client
+            .airdrop(&payer.pubkey(), 1.0)
+            .await
+            .unwrap();
+
+        let user = Pubkey::new_unique();
+
+        vault_whitelist_client
+            .add_to_blacklist(&vault_root, &user, &payer)
+            .await
+            .unwrap();
+
+        // Advance the slot so re-sent instructions land in a new transaction
+        fixture.warp_slot_incremental(1).await.unwrap();
+
+        let result = vault_whitelist_client
+            .do_add_to_blacklist(&vault_root, &user)
+            .await;
+        assert_ix_error(result, InstructionError::AccountAlreadyInitialized);
+
+        vault_whitelist_client
+            .do_close_whitelist(&vault_root)
+            .await
+            .unwrap();
+
+        vault_whitelist_client
+            .do_initialize_whitelist(&vault_root)
+            .await
+            .unwrap();
+
+        fixture.warp_slot_incremental(1).await.unwrap();
+
+        vault_whitelist_client
+            .do_add_to_blacklist(&vault_root, &user)
+            .await
+            .unwrap();
+
+        let whitelist_pubkey = Whitelist::find_program_address(
+            &jito_vault_whitelist_program::id(),
+            &vault_root.vault_pubkey,
+        )
+        .0;
+        let blacklisted = vault_whitelist_client
+            .get_blacklisted(
+                &Blacklisted::find_program_address(
+                    &jito_vault_whitelist_program::id(),
+                    &whitelist_pubkey,
+                    &user,
+                )
+                .0,
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(blacklisted.generation(), 2);
+        assert_eq!(blacklisted.payer, payer.pubkey());
+    }
+
+    #[tokio::test]
+    async fn test_add_to_blacklist_invalid_vault_admin_fails() {
+        let fixture = TestBuilder::new().await;
+        let mut vault_program_client = fixture.vault_program_client();
+        vault_program_client.do_initialize_config().await.unwrap();
+        let mut vault_root_a = vault_program_client
+            .do_initialize_vault(1000, 1000, 1000, 9, &Pubkey::new_unique())
+            .await
+            .unwrap();
+
+        let mut vault_whitelist_client = fixture.vault_whitelist_program_client();
+        vault_whitelist_client.do_initialize_config().await.unwrap();
+
+        vault_whitelist_client
+            .do_initialize_whitelist(&vault_root_a)
+            .await
+            .unwrap();
+
+        let vault_root_b = vault_program_client
+            .do_initialize_vault(1000, 1000, 1000, 9, &Pubkey::new_unique())
+            .await
+            .unwrap();
+        vault_root_a.vault_admin = vault_root_b.vault_admin;
+
+        let result = vault_whitelist_client
+            .do_add_to_blacklist(&vault_root_a, &Pubkey::new_unique())
+            .await;
+
+        assert_vault_error(result, VaultError::VaultAdminInvalid);
+    }
+}
@@ -1,8 +1,11 @@
 #[cfg(test)]
 mod tests {
     use jito_vault_sdk::error::VaultError;
-    use jito_vault_whitelist_core::{whitelist::Whitelist, whitelist_user::WhitelistUser};
-    use solana_sdk::pubkey::Pubkey;
+    use jito_vault_whitelist_core::{
+        whitelist::Whitelist,
+        whitelist_user::{WhitelistUser, WHITELIST_USER_REFERENCE_LEN},
+    };
+    use solana_sdk::{pubkey::Pubkey, signature::Keypair, signer::Signer};
 
     use crate::{client::vault_client::assert_vault_error, fixtures::fixture::TestBuilder};
 
@@ -49,6 +52,165 @@ mod tests {
 
         assert_eq!(whitelist_user.whitelist, whitelist_pubkey);
         assert_eq!(whitelist_user.user, depositor);
+        assert_eq!(
+            whitelist_user.reference(),
+            &[0; WHITELIST_USER_REFERENCE_LEN]
+        );
+
+        let whitelist = vault_whitelist_client
+            .get_whitelist(&whitelist_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(whitelist.user_count(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_add_to_whitelist_with_payer() {
+        let fixture = TestBuilder::new().await;
+        let mut vault_program_client = fixture.vault_program_client();
+        vault_program_client.do_initialize_config().await.unwrap();
+        let vault_root = vault_program_client
+            .do_initialize_vault(1000, 1000, 1000, 9, &Pubkey::new_unique())
+            .await
+            .unwrap();
+
+        let mut vault_whitelist_client = fixture.vault_whitelist_program_client();
+        vault_whitelist_client.do_initialize_config().await.unwrap();
+
+        vault_whitelist_client
+            .do_initialize_whitelist(&vault_root)
+            .await
+            .unwrap();
+
+        let payer = Keypair::new();
+        vault_whitelist_client
+            .airdrop(&payer.pubkey(), 1.0)
+            .await
+            .unwrap();
+
+        let vault_admin_balance_before = vault_whitelist_client
+            .banks_client
+            .get_balance(vault_root.vault_admin.pubkey())
+            .await
+            .unwrap();
+
+        let depositor = Pubkey::new_unique();
+        let reference = [7; WHITELIST_USER_REFERENCE_LEN];
+
+        vault_whitelist_client
+            .add_to_whitelist(&vault_root, &depositor, &payer, reference)
+            .await
+            .unwrap();
+
+        let vault_admin_balance_after = vault_whitelist_client
+            .banks_client
+            .get_balance(vault_root.vault_admin.pubkey())
+            .await
+            .unwrap();
+
+        assert_eq!(vault_admin_balance_before, vault_admin_balance_after);
+
+        let whitelist_pubkey = Whitelist::find_program_address(
+            &jito_vault_whitelist_program::id(),
+            &vault_root.vault_pubkey,
+        )
+        .0;
+        let whitelist_user_pubkey = WhitelistUser::find_program_address(
+            &jito_vault_whitelist_program::id(),
+            &whitelist_pubkey,
+            &depositor,
+        )
+        .0;
+        let whitelist_user = vault_whitelist_client
+            .get_whitelist_user(&whitelist_user_pubkey)
+            .await
+            .unwrap();
+
+        assert_eq!(whitelist_user.user, depositor);
+        assert_eq!(whitelist_user.payer, payer.pubkey());
+        assert_eq!(whitelist_user.reference(), &reference);
+    }
+
+    #[tokio::test]
+    async fn test_add_to_whitelist_after_recreate() {
+        let fixture = TestBuilder::new().await;
+        let mut vault_program_client = fixture.vault_program_client();
+        vault_program_client.do_initialize_config().await.unwrap();
+        let vault_root = vault_program_client
+            .do_initialize_vault(1000, 1000, 1000, 9, &Pubkey::new_unique())
+            .await
+            .unwrap();
+
+        let mut vault_whitelist_client = fixture.vault_whitelist_program_client();
+        vault_whitelist_client.do_initialize_config().await.unwrap();
+
+        vault_whitelist_client
+            .do_initialize_whitelist(&vault_root)
+            .await
+            .unwrap();
+
+        let payer = Keypair::new();
+        vault_whitelist_client
+            .airdrop(&payer.pubkey(), 1.0)
+            .await
+            .unwrap();
+
+        let depositor = Pubkey::new_unique();
+
+        vault_whitelist_client
+            .add_to_whitelist(
+                &vault_root,
+                &depositor,
+                &payer,
+                [7; WHITELIST_USER_REFERENCE_LEN],
+            )
+            .await
+            .unwrap();
+
+        // The WhitelistUser of the closed whitelist is left at the PDA
+        vault_whitelist_client
+            .do_close_whitelist(&vault_root)
+            .await
+            .unwrap();
+        vault_whitelist_client
+            .do_initialize_whitelist(&vault_root)
+            .await
+            .unwrap();
+
+        vault_whitelist_client
+            .do_add_to_whitelist(&vault_root, &depositor)
+            .await
+            .unwrap();
+
+        let whitelist_pubkey = Whitelist::find_program_address(
+            &jito_vault_whitelist_program::id(),
+            &vault_root.vault_pubkey,
+        )
+        .0;
+        let whitelist = vault_whitelist_client
+            .get_whitelist(&whitelist_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(whitelist.user_count(), 1);
+
+        let whitelist_user_pubkey = WhitelistUser::find_program_address(
+            &jito_vault_whitelist_program::id(),
+            &whitelist_pubkey,
+            &depositor,
+        )
+        .0;
+        let whitelist_user = vault_whitelist_client
+            .get_whitelist_user(&whitelist_user_pubkey)
+            .await
+            .unwrap();
+
+        assert_eq!(whitelist_user.generation(), whitelist.generation());
+        assert_eq!(whitelist_user.user, depositor);
+        assert_eq!(whitelist_user.payer, payer.pubkey());
+        assert_eq!(
+            whitelist_user.reference(),
+            &[0; WHITELIST_USER_REFERENCE_LEN]
+        );
     }
 
     #[tokio::test]
@@ -1,8 +1,13 @@
 #[cfg(test)]
 mod tests {
-    use solana_sdk::pubkey::Pubkey;
+    use jito_vault_whitelist_core::whitelist::Whitelist;
+    use jito_vault_whitelist_sdk::error::VaultWhitelistError;
+    use solana_sdk::{pubkey::Pubkey, signature::Keypair, signer::Signer};
 
-    use crate::fixtures::fixture::TestBuilder;
+    use crate::{
+        client::vault_whitelist_client::assert_vault_whitelist_error,
+        fixtures::fixture::TestBuilder,
+    };
 
     #[tokio::test]
     async fn test_close_whitelist() {
@@ -37,4 +42,72 @@ mod tests {
             .await
             .unwrap();
     }
+
+    #[tokio::test]
+    async fn test_close_whitelist_refunds_payer() {
+        let fixture = TestBuilder::new().await;
+        let mut vault_program_client = fixture.vault_program_client();
+        vault_program_client.do_initialize_config().await.unwrap();
+        let vault_root = vault_program_client
+            .do_initialize_vault(1000, 1000, 1000, 9, &Pubkey::new_unique())
+            .await
+            .unwrap();
+
+        let mut vault_whitelist_client = fixture.vault_whitelist_program_client();
+        vault_whitelist_client.do_initialize_config().await.unwrap();
+
+        let payer = Keypair::new();
+        vault_whitelist_client
+            .airdrop(&payer.pubkey(), 1.0)
+            .await
+            .unwrap();
+
+        vault_whitelist_client
+            .initialize_whitelist(&vault_root, &payer)
+            .await
+            .unwrap();
+
+        vault_whitelist_client
+            .do_set_mint_burn_admin(&vault_root)
+            .await
+            .unwrap();
+
+        let whitelist_pubkey = Whitelist::find_program_address(
+            &jito_vault_whitelist_program::id(),
+            &vault_root.vault_pubkey,
+        )
+        .0;
+        let whitelist_lamports = vault_whitelist_client
+            .banks_client
+            .get_balance(whitelist_pubkey)
+            .await
+            .unwrap();
+        let payer_lamports_before = vault_whitelist_client
+            .banks_client
+            .get_balance(payer.pubkey())
+            .await
+            .unwrap();
+
+        let result = vault_whitelist_client
+            .close_whitelist(&vault_root, &vault_root.vault_admin.pubkey())
+            .await;
+
+        assert_vault_whitelist_error(result, VaultWhitelistError::InvalidRentReceiver);
+
+        vault_whitelist_client
+            .close_whitelist(&vault_root, &payer.pubkey())
+            .await
+            .unwrap();
+
+        let payer_lamports_after = vault_whitelist_client
+            .banks_client
+            .get_balance(payer.pubkey())
+            .await
+            .unwrap();
+
+        assert_eq!(
+            payer_lamports_after,
+            payer_lamports_before + whitelist_lamports
+        );
+    }
 }
@@ -0,0 +1,458 @@
+#[cfg(test)]
+mod tests {
+    use jito_vault_whitelist_core::{whitelist::Whitelist, whitelist_user::WhitelistUser};
+    use jito_vault_whitelist_sdk::error::VaultWhitelistError;
+    use solana_sdk::{pubkey::Pubkey, signature::Keypair, signer::Signer};
+
+    use crate::{
+        client::{
+            vault_client::VaultStakerWithdrawalTicketRoot,
+            vault_whitelist_client::assert_vault_whitelist_error,
+        },
+        fixtures::fixture::{ConfiguredVault, TestBuilder},
+    };
+
+    #[tokio::test]
+    async fn test_crank_burn_withdrawal_ticket_ok() {
+        const MINT_AMOUNT: u64 = 100_000;
+        const DEPOSIT_FEE_BPS: u16 = 100;
+        const WITHDRAWAL_FEE_BPS: u16 = 100;
+
+        let deposit_fee_bps = DEPOSIT_FEE_BPS;
+        let withdrawal_fee_bps = WITHDRAWAL_FEE_BPS;
+        let reward_fee_bps = 0;
+        let num_operators = 1;
+        let slasher_amounts = vec![];
+
+        let mut fixture = TestBuilder::new().await;
+        let ConfiguredVault {
+            mut vault_program_client,
+            mut vault_whitelist_client,
+            vault_root,
+            operator_roots,
+            ..
+        } = fixture
+            .setup_vault_with_ncn_and_operators(
+                deposit_fee_bps,
+                withdrawal_fee_bps,
+                reward_fee_bps,
+                num_operators,
+                &slasher_amounts,
+            )
+            .await
+            .unwrap();
+
+        vault_whitelist_client.do_initialize_config().await.unwrap();
+
+        vault_whitelist_client
+            .do_initialize_whitelist(&vault_root)
+            .await
+            .unwrap();
+
+        vault_whitelist_client
+            .do_set_mint_burn_admin(&vault_root)
+            .await
+            .unwrap();
+
+        let burn_crank = Keypair::new();
+        vault_whitelist_client
+            .do_set_burn_crank(&vault_root, &burn_crank.pubkey())
+            .await
+            .unwrap();
+
+        let vault = vault_program_client
+            .get_vault(&vault_root.vault_pubkey)
+            .await
+            .unwrap();
+
+        let depositor = Keypair::new();
+        vault_program_client
+            .configure_depositor(&vault_root, &depositor.pubkey(), MINT_AMOUNT)
+            .await
+            .unwrap();
+
+        vault_whitelist_client
+            .do_add_to_whitelist(&vault_root, &depositor.pubkey())
+            .await
+            .unwrap();
+
+        let min_amount_out: u64 = 90000;
+
+        vault_whitelist_client
+            .do_mint(&vault_root, &vault, &depositor, MINT_AMOUNT, min_amount_out)
+            .await
+            .unwrap();
+
+        let config = vault_program_client
+            .get_config(
+                &jito_vault_core::config::Config::find_program_address(&jito_vault_program::id()).0,
+            )
+            .await
+            .unwrap();
+        fixture
+            .warp_slot_incremental(2 * config.epoch_length())
+            .await
+            .unwrap();
+
+        let operator_root_pubkeys: Vec<_> = operator_roots
+            .iter()
+            .map(|root| root.operator_pubkey)
+            .collect();
+        vault_program_client
+            .do_full_vault_update(&vault_root.vault_pubkey, &operator_root_pubkeys)
+            .await
+            .unwrap();
+
+        let operator_root = operator_roots.first().unwrap();
+        vault_program_client
+            .do_add_delegation(&vault_root, &operator_root.operator_pubkey, MINT_AMOUNT)
+            .await
+            .unwrap();
+
+        let vault_operator_delegation = vault_program_client
+            .get_vault_operator_delegation(&vault_root.vault_pubkey, &operator_root.operator_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(
+            vault_operator_delegation.delegation_state.staked_amount(),
+            MINT_AMOUNT
+        );
+
+        // the user is withdrawing 99,000 VRT tokens, there is a 1% fee on withdraws, so
+        // 98010 tokens will be undeleged for withdraw
+        let amount_to_dequeue = MINT_AMOUNT * (10_000 - WITHDRAWAL_FEE_BPS) as u64 / 10_000;
+
+        let vault = vault_program_client
+            .get_vault(&vault_root.vault_pubkey)
+            .await
+            .unwrap();
+
+        let VaultStakerWithdrawalTicketRoot { base } = vault_whitelist_client
+            .do_enqueue_withdrawal(&vault_root, &vault, &depositor, amount_to_dequeue)
+            .await
+            .unwrap();
+
+        vault_program_client
+            .do_cooldown_delegation(&vault_root, &operator_roots[0].operator_pubkey, MINT_AMOUNT)
+            .await
+            .unwrap();
+
+        fixture
+            .warp_slot_incremental(config.epoch_length())
+            .await
+            .unwrap();
+        vault_program_client
+            .do_full_vault_update(
+                &vault_root.vault_pubkey,
+                &[operator_roots[0].operator_pubkey],
+            )
+            .await
+            .unwrap();
+        fixture
+            .warp_slot_incremental(config.epoch_length())
+            .await
+            .unwrap();
+        vault_program_client
+            .do_full_vault_update(
+                &vault_root.vault_pubkey,
+                &[operator_roots[0].operator_pubkey],
+            )
+            .await
+            .unwrap();
+
+        // the staker does not sign, the crank burns the ticket on its behalf
+        vault_whitelist_client
+            .do_crank_burn_withdrawal_ticket(
+                &config,
+                &vault_root,
+                &vault,
+                &depositor.pubkey(),
+                &base,
+                &burn_crank,
+            )
+            .await
+            .unwrap();
+
+        let whitelist_pubkey = Whitelist::find_program_address(
+            &jito_vault_whitelist_program::id(),
+            &vault_root.vault_pubkey,
+        )
+        .0;
+        let whitelist = vault_whitelist_client
+            .get_whitelist(&whitelist_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(whitelist.burn_crank, burn_crank.pubkey());
+        assert_eq!(whitelist.total_tickets_burned(), 1);
+
+        let whitelist_user = vault_whitelist_client
+            .get_whitelist_user(
+                &WhitelistUser::find_program_address(
+                    &jito_vault_whitelist_program::id(),
+                    &whitelist_pubkey,
+                    &depositor.pubkey(),
+                )
+                .0,
+            )
+            .await
+            .unwrap();
+        assert!(whitelist_user.total_tokens_withdrawn() > 0);
+    }
+
+    #[tokio::test]
+    async fn test_crank_burn_withdrawal_ticket_burn_delegate_ok() {
+        const MINT_AMOUNT: u64 = 100_000;
+        const DEPOSIT_FEE_BPS: u16 = 100;
+        const WITHDRAWAL_FEE_BPS: u16 = 100;
+
+        let deposit_fee_bps = DEPOSIT_FEE_BPS;
+        let withdrawal_fee_bps = WITHDRAWAL_FEE_BPS;
+        let reward_fee_bps = 0;
+        let num_operators = 1;
+        let slasher_amounts = vec![];
+
+        let mut fixture = TestBuilder::new().await;
+        let ConfiguredVault {
+            mut vault_program_client,
+            mut vault_whitelist_client,
+            vault_root,
+            operator_roots,
+            ..
+        } = fixture
+            .setup_vault_with_ncn_and_operators(
+                deposit_fee_bps,
+                withdrawal_fee_bps,
+                reward_fee_bps,
+                num_operators,
+                &slasher_amounts,
+            )
+            .await
+            .unwrap();
+
+        vault_whitelist_client.do_initialize_config().await.unwrap();
+
+        vault_whitelist_client
+            .do_initialize_whitelist(&vault_root)
+            .await
+            .unwrap();
+
+        vault_whitelist_client
+            .do_set_mint_burn_admin(&vault_root)
+            .await
+            .unwrap();
+
+        let vault = vault_program_client
+            .get_vault(&vault_root.vault_pubkey)
+            .await
+            .unwrap();
+
+        let depositor = Keypair::new();
+        vault_program_client
+            .configure_depositor(&vault_root, &depositor.pubkey(), MINT_AMOUNT)
+            .await
+            .unwrap();
+
+        vault_whitelist_client
+            .do_add_to_whitelist(&vault_root, &depositor.pubkey())
+            .await
+            .unwrap();
+
+        // no whitelist burn crank is set, the staker lets anyone burn its tickets
+        vault_whitelist_client
+            .do_set_burn_delegate(&vault_root, &depositor, &Pubkey::default(), true)
+            .await
+            .unwrap();
+
+        let min_amount_out: u64 = 90000;
+
+        vault_whitelist_client
+            .do_mint(&vault_root, &vault, &depositor, MINT_AMOUNT, min_amount_out)
+            .await
+            .unwrap();
+
+        let config = vault_program_client
+            .get_config(
+                &jito_vault_core::config::Config::find_program_address(&jito_vault_program::id()).0,
+            )
+            .await
+            .unwrap();
+        fixture
+            .warp_slot_incremental(2 * config.epoch_length())
+            .await
+            .unwrap();
+
+        let operator_root_pubkeys: Vec<_> = operator_roots
+            .iter()
+            .map(|root| root.operator_pubkey)
+            .collect();
+        vault_program_client
+            .do_full_vault_update(&vault_root.vault_pubkey, &operator_root_pubkeys)
+            .await
+            .unwrap();
+
+        let operator_root = operator_roots.first().unwrap();
+        vault_program_client
+            .do_add_delegation(&vault_root, &operator_root.operator_pubkey, MINT_AMOUNT)
+            .await
+            .unwrap();
+
+        let vault_operator_delegation = vault_program_client
+            .get_vault_operator_delegation(&vault_root.vault_pubkey, &operator_root.operator_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(
+            vault_operator_delegation.delegation_state.staked_amount(),
+            MINT_AMOUNT
+        );
+
+        // the user is withdrawing 99,000 VRT tokens, there is a 1% fee on withdraws, so
+        // 98010 tokens will be undeleged for withdraw
+        let amount_to_dequeue = MINT_AMOUNT * (10_000 - WITHDRAWAL_FEE_BPS) as u64 / 10_000;
+
+        let vault = vault_program_client
+            .get_vault(&vault_root.vault_pubkey)
+            .await
+            .unwrap();
+
+        let VaultStakerWithdrawalTicketRoot { base } = vault_whitelist_client
+            .do_enqueue_withdrawal(&vault_root, &vault, &depositor, amount_to_dequeue)
+            .await
+            .unwrap();
+
+        vault_program_client
+            .do_cooldown_delegation(&vault_root, &operator_roots[0].operator_pubkey, MINT_AMOUNT)
+            .await
+            .unwrap();
+
+        fixture
+            .warp_slot_incremental(config.epoch_length())
+            .await
+            .unwrap();
+        vault_program_client
+            .do_full_vault_update(
+                &vault_root.vault_pubkey,
+                &[operator_roots[0].operator_pubkey],
+            )
+            .await
+            .unwrap();
+        fixture
+            .warp_slot_incremental(config.epoch_length())
+            .await
+            .unwrap();
+        vault_program_client
+            .do_full_vault_update(
+                &vault_root.vault_pubkey,
+                &[operator_roots[0].operator_pubkey],
+            )
+            .await
+            .unwrap();
+
+        let burn_crank = Keypair::new();
+        vault_whitelist_client
+            .do_crank_burn_withdrawal_ticket(
+                &config,
+                &vault_root,
+                &vault,
+                &depositor.pubkey(),
+                &base,
+                &burn_crank,
+            )
+            .await
+            .unwrap();
+
+        let whitelist_pubkey = Whitelist::find_program_address(
+            &jito_vault_whitelist_program::id(),
+            &vault_root.vault_pubkey,
+        )
+        .0;
+        let whitelist = vault_whitelist_client
+            .get_whitelist(&whitelist_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(whitelist.total_tickets_burned(), 1);
+
+        let whitelist_user = vault_whitelist_client
+            .get_whitelist_user(
+                &WhitelistUser::find_program_address(
+                    &jito_vault_whitelist_program::id(),
+                    &whitelist_pubkey,
+                    &depositor.pubkey(),
+                )
+                .0,
+            )
+            .await
+            .unwrap();
+        assert!(whitelist_user.total_tokens_withdrawn() > 0);
+    }
+
+    #[tokio::test]
+    async fn test_crank_burn_withdrawal_ticket_invalid_burn_crank() {
+        let fixture = TestBuilder::new().await;
+        let mut vault_program_client = fixture.vault_program_client();
+        vault_program_client.do_initialize_config().await.unwrap();
+        let vault_root = vault_program_client
+            .do_initialize_vault(1000, 1000, 1000, 9, &Pubkey::new_unique())
+            .await
+            .unwrap();
+
+        let mut vault_whitelist_client = fixture.vault_whitelist_program_client();
+        vault_whitelist_client.do_initialize_config().await.unwrap();
+
+        vault_whitelist_client
+            .do_initialize_whitelist(&vault_root)
+            .await
+            .unwrap();
+
+        let vault = vault_program_client
+            .get_vault(&vault_root.vault_pubkey)
+            .await
+            .unwrap();
+        let config = vault_program_client
+            .get_config(
+                &jito_vault_core::config::Config::find_program_address(&jito_vault_program::id()).0,
+            )
+            .await
+            .unwrap();
+
+        let staker = Keypair::new();
+        vault_whitelist_client
+            .do_add_to_whitelist(&vault_root, &staker.pubkey())
+            .await
+            .unwrap();
+
+        // no burn crank is set
+        let burn_crank = Keypair::new();
+        let result = vault_whitelist_client
+            .do_crank_burn_withdrawal_ticket(
+                &config,
+                &vault_root,
+                &vault,
+                &staker.pubkey(),
+                &Pubkey::new_unique(),
+                &burn_crank,
+            )
+            .await;
+        assert_vault_whitelist_error(result, VaultWhitelistError::InvalidBurnCrank);
+
+        vault_whitelist_client
+            .do_set_burn_crank(&vault_root, &Pubkey::new_unique())
+            .await
+            .unwrap();
+        vault_whitelist_client
+            .do_set_burn_delegate(&vault_root, &staker, &Pubkey::new_unique(), false)
+            .await
+            .unwrap();
+
+        let result = vault_whitelist_client
+            .do_crank_burn_withdrawal_ticket(
+                &config,
+                &vault_root,
+                &vault,
+                &staker.pubkey(),
+                &Pubkey::new_unique(),
+                &burn_crank,
+            )
+            .await;
+        assert_vault_whitelist_error(result, VaultWhitelistError::InvalidBurnCrank);
+    }
+}
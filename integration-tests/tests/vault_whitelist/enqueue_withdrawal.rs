@@ -1,9 +1,14 @@
 #[cfg(test)]
 mod tests {
+    use jito_vault_whitelist_core::{whitelist::Whitelist, whitelist_user::WhitelistUser};
+    use jito_vault_whitelist_sdk::error::VaultWhitelistError;
     use solana_sdk::{instruction::InstructionError, signature::Keypair, signer::Signer};
 
     use crate::{
-        client::vault_client::VaultStakerWithdrawalTicketRoot,
+        client::{
+            vault_client::VaultStakerWithdrawalTicketRoot,
+            vault_whitelist_client::assert_vault_whitelist_error,
+        },
         fixtures::{
             assert_ix_error,
             fixture::{ConfiguredVault, TestBuilder},
@@ -124,6 +129,31 @@ mod tests {
             .do_enqueue_withdrawal(&vault_root, &vault, &depositor, amount_to_dequeue)
             .await
             .unwrap();
+
+        let whitelist_pubkey = Whitelist::find_program_address(
+            &jito_vault_whitelist_program::id(),
+            &vault_root.vault_pubkey,
+        )
+        .0;
+        let whitelist = vault_whitelist_client
+            .get_whitelist(&whitelist_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(whitelist.total_vrt_enqueued(), amount_to_dequeue);
+
+        let whitelist_user = vault_whitelist_client
+            .get_whitelist_user(
+                &WhitelistUser::find_program_address(
+                    &jito_vault_whitelist_program::id(),
+                    &whitelist_pubkey,
+                    &depositor.pubkey(),
+                )
+                .0,
+            )
+            .await
+            .unwrap();
+        assert_eq!(whitelist_user.total_deposited(), MINT_AMOUNT);
+        assert_eq!(whitelist_user.total_vrt_enqueued(), amount_to_dequeue);
     }
 
     #[tokio::test]
@@ -243,4 +273,111 @@ mod tests {
 
         assert_ix_error(result, InstructionError::InvalidAccountOwner);
     }
+
+    #[tokio::test]
+    async fn test_enqueue_withdrawal_locked() {
+        const MINT_AMOUNT: u64 = 100_000;
+        const DEPOSIT_FEE_BPS: u16 = 100;
+        const WITHDRAWAL_FEE_BPS: u16 = 100;
+
+        let deposit_fee_bps = DEPOSIT_FEE_BPS;
+        let withdrawal_fee_bps = WITHDRAWAL_FEE_BPS;
+        let reward_fee_bps = 0;
+        let num_operators = 1;
+        let slasher_amounts = vec![];
+
+        let mut fixture = TestBuilder::new().await;
+        let ConfiguredVault {
+            mut vault_program_client,
+            mut vault_whitelist_client,
+            vault_root,
+            operator_roots,
+            ..
+        } = fixture
+            .setup_vault_with_ncn_and_operators(
+                deposit_fee_bps,
+                withdrawal_fee_bps,
+                reward_fee_bps,
+                num_operators,
+                &slasher_amounts,
+            )
+            .await
+            .unwrap();
+
+        vault_whitelist_client.do_initialize_config().await.unwrap();
+
+        vault_whitelist_client
+            .do_initialize_whitelist(&vault_root)
+            .await
+            .unwrap();
+
+        vault_whitelist_client
+            .do_set_mint_burn_admin(&vault_root)
+            .await
+            .unwrap();
+
+        let config = vault_program_client
+            .get_config(
+                &jito_vault_core::config::Config::find_program_address(&jito_vault_program::id()).0,
+            )
+            .await
+            .unwrap();
+        let lockup_slots = config.epoch_length();
+
+        vault_whitelist_client
+            .do_set_lockup(&vault_root, lockup_slots)
+            .await
+            .unwrap();
+
+        let vault = vault_program_client
+            .get_vault(&vault_root.vault_pubkey)
+            .await
+            .unwrap();
+
+        let depositor = Keypair::new();
+        vault_program_client
+            .configure_depositor(&vault_root, &depositor.pubkey(), MINT_AMOUNT)
+            .await
+            .unwrap();
+
+        vault_whitelist_client
+            .do_add_to_whitelist(&vault_root, &depositor.pubkey())
+            .await
+            .unwrap();
+
+        let min_amount_out: u64 = 90000;
+
+        vault_whitelist_client
+            .do_mint(&vault_root, &vault, &depositor, MINT_AMOUNT, min_amount_out)
+            .await
+            .unwrap();
+
+        let amount_to_dequeue = MINT_AMOUNT * (10_000 - WITHDRAWAL_FEE_BPS) as u64 / 10_000;
+
+        let result = vault_whitelist_client
+            .do_enqueue_withdrawal(&vault_root, &vault, &depositor, amount_to_dequeue)
+            .await;
+
+        assert_vault_whitelist_error(result, VaultWhitelistError::WithdrawalLocked);
+
+        // the lockup ends one epoch after the mint
+        fixture
+            .warp_slot_incremental(2 * config.epoch_length())
+            .await
+            .unwrap();
+
+        let operator_root_pubkeys: Vec<_> = operator_roots
+            .iter()
+            .map(|root| root.operator_pubkey)
+            .collect();
+        vault_program_client
+            .do_full_vault_update(&vault_root.vault_pubkey, &operator_root_pubkeys)
+            .await
+            .unwrap();
+
+        vault_whitelist_client
+            .do_enqueue_withdrawal(&vault_root, &vault, &depositor, amount_to_dequeue)
+            .await
+            .unwrap();
+    }
 }
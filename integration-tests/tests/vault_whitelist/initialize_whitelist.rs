@@ -1,10 +1,16 @@
 #[cfg(test)]
 mod tests {
     use jito_vault_sdk::error::VaultError;
-    use jito_vault_whitelist_core::whitelist::Whitelist;
-    use solana_sdk::pubkey::Pubkey;
+    use jito_vault_whitelist_core::{whitelist::Whitelist, whitelist_user::WhitelistUser};
+    use jito_vault_whitelist_sdk::error::VaultWhitelistError;
+    use solana_sdk::{pubkey::Pubkey, signature::Keypair, signer::Signer};
 
-    use crate::{client::vault_client::assert_vault_error, fixtures::fixture::TestBuilder};
+    use crate::{
+        client::{
+            vault_client::assert_vault_error, vault_whitelist_client::assert_vault_whitelist_error,
+        },
+        fixtures::fixture::TestBuilder,
+    };
 
     #[tokio::test]
     async fn test_initialize_whitelist() {
@@ -38,6 +44,160 @@ mod tests {
         // assert_eq!(whitelist.meta_merkle_root, meta_merkle_root);
     }
 
+    #[tokio::test]
+    async fn test_initialize_whitelist_with_payer() {
+        let fixture = TestBuilder::new().await;
+        let mut vault_program_client = fixture.vault_program_client();
+        vault_program_client.do_initialize_config().await.unwrap();
+        let vault_root = vault_program_client
+            .do_initialize_vault(1000, 1000, 1000, 9, &Pubkey::new_unique())
+            .await
+            .unwrap();
+
+        let mut vault_whitelist_client = fixture.vault_whitelist_program_client();
+        vault_whitelist_client.do_initialize_config().await.unwrap();
+
+        let payer = Keypair::new();
+        vault_whitelist_client
+            .airdrop(&payer.pubkey(), 1.0)
+            .await
+            .unwrap();
+
+        let vault_admin_balance_before = vault_whitelist_client
+            .banks_client
+            .get_balance(vault_root.vault_admin.pubkey())
+            .await
+            .unwrap();
+        let payer_balance_before = vault_whitelist_client
+            .banks_client
+            .get_balance(payer.pubkey())
+            .await
+            .unwrap();
+
+        vault_whitelist_client
+            .initialize_whitelist(&vault_root, &payer)
+            .await
+            .unwrap();
+
+        let vault_admin_balance_after = vault_whitelist_client
+            .banks_client
+            .get_balance(vault_root.vault_admin.pubkey())
+            .await
+            .unwrap();
+        let payer_balance_after = vault_whitelist_client
+            .banks_client
+            .get_balance(payer.pubkey())
+            .await
+            .unwrap();
+
+        assert_eq!(vault_admin_balance_before, vault_admin_balance_after);
+        assert!(payer_balance_after < payer_balance_before);
+
+        let whitelist_pubkey = Whitelist::find_program_address(
+            &jito_vault_whitelist_program::id(),
+            &vault_root.vault_pubkey,
+        )
+        .0;
+        let whitelist = vault_whitelist_client
+            .get_whitelist(&whitelist_pubkey)
+            .await
+            .unwrap();
+
+        assert_eq!(whitelist.vault, vault_root.vault_pubkey);
+        assert_eq!(whitelist.payer, payer.pubkey());
+    }
+
+    #[tokio::test]
+    async fn test_reinitialize_whitelist_invalidates_users() {
+        let mut fixture = TestBuilder::new().await;
+        let mut vault_program_client = fixture.vault_program_client();
+        vault_program_client.do_initialize_config().await.unwrap();
+        let vault_root = vault_program_client
+            .do_initialize_vault(1000, 1000, 1000, 9, &Pubkey::new_unique())
+            .await
+            .unwrap();
+
+        let mut vault_whitelist_client = fixture.vault_whitelist_program_client();
+        vault_whitelist_client.do_initialize_config().await.unwrap();
+
+        vault_whitelist_client
+            .do_initialize_whitelist(&vault_root)
+            .await
+            .unwrap();
+
+        let depositor = Pubkey::new_unique();
+
+        vault_whitelist_client
+            .do_add_to_whitelist(&vault_root, &depositor)
+            .await
+            .unwrap();
+
+        vault_whitelist_client
+            .do_set_mint_burn_admin(&vault_root)
+            .await
+            .unwrap();
+
+        vault_whitelist_client
+            .do_close_whitelist(&vault_root)
+            .await
+            .unwrap();
+
+        // Advance the slot so re-sent instructions land in a new transaction
+        fixture.warp_slot_incremental(1).await.unwrap();
+
+        vault_whitelist_client
+            .do_initialize_whitelist(&vault_root)
+            .await
+            .unwrap();
+
+        let config = vault_whitelist_client.get_config().await.unwrap();
+        assert_eq!(config.whitelist_generation(), 2);
+
+        let whitelist_pubkey = Whitelist::find_program_address(
+            &jito_vault_whitelist_program::id(),
+            &vault_root.vault_pubkey,
+        )
+        .0;
+        let whitelist = vault_whitelist_client
+            .get_whitelist(&whitelist_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(whitelist.generation(), 2);
+
+        let whitelist_user_pubkey = WhitelistUser::find_program_address(
+            &jito_vault_whitelist_program::id(),
+            &whitelist_pubkey,
+            &depositor,
+        )
+        .0;
+        let whitelist_user = vault_whitelist_client
+            .get_whitelist_user(&whitelist_user_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(whitelist_user.generation(), 1);
+
+        let result = vault_whitelist_client
+            .do_remove_from_whitelist(&vault_root, &depositor)
+            .await;
+        assert_vault_whitelist_error(result, VaultWhitelistError::StaleWhitelistUser);
+
+        vault_whitelist_client
+            .do_close_orphaned_whitelist_user(&vault_root, &depositor)
+            .await
+            .unwrap();
+
+        vault_whitelist_client
+            .do_add_to_whitelist(&vault_root, &depositor)
+            .await
+            .unwrap();
+
+        let whitelist_user = vault_whitelist_client
+            .get_whitelist_user(&whitelist_user_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(whitelist_user.generation(), 2);
+    }
+
     #[tokio::test]
     async fn test_initialize_whitelist_invalid_vault_admin_fails() {
         let fixture = TestBuilder::new().await;
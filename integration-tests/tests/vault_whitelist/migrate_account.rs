@@ -0,0 +1,183 @@
+#[cfg(test)]
+mod tests {
+    use jito_vault_whitelist_core::{
+        blacklisted::Blacklisted, config::Config, version, whitelist::Whitelist,
+    };
+    use solana_sdk::{account::AccountSharedData, instruction::InstructionError, pubkey::Pubkey};
+
+    use crate::fixtures::{assert_ix_error, fixture::TestBuilder};
+
+    #[tokio::test]
+    async fn test_migrate_account_current_config_ok() {
+        let fixture = TestBuilder::new().await;
+        let mut vault_whitelist_client = fixture.vault_whitelist_program_client();
+        vault_whitelist_client.do_initialize_config().await.unwrap();
+
+        let config = vault_whitelist_client.get_config().await.unwrap();
+        assert_eq!(config.version(), Config::VERSION);
+
+        vault_whitelist_client
+            .do_migrate_account(
+                &Config::find_program_address(&jito_vault_whitelist_program::id()).0,
+            )
+            .await
+            .unwrap();
+
+        let config = vault_whitelist_client.get_config().await.unwrap();
+        assert_eq!(config.version(), Config::VERSION);
+    }
+
+    #[tokio::test]
+    async fn test_migrate_account_legacy_whitelist_ok() {
+        let mut fixture = TestBuilder::new().await;
+        let mut vault_program_client = fixture.vault_program_client();
+        vault_program_client.do_initialize_config().await.unwrap();
+        let vault_root = vault_program_client
+            .do_initialize_vault(1000, 1000, 1000, 9, &Pubkey::new_unique())
+            .await
+            .unwrap();
+
+        let mut vault_whitelist_client = fixture.vault_whitelist_program_client();
+        vault_whitelist_client.do_initialize_config().await.unwrap();
+
+        vault_whitelist_client
+            .do_initialize_whitelist(&vault_root)
+            .await
+            .unwrap();
+
+        let burn_crank = Pubkey::new_unique();
+        vault_whitelist_client
+            .do_set_burn_crank(&vault_root, &burn_crank)
+            .await
+            .unwrap();
+
+        // Rewrite the whitelist as created before versioning, holding zero in the version byte
+        let whitelist_pubkey = Whitelist::find_program_address(
+            &jito_vault_whitelist_program::id(),
+            &vault_root.vault_pubkey,
+        )
+        .0;
+        let mut account = fixture
+            .context
+            .banks_client
+            .get_account(whitelist_pubkey)
+            .await
+            .unwrap()
+            .unwrap();
+        account.data[8 + std::mem::offset_of!(Whitelist, version)] = version::LEGACY_VERSION;
+        fixture
+            .context
+            .set_account(&whitelist_pubkey, &AccountSharedData::from(account));
+
+        vault_whitelist_client
+            .do_migrate_account(&whitelist_pubkey)
+            .await
+            .unwrap();
+
+        let account = fixture
+            .context
+            .banks_client
+            .get_account(whitelist_pubkey)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(account.data.len(), data_len);
+
+        let whitelist = vault_whitelist_client
+            .get_whitelist(&whitelist_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(whitelist.version(), version::LEGACY_VERSION);
+
+        vault_whitelist_client
+            .do_migrate_account(&whitelist_pubkey)
+            .await
+            .unwrap();
+
+        let whitelist = vault_whitelist_client
+            .get_whitelist(&whitelist_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(whitelist.version(), Whitelist::VERSION);
+        assert_eq!(whitelist.vault, vault_root.vault_pubkey);
+        assert_eq!(whitelist.burn_crank, burn_crank);
+    }
+
+    #[tokio::test]
+    async fn test_migrate_account_blacklisted_ok() {
+        let mut fixture = TestBuilder::new().await;
+        let mut vault_program_client = fixture.vault_program_client();
+        vault_program_client.do_initialize_config().await.unwrap();
+        let vault_root = vault_program_client
+            .do_initialize_vault(1000, 1000, 1000, 9, &Pubkey::new_unique())
+            .await
+            .unwrap();
+
+        let mut vault_whitelist_client = fixture.vault_whitelist_program_client();
+        vault_whitelist_client.do_initialize_config().await.unwrap();
+
+        vault_whitelist_client
+            .do_initialize_whitelist(&vault_root)
+            .await
+            .unwrap();
+
+        let user = Pubkey::new_unique();
+        vault_whitelist_client
+            .do_add_to_blacklist(&vault_root, &user)
+            .await
+            .unwrap();
+
+        let whitelist_pubkey = Whitelist::find_program_address(
+            &jito_vault_whitelist_program::id(),
+            &vault_root.vault_pubkey,
+        )
+        .0;
+        let blacklisted_pubkey = Blacklisted::find_program_address(
+            &jito_vault_whitelist_program::id(),
+            &whitelist_pubkey,
+            &user,
+        )
+        .0;
+        let mut account = fixture
+            .context
+            .banks_client
+            .get_account(blacklisted_pubkey)
+            .await
+            .unwrap()
+            .unwrap();
+        account.data[8 + std::mem::offset_of!(Blacklisted, version)] = version::LEGACY_VERSION;
+        fixture
+            .context
+            .set_account(&blacklisted_pubkey, &AccountSharedData::from(account));
+
+        vault_whitelist_client
+            .do_migrate_account(&blacklisted_pubkey)
+            .await
+            .unwrap();
+
+        let blacklisted = vault_whitelist_client
+            .get_blacklisted(&blacklisted_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(blacklisted.version(), Blacklisted::VERSION);
+        assert_eq!(blacklisted.user, user);
+    }
+
+    #[tokio::test]
+    async fn test_migrate_account_invalid_owner_fails() {
+        let fixture = TestBuilder::new().await;
+        let mut vault_program_client = fixture.vault_program_client();
+        vault_program_client.do_initialize_config().await.unwrap();
+        let vault_root = vault_program_client
+            .do_initialize_vault(1000, 1000, 1000, 9, &Pubkey::new_unique())
+            .await
+            .unwrap();
+
+        let mut vault_whitelist_client = fixture.vault_whitelist_program_client();
+        let result = vault_whitelist_client
+            .do_migrate_account(&vault_root.vault_pubkey)
+            .await;
+
+        assert_ix_error(result, InstructionError::InvalidAccountOwner);
+    }
+}
@@ -1,10 +1,18 @@
 #[cfg(test)]
 mod tests {
+    use jito_vault_whitelist_core::{
+        whitelist::{Whitelist, WhitelistMode},
+        whitelist_user::WhitelistUser,
+    };
+    use jito_vault_whitelist_sdk::error::VaultWhitelistError;
     use solana_sdk::{
         instruction::InstructionError, pubkey::Pubkey, signature::Keypair, signer::Signer,
     };
 
-    use crate::fixtures::{assert_ix_error, fixture::TestBuilder};
+    use crate::{
+        client::vault_whitelist_client::assert_vault_whitelist_error,
+        fixtures::{assert_ix_error, fixture::TestBuilder},
+    };
 
     const MINT_AMOUNT: u64 = 100_000;
 
@@ -53,6 +61,33 @@ mod tests {
             .do_mint(&vault_root, &vault, &depositor, MINT_AMOUNT, min_amount_out)
             .await
             .unwrap();
+
+        let whitelist_pubkey = Whitelist::find_program_address(
+            &jito_vault_whitelist_program::id(),
+            &vault_root.vault_pubkey,
+        )
+        .0;
+        let whitelist = vault_whitelist_client
+            .get_whitelist(&whitelist_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(whitelist.user_count(), 1);
+        assert_eq!(whitelist.total_minted_in(), MINT_AMOUNT);
+
+        let whitelist_user = vault_whitelist_client
+            .get_whitelist_user(
+                &WhitelistUser::find_program_address(
+                    &jito_vault_whitelist_program::id(),
+                    &whitelist_pubkey,
+                    &depositor.pubkey(),
+                )
+                .0,
+            )
+            .await
+            .unwrap();
+        assert_eq!(whitelist_user.total_deposited(), MINT_AMOUNT);
+        assert!(whitelist_user.total_vrt_minted() >= min_amount_out);
+        assert!(whitelist_user.last_activity_slot() > 0);
     }
 
     #[tokio::test]
@@ -90,7 +125,7 @@ mod tests {
             .unwrap();
 
         vault_whitelist_client
-            .add_to_whitelist(&vault_root, &depositor.pubkey())
+            .do_add_to_whitelist(&vault_root, &depositor.pubkey())
             .await
             .unwrap();
 
@@ -114,4 +149,427 @@ mod tests {
 
         assert_ix_error(result, InstructionError::InvalidAccountOwner);
     }
+
+    #[tokio::test]
+    async fn test_mint_deny_list() {
+        let fixture = TestBuilder::new().await;
+        let mut vault_program_client = fixture.vault_program_client();
+        vault_program_client.do_initialize_config().await.unwrap();
+        let vault_root = vault_program_client
+            .do_initialize_vault(1000, 1000, 1000, 9, &Pubkey::new_unique())
+            .await
+            .unwrap();
+
+        let mut vault_whitelist_client = fixture.vault_whitelist_program_client();
+        vault_whitelist_client.do_initialize_config().await.unwrap();
+
+        vault_whitelist_client
+            .do_initialize_whitelist(&vault_root)
+            .await
+            .unwrap();
+
+        vault_whitelist_client
+            .do_set_mint_burn_admin(&vault_root)
+            .await
+            .unwrap();
+
+        vault_whitelist_client
+            .do_set_whitelist_mode(&vault_root, WhitelistMode::DenyList)
+            .await
+            .unwrap();
+
+        let vault = vault_program_client
+            .get_vault(&vault_root.vault_pubkey)
+            .await
+            .unwrap();
+
+        let depositor = Keypair::new();
+        vault_program_client
+            .configure_depositor(&vault_root, &depositor.pubkey(), MINT_AMOUNT)
+            .await
+            .unwrap();
+
+        let min_amount_out: u64 = 90000;
+
+        vault_whitelist_client
+            .do_mint(&vault_root, &vault, &depositor, MINT_AMOUNT, min_amount_out)
+            .await
+            .unwrap();
+
+        let whitelist = vault_whitelist_client
+            .get_whitelist(
+                &Whitelist::find_program_address(
+                    &jito_vault_whitelist_program::id(),
+                    &vault_root.vault_pubkey,
+                )
+                .0,
+            )
+            .await
+            .unwrap();
+        assert_eq!(whitelist.user_count(), 0);
+        assert_eq!(whitelist.total_minted_in(), MINT_AMOUNT);
+    }
+
+    #[tokio::test]
+    async fn test_mint_deny_list_blacklisted_user_fails() {
+        let fixture = TestBuilder::new().await;
+        let mut vault_program_client = fixture.vault_program_client();
+        vault_program_client.do_initialize_config().await.unwrap();
+        let vault_root = vault_program_client
+            .do_initialize_vault(1000, 1000, 1000, 9, &Pubkey::new_unique())
+            .await
+            .unwrap();
+
+        let mut vault_whitelist_client = fixture.vault_whitelist_program_client();
+        vault_whitelist_client.do_initialize_config().await.unwrap();
+
+        vault_whitelist_client
+            .do_initialize_whitelist(&vault_root)
+            .await
+            .unwrap();
+
+        vault_whitelist_client
+            .do_set_mint_burn_admin(&vault_root)
+            .await
+            .unwrap();
+
+        vault_whitelist_client
+            .do_set_whitelist_mode(&vault_root, WhitelistMode::DenyList)
+            .await
+            .unwrap();
+
+        let vault = vault_program_client
+            .get_vault(&vault_root.vault_pubkey)
+            .await
+            .unwrap();
+
+        let depositor = Keypair::new();
+        vault_program_client
+            .configure_depositor(&vault_root, &depositor.pubkey(), MINT_AMOUNT)
+            .await
+            .unwrap();
+
+        vault_whitelist_client
+            .do_add_to_blacklist(&vault_root, &depositor.pubkey())
+            .await
+            .unwrap();
+
+        let min_amount_out: u64 = 90000;
+
+        let result = vault_whitelist_client
+            .do_mint(&vault_root, &vault, &depositor, MINT_AMOUNT, min_amount_out)
+            .await;
+
+        assert_vault_whitelist_error(result, VaultWhitelistError::UserBlacklisted);
+    }
+
+    #[tokio::test]
+    async fn test_mint_deny_list_stale_blacklisted_user_ok() {
+        let mut fixture = TestBuilder::new().await;
+        let mut vault_program_client = fixture.vault_program_client();
+        vault_program_client.do_initialize_config().await.unwrap();
+        let vault_root = vault_program_client
+            .do_initialize_vault(1000, 1000, 1000, 9, &Pubkey::new_unique())
+            .await
+            .unwrap();
+
+        let mut vault_whitelist_client = fixture.vault_whitelist_program_client();
+        vault_whitelist_client.do_initialize_config().await.unwrap();
+
+        vault_whitelist_client
+            .do_initialize_whitelist(&vault_root)
+            .await
+            .unwrap();
+
+        let depositor = Keypair::new();
+
+        vault_whitelist_client
+            .do_add_to_blacklist(&vault_root, &depositor.pubkey())
+            .await
+            .unwrap();
+
+        vault_whitelist_client
+            .do_close_whitelist(&vault_root)
+            .await
+            .unwrap();
+
+        // Advance the slot so re-sent instructions land in a new transaction
+        fixture.warp_slot_incremental(1).await.unwrap();
+
+        vault_whitelist_client
+            .do_initialize_whitelist(&vault_root)
+            .await
+            .unwrap();
+
+        vault_whitelist_client
+            .do_set_mint_burn_admin(&vault_root)
+            .await
+            .unwrap();
+
+        vault_whitelist_client
+            .do_set_whitelist_mode(&vault_root, WhitelistMode::DenyList)
+            .await
+            .unwrap();
+
+        let vault = vault_program_client
+            .get_vault(&vault_root.vault_pubkey)
+            .await
+            .unwrap();
+
+        vault_program_client
+            .configure_depositor(&vault_root, &depositor.pubkey(), MINT_AMOUNT)
+            .await
+            .unwrap();
+
+        let min_amount_out: u64 = 90000;
+
+        // The marker left behind by the closed whitelist no longer denies the depositor
+        vault_whitelist_client
+            .do_mint(&vault_root, &vault, &depositor, MINT_AMOUNT, min_amount_out)
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_mint_rate_limit() {
+        let fixture = TestBuilder::new().await;
+        let mut vault_program_client = fixture.vault_program_client();
+        vault_program_client.do_initialize_config().await.unwrap();
+        let vault_root = vault_program_client
+            .do_initialize_vault(1000, 1000, 1000, 9, &Pubkey::new_unique())
+            .await
+            .unwrap();
+
+        let mut vault_whitelist_client = fixture.vault_whitelist_program_client();
+        vault_whitelist_client.do_initialize_config().await.unwrap();
+
+        vault_whitelist_client
+            .do_initialize_whitelist(&vault_root)
+            .await
+            .unwrap();
+
+        vault_whitelist_client
+            .do_set_mint_burn_admin(&vault_root)
+            .await
+            .unwrap();
+
+        vault_whitelist_client
+            .do_set_rate_limit(&vault_root, 1, MINT_AMOUNT, 0, MINT_AMOUNT, 0)
+            .await
+            .unwrap();
+
+        let vault = vault_program_client
+            .get_vault(&vault_root.vault_pubkey)
+            .await
+            .unwrap();
+
+        let depositor = Keypair::new();
+        vault_program_client
+            .configure_depositor(&vault_root, &depositor.pubkey(), MINT_AMOUNT)
+            .await
+            .unwrap();
+
+        vault_whitelist_client
+            .do_add_to_whitelist(&vault_root, &depositor.pubkey())
+            .await
+            .unwrap();
+
+        let min_amount_out: u64 = 90000;
+
+        vault_whitelist_client
+            .do_mint(&vault_root, &vault, &depositor, MINT_AMOUNT, min_amount_out)
+            .await
+            .unwrap();
+
+        let whitelist_pubkey = Whitelist::find_program_address(
+            &jito_vault_whitelist_program::id(),
+            &vault_root.vault_pubkey,
+        )
+        .0;
+        let whitelist = vault_whitelist_client
+            .get_whitelist(&whitelist_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(whitelist.window_minted(), MINT_AMOUNT);
+
+        let whitelist_user = vault_whitelist_client
+            .get_whitelist_user(
+                &WhitelistUser::find_program_address(
+                    &jito_vault_whitelist_program::id(),
+                    &whitelist_pubkey,
+                    &depositor.pubkey(),
+                )
+                .0,
+            )
+            .await
+            .unwrap();
+        assert_eq!(whitelist_user.window_minted(), MINT_AMOUNT);
+        assert_eq!(
+            whitelist_user.window_start_epoch(),
+            whitelist.window_start_epoch()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_mint_user_rate_limit_exceeded_fails() {
+        let fixture = TestBuilder::new().await;
+        let mut vault_program_client = fixture.vault_program_client();
+        vault_program_client.do_initialize_config().await.unwrap();
+        let vault_root = vault_program_client
+            .do_initialize_vault(1000, 1000, 1000, 9, &Pubkey::new_unique())
+            .await
+            .unwrap();
+
+        let mut vault_whitelist_client = fixture.vault_whitelist_program_client();
+        vault_whitelist_client.do_initialize_config().await.unwrap();
+
+        vault_whitelist_client
+            .do_initialize_whitelist(&vault_root)
+            .await
+            .unwrap();
+
+        vault_whitelist_client
+            .do_set_mint_burn_admin(&vault_root)
+            .await
+            .unwrap();
+
+        vault_whitelist_client
+            .do_set_rate_limit(&vault_root, 1, MINT_AMOUNT, 0, MINT_AMOUNT / 2, 0)
+            .await
+            .unwrap();
+
+        let vault = vault_program_client
+            .get_vault(&vault_root.vault_pubkey)
+            .await
+            .unwrap();
+
+        let depositor = Keypair::new();
+        vault_program_client
+            .configure_depositor(&vault_root, &depositor.pubkey(), MINT_AMOUNT)
+            .await
+            .unwrap();
+
+        vault_whitelist_client
+            .do_add_to_whitelist(&vault_root, &depositor.pubkey())
+            .await
+            .unwrap();
+
+        let min_amount_out: u64 = 90000;
+
+        let result = vault_whitelist_client
+            .do_mint(&vault_root, &vault, &depositor, MINT_AMOUNT, min_amount_out)
+            .await;
+
+        assert_vault_whitelist_error(result, VaultWhitelistError::RateLimitExceeded);
+    }
+
+    #[tokio::test]
+    async fn test_mint_below_min_deposit_fails() {
+        let fixture = TestBuilder::new().await;
+        let mut vault_program_client = fixture.vault_program_client();
+        vault_program_client.do_initialize_config().await.unwrap();
+        let vault_root = vault_program_client
+            .do_initialize_vault(1000, 1000, 1000, 9, &Pubkey::new_unique())
+            .await
+            .unwrap();
+
+        let mut vault_whitelist_client = fixture.vault_whitelist_program_client();
+        vault_whitelist_client.do_initialize_config().await.unwrap();
+
+        vault_whitelist_client
+            .do_initialize_whitelist(&vault_root)
+            .await
+            .unwrap();
+
+        vault_whitelist_client
+            .do_set_mint_burn_admin(&vault_root)
+            .await
+            .unwrap();
+
+        let vault = vault_program_client
+            .get_vault(&vault_root.vault_pubkey)
+            .await
+            .unwrap();
+
+        let depositor = Keypair::new();
+        vault_program_client
+            .configure_depositor(&vault_root, &depositor.pubkey(), MINT_AMOUNT)
+            .await
+            .unwrap();
+
+        vault_whitelist_client
+            .do_add_to_whitelist(&vault_root, &depositor.pubkey())
+            .await
+            .unwrap();
+
+        vault_whitelist_client
+            .do_set_deposit_limits(&vault_root, MINT_AMOUNT + 1, 0)
+            .await
+            .unwrap();
+
+        let min_amount_out: u64 = 90000;
+
+        let result = vault_whitelist_client
+            .do_mint(&vault_root, &vault, &depositor, MINT_AMOUNT, min_amount_out)
+            .await;
+
+        assert_vault_whitelist_error(result, VaultWhitelistError::DepositTooSmall);
+    }
+
+    #[tokio::test]
+    async fn test_mint_user_deposit_limits_override() {
+        let fixture = TestBuilder::new().await;
+        let mut vault_program_client = fixture.vault_program_client();
+        vault_program_client.do_initialize_config().await.unwrap();
+        let vault_root = vault_program_client
+            .do_initialize_vault(1000, 1000, 1000, 9, &Pubkey::new_unique())
+            .await
+            .unwrap();
+
+        let mut vault_whitelist_client = fixture.vault_whitelist_program_client();
+        vault_whitelist_client.do_initialize_config().await.unwrap();
+
+        vault_whitelist_client
+            .do_initialize_whitelist(&vault_root)
+            .await
+            .unwrap();
+
+        vault_whitelist_client
+            .do_set_mint_burn_admin(&vault_root)
+            .await
+            .unwrap();
+
+        let vault = vault_program_client
+            .get_vault(&vault_root.vault_pubkey)
+            .await
+            .unwrap();
+
+        let depositor = Keypair::new();
+        vault_program_client
+            .configure_depositor(&vault_root, &depositor.pubkey(), MINT_AMOUNT)
+            .await
+            .unwrap();
+
+        vault_whitelist_client
+            .do_add_to_whitelist(&vault_root, &depositor.pubkey())
+            .await
+            .unwrap();
+
+        vault_whitelist_client
+            .do_set_deposit_limits(&vault_root, MINT_AMOUNT + 1, 0)
+            .await
+            .unwrap();
+
+        let min_amount_out: u64 = 90000;
+
+        vault_whitelist_client
+            .do_set_user_deposit_limits(&vault_root, &depositor.pubkey(), MINT_AMOUNT, 0)
+            .await
+            .unwrap();
+
+        vault_whitelist_client
+            .do_mint(&vault_root, &vault, &depositor, MINT_AMOUNT, min_amount_out)
+            .await
+            .unwrap();
+    }
 }
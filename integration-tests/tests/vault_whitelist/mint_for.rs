@@ -0,0 +1,238 @@
+#[cfg(test)]
+mod tests {
+    use jito_vault_whitelist_core::{whitelist::Whitelist, whitelist_user::WhitelistUser};
+    use jito_vault_whitelist_sdk::error::VaultWhitelistError;
+    use solana_sdk::{
+        instruction::InstructionError, pubkey::Pubkey, signature::Keypair, signer::Signer,
+    };
+
+    use crate::{
+        client::vault_whitelist_client::assert_vault_whitelist_error,
+        fixtures::{assert_ix_error, fixture::TestBuilder},
+    };
+
+    const MINT_AMOUNT: u64 = 100_000;
+
+    #[tokio::test]
+    async fn test_mint_for() {
+        let fixture = TestBuilder::new().await;
+        let mut vault_program_client = fixture.vault_program_client();
+        vault_program_client.do_initialize_config().await.unwrap();
+        let vault_root = vault_program_client
+            .do_initialize_vault(1000, 1000, 1000, 9, &Pubkey::new_unique())
+            .await
+            .unwrap();
+
+        let mut vault_whitelist_client = fixture.vault_whitelist_program_client();
+        vault_whitelist_client.do_initialize_config().await.unwrap();
+
+        vault_whitelist_client
+            .do_initialize_whitelist(&vault_root)
+            .await
+            .unwrap();
+
+        vault_whitelist_client
+            .do_set_mint_burn_admin(&vault_root)
+            .await
+            .unwrap();
+
+        let vault = vault_program_client
+            .get_vault(&vault_root.vault_pubkey)
+            .await
+            .unwrap();
+
+        let custodian = Keypair::new();
+        vault_program_client
+            .configure_depositor(&vault_root, &custodian.pubkey(), MINT_AMOUNT)
+            .await
+            .unwrap();
+
+        vault_whitelist_client
+            .do_add_to_whitelist(&vault_root, &custodian.pubkey())
+            .await
+            .unwrap();
+
+        let beneficiary = Pubkey::new_unique();
+        vault_whitelist_client
+            .do_add_to_whitelist(&vault_root, &beneficiary)
+            .await
+            .unwrap();
+
+        vault_whitelist_client
+            .do_set_custodian(&vault_root, &custodian.pubkey(), true)
+            .await
+            .unwrap();
+
+        let min_amount_out: u64 = 90000;
+
+        let result = vault_whitelist_client
+            .do_mint_for(
+                &vault_root,
+                &vault,
+                &custodian,
+                &beneficiary,
+                MINT_AMOUNT,
+                min_amount_out,
+            )
+            .await;
+        result.unwrap();
+
+        let whitelist_pubkey = Whitelist::find_program_address(
+            &jito_vault_whitelist_program::id(),
+            &vault_root.vault_pubkey,
+        )
+        .0;
+
+        let custodian_whitelist_user = vault_whitelist_client
+            .get_whitelist_user(
+                &WhitelistUser::find_program_address(
+                    &jito_vault_whitelist_program::id(),
+                    &whitelist_pubkey,
+                    &custodian.pubkey(),
+                )
+                .0,
+            )
+            .await
+            .unwrap();
+        assert_eq!(custodian_whitelist_user.total_deposited(), MINT_AMOUNT);
+        assert_eq!(custodian_whitelist_user.total_vrt_minted(), 0);
+
+        let beneficiary_whitelist_user = vault_whitelist_client
+            .get_whitelist_user(
+                &WhitelistUser::find_program_address(
+                    &jito_vault_whitelist_program::id(),
+                    &whitelist_pubkey,
+                    &beneficiary,
+                )
+                .0,
+            )
+            .await
+            .unwrap();
+        assert_eq!(beneficiary_whitelist_user.total_deposited(), 0);
+        assert!(beneficiary_whitelist_user.total_vrt_minted() >= min_amount_out);
+        assert!(beneficiary_whitelist_user.last_mint_slot() > 0);
+    }
+
+    #[tokio::test]
+    async fn test_mint_for_not_custodian_fails() {
+        let fixture = TestBuilder::new().await;
+        let mut vault_program_client = fixture.vault_program_client();
+        vault_program_client.do_initialize_config().await.unwrap();
+        let vault_root = vault_program_client
+            .do_initialize_vault(1000, 1000, 1000, 9, &Pubkey::new_unique())
+            .await
+            .unwrap();
+
+        let mut vault_whitelist_client = fixture.vault_whitelist_program_client();
+        vault_whitelist_client.do_initialize_config().await.unwrap();
+
+        vault_whitelist_client
+            .do_initialize_whitelist(&vault_root)
+            .await
+            .unwrap();
+
+        vault_whitelist_client
+            .do_set_mint_burn_admin(&vault_root)
+            .await
+            .unwrap();
+
+        let vault = vault_program_client
+            .get_vault(&vault_root.vault_pubkey)
+            .await
+            .unwrap();
+
+        let custodian = Keypair::new();
+        vault_program_client
+            .configure_depositor(&vault_root, &custodian.pubkey(), MINT_AMOUNT)
+            .await
+            .unwrap();
+
+        vault_whitelist_client
+            .do_add_to_whitelist(&vault_root, &custodian.pubkey())
+            .await
+            .unwrap();
+
+        let beneficiary = Pubkey::new_unique();
+        vault_whitelist_client
+            .do_add_to_whitelist(&vault_root, &beneficiary)
+            .await
+            .unwrap();
+
+        let min_amount_out: u64 = 90000;
+
+        let result = vault_whitelist_client
+            .do_mint_for(
+                &vault_root,
+                &vault,
+                &custodian,
+                &beneficiary,
+                MINT_AMOUNT,
+                min_amount_out,
+            )
+            .await;
+
+        assert_vault_whitelist_error(result, VaultWhitelistError::NotCustodian);
+    }
+
+    #[tokio::test]
+    async fn test_mint_for_beneficiary_not_whitelisted_fails() {
+        let fixture = TestBuilder::new().await;
+        let mut vault_program_client = fixture.vault_program_client();
+        vault_program_client.do_initialize_config().await.unwrap();
+        let vault_root = vault_program_client
+            .do_initialize_vault(1000, 1000, 1000, 9, &Pubkey::new_unique())
+            .await
+            .unwrap();
+
+        let mut vault_whitelist_client = fixture.vault_whitelist_program_client();
+        vault_whitelist_client.do_initialize_config().await.unwrap();
+
+        vault_whitelist_client
+            .do_initialize_whitelist(&vault_root)
+            .await
+            .unwrap();
+
+        vault_whitelist_client
+            .do_set_mint_burn_admin(&vault_root)
+            .await
+            .unwrap();
+
+        let vault = vault_program_client
+            .get_vault(&vault_root.vault_pubkey)
+            .await
+            .unwrap();
+
+        let custodian = Keypair::new();
+        vault_program_client
+            .configure_depositor(&vault_root, &custodian.pubkey(), MINT_AMOUNT)
+            .await
+            .unwrap();
+
+        vault_whitelist_client
+            .do_add_to_whitelist(&vault_root, &custodian.pubkey())
+            .await
+            .unwrap();
+
+        let beneficiary = Pubkey::new_unique();
+
+        vault_whitelist_client
+            .do_set_custodian(&vault_root, &custodian.pubkey(), true)
+            .await
+            .unwrap();
+
+        let min_amount_out: u64 = 90000;
+
+        let result = vault_whitelist_client
+            .do_mint_for(
+                &vault_root,
+                &vault,
+                &custodian,
+                &beneficiary,
+                MINT_AMOUNT,
+                min_amount_out,
+            )
+            .await;
+
+        assert_ix_error(result, InstructionError::InvalidAccountOwner);
+    }
+}
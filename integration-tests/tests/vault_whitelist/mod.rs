@@ -1,9 +1,21 @@
+mod add_to_blacklist;
 mod add_to_whitelist;
 mod burn_withdrawal_ticket;
+mod close_orphaned_whitelist_user;
 mod close_whitelist;
+mod crank_burn_withdrawal_ticket;
 mod enqueue_withdrawal;
 mod initialize_config;
 mod initialize_whitelist;
+mod migrate_account;
 mod mint;
+mod mint_for;
+mod remove_from_blacklist;
 mod remove_from_whitelist;
+mod set_deposit_limits;
+mod set_lockup;
 mod set_mint_burn_admin;
+mod set_rate_limit;
+mod set_user_reference;
+mod set_whitelist_metadata;
+mod set_whitelist_mode;
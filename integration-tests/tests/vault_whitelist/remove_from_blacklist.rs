@@ -0,0 +1,117 @@
+#[cfg(test)]
+mod tests {
+    use jito_vault_whitelist_core::{blacklisted::Blacklisted, whitelist::Whitelist};
+    use jito_vault_whitelist_sdk::error::VaultWhitelistError;
+    use solana_sdk::{pubkey::Pubkey, signature::Keypair, signer::Signer};
+
+    use crate::{
+        client::vault_whitelist_client::assert_vault_whitelist_error,
+        fixtures::fixture::TestBuilder,
+    };
+
+    #[tokio::test]
+    async fn test_remove_from_blacklist_refunds_payer() {
+        let fixture = TestBuilder::new().await;
+        let mut vault_program_client = fixture.vault_program_client();
+        vault_program_client.do_initialize_config().await.unwrap();
+        let vault_root = vault_program_client
+            .do_initialize_vault(1000, 1000, 1000, 9, &Pubkey::new_unique())
+            .await
+            .unwrap();
+
+        let mut vault_whitelist_client = fixture.vault_whitelist_program_client();
+        vault_whitelist_client.do_initialize_config().await.unwrap();
+
+        vault_whitelist_client
+            .do_initialize_whitelist(&vault_root)
+            .await
+            .unwrap();
+
+        let payer = Keypair::new();
+        vault_whitelist_client
+            .airdrop(&payer.pubkey(), 1.0)
+            .await
+            .unwrap();
+
+        let user = Pubkey::new_unique();
+
+        vault_whitelist_client
+            .add_to_blacklist(&vault_root, &user, &payer)
+            .await
+            .unwrap();
+
+        let whitelist_pubkey = Whitelist::find_program_address(
+            &jito_vault_whitelist_program::id(),
+            &vault_root.vault_pubkey,
+        )
+        .0;
+        let blacklisted_pubkey = Blacklisted::find_program_address(
+            &jito_vault_whitelist_program::id(),
+            &whitelist_pubkey,
+            &user,
+        )
+        .0;
+        let blacklisted_lamports = vault_whitelist_client
+            .banks_client
+            .get_balance(blacklisted_pubkey)
+            .await
+            .unwrap();
+        let payer_lamports_before = vault_whitelist_client
+            .banks_client
+            .get_balance(payer.pubkey())
+            .await
+            .unwrap();
+
+        vault_whitelist_client
+            .remove_from_blacklist(&vault_root, &user, &payer.pubkey())
+            .await
+            .unwrap();
+
+        let response = vault_whitelist_client
+            .get_blacklisted(&blacklisted_pubkey)
+            .await;
+        assert!(response.is_err());
+
+        let payer_lamports_after = vault_whitelist_client
+            .banks_client
+            .get_balance(payer.pubkey())
+            .await
+            .unwrap();
+        assert_eq!(
+            payer_lamports_after,
+            payer_lamports_before + blacklisted_lamports
+        );
+    }
+
+    #[tokio::test]
+    async fn test_remove_from_blacklist_invalid_rent_receiver_fails() {
+        let fixture = TestBuilder::new().await;
+        let mut vault_program_client = fixture.vault_program_client();
+        vault_program_client.do_initialize_config().await.unwrap();
+        let vault_root = vault_program_client
+            .do_initialize_vault(1000, 1000, 1000, 9, &Pubkey::new_unique())
+            .await
+            .unwrap();
+
+        let mut vault_whitelist_client = fixture.vault_whitelist_program_client();
+        vault_whitelist_client.do_initialize_config().await.unwrap();
+
+        vault_whitelist_client
+            .do_initialize_whitelist(&vault_root)
+            .await
+            .unwrap();
+
+        let user = Pubkey::new_unique();
+
+        vault_whitelist_client
+            .do_add_to_blacklist(&vault_root, &user)
+            .await
+            .unwrap();
+
+        let result = vault_whitelist_client
+            .remove_from_blacklist(&vault_root, &user, &Pubkey::new_unique())
+            .await;
+
+        assert_vault_whitelist_error(result, VaultWhitelistError::InvalidRentReceiver);
+    }
+}
@@ -1,9 +1,16 @@
 #[cfg(test)]
 mod tests {
-    use jito_vault_whitelist_core::{whitelist::Whitelist, whitelist_user::WhitelistUser};
-    use solana_sdk::pubkey::Pubkey;
+    use jito_vault_whitelist_core::{
+        whitelist::Whitelist,
+        whitelist_user::{WhitelistUser, WHITELIST_USER_REFERENCE_LEN},
+    };
+    use jito_vault_whitelist_sdk::error::VaultWhitelistError;
+    use solana_sdk::{pubkey::Pubkey, signature::Keypair, signer::Signer};
 
-    use crate::fixtures::fixture::TestBuilder;
+    use crate::{
+        client::vault_whitelist_client::assert_vault_whitelist_error,
+        fixtures::fixture::TestBuilder,
+    };
 
     #[tokio::test]
     async fn test_remove_from_whitelist() {
@@ -59,5 +66,118 @@ mod tests {
             .await;
 
         assert!(response.is_err());
+
+        let whitelist = vault_whitelist_client
+            .get_whitelist(&whitelist_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(whitelist.user_count(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_remove_from_whitelist_refunds_payer() {
+        let fixture = TestBuilder::new().await;
+        let mut vault_program_client = fixture.vault_program_client();
+        vault_program_client.do_initialize_config().await.unwrap();
+        let vault_root = vault_program_client
+            .do_initialize_vault(1000, 1000, 1000, 9, &Pubkey::new_unique())
+            .await
+            .unwrap();
+
+        let mut vault_whitelist_client = fixture.vault_whitelist_program_client();
+        vault_whitelist_client.do_initialize_config().await.unwrap();
+
+        vault_whitelist_client
+            .do_initialize_whitelist(&vault_root)
+            .await
+            .unwrap();
+
+        let payer = Keypair::new();
+        vault_whitelist_client
+            .airdrop(&payer.pubkey(), 1.0)
+            .await
+            .unwrap();
+
+        let depositor = Pubkey::new_unique();
+
+        vault_whitelist_client
+            .add_to_whitelist(
+                &vault_root,
+                &depositor,
+                &payer,
+                [0; WHITELIST_USER_REFERENCE_LEN],
+            )
+            .await
+            .unwrap();
+
+        let whitelist_pubkey = Whitelist::find_program_address(
+            &jito_vault_whitelist_program::id(),
+            &vault_root.vault_pubkey,
+        )
+        .0;
+        let whitelist_user_pubkey = WhitelistUser::find_program_address(
+            &jito_vault_whitelist_program::id(),
+            &whitelist_pubkey,
+            &depositor,
+        )
+        .0;
+        let whitelist_user_lamports = vault_whitelist_client
+            .banks_client
+            .get_balance(whitelist_user_pubkey)
+            .await
+            .unwrap();
+        let payer_lamports_before = vault_whitelist_client
+            .banks_client
+            .get_balance(payer.pubkey())
+            .await
+            .unwrap();
+
+        vault_whitelist_client
+            .remove_from_whitelist(&vault_root, &depositor, &payer.pubkey())
+            .await
+            .unwrap();
+
+        let payer_lamports_after = vault_whitelist_client
+            .banks_client
+            .get_balance(payer.pubkey())
+            .await
+            .unwrap();
+
+        assert_eq!(
+            payer_lamports_after,
+            payer_lamports_before + whitelist_user_lamports
+        );
+    }
+
+    #[tokio::test]
+    async fn test_remove_from_whitelist_invalid_rent_receiver_fails() {
+        let fixture = TestBuilder::new().await;
+        let mut vault_program_client = fixture.vault_program_client();
+        vault_program_client.do_initialize_config().await.unwrap();
+        let vault_root = vault_program_client
+            .do_initialize_vault(1000, 1000, 1000, 9, &Pubkey::new_unique())
+            .await
+            .unwrap();
+
+        let mut vault_whitelist_client = fixture.vault_whitelist_program_client();
+        vault_whitelist_client.do_initialize_config().await.unwrap();
+
+        vault_whitelist_client
+            .do_initialize_whitelist(&vault_root)
+            .await
+            .unwrap();
+
+        let depositor = Pubkey::new_unique();
+
+        vault_whitelist_client
+            .do_add_to_whitelist(&vault_root, &depositor)
+            .await
+            .unwrap();
+
+        let result = vault_whitelist_client
+            .remove_from_whitelist(&vault_root, &depositor, &Pubkey::new_unique())
+            .await;
+
+        assert_vault_whitelist_error(result, VaultWhitelistError::InvalidRentReceiver);
     }
 }
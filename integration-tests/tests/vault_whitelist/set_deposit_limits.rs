@@ -0,0 +1,156 @@
+#[cfg(test)]
+mod tests {
+    use jito_vault_sdk::error::VaultError;
+    use jito_vault_whitelist_core::{whitelist::Whitelist, whitelist_user::WhitelistUser};
+    use jito_vault_whitelist_sdk::error::VaultWhitelistError;
+    use solana_sdk::pubkey::Pubkey;
+
+    use crate::{
+        client::{
+            vault_client::assert_vault_error, vault_whitelist_client::assert_vault_whitelist_error,
+        },
+        fixtures::fixture::TestBuilder,
+    };
+
+    #[tokio::test]
+    async fn test_set_deposit_limits() {
+        let fixture = TestBuilder::new().await;
+        let mut vault_program_client = fixture.vault_program_client();
+        vault_program_client.do_initialize_config().await.unwrap();
+        let vault_root = vault_program_client
+            .do_initialize_vault(1000, 1000, 1000, 9, &Pubkey::new_unique())
+            .await
+            .unwrap();
+
+        let mut vault_whitelist_client = fixture.vault_whitelist_program_client();
+        vault_whitelist_client.do_initialize_config().await.unwrap();
+
+        vault_whitelist_client
+            .do_initialize_whitelist(&vault_root)
+            .await
+            .unwrap();
+
+        vault_whitelist_client
+            .do_set_deposit_limits(&vault_root, 100, 1_000)
+            .await
+            .unwrap();
+
+        let whitelist = vault_whitelist_client
+            .get_whitelist(
+                &Whitelist::find_program_address(
+                    &jito_vault_whitelist_program::id(),
+                    &vault_root.vault_pubkey,
+                )
+                .0,
+            )
+            .await
+            .unwrap();
+        assert_eq!(whitelist.min_deposit(), 100);
+        assert_eq!(whitelist.max_deposit(), 1_000);
+    }
+
+    #[tokio::test]
+    async fn test_set_deposit_limits_invalid_vault_admin_fails() {
+        let fixture = TestBuilder::new().await;
+        let mut vault_program_client = fixture.vault_program_client();
+        vault_program_client.do_initialize_config().await.unwrap();
+        let mut vault_root_a = vault_program_client
+            .do_initialize_vault(1000, 1000, 1000, 9, &Pubkey::new_unique())
+            .await
+            .unwrap();
+
+        let mut vault_whitelist_client = fixture.vault_whitelist_program_client();
+        vault_whitelist_client.do_initialize_config().await.unwrap();
+
+        vault_whitelist_client
+            .do_initialize_whitelist(&vault_root_a)
+            .await
+            .unwrap();
+
+        let vault_root_b = vault_program_client
+            .do_initialize_vault(1000, 1000, 1000, 9, &Pubkey::new_unique())
+            .await
+            .unwrap();
+        vault_root_a.vault_admin = vault_root_b.vault_admin;
+
+        let result = vault_whitelist_client
+            .do_set_deposit_limits(&vault_root_a, 100, 1_000)
+            .await;
+
+        assert_vault_error(result, VaultError::VaultAdminInvalid);
+    }
+
+    #[tokio::test]
+    async fn test_set_deposit_limits_min_above_max_fails() {
+        let fixture = TestBuilder::new().await;
+        let mut vault_program_client = fixture.vault_program_client();
+        vault_program_client.do_initialize_config().await.unwrap();
+        let vault_root = vault_program_client
+            .do_initialize_vault(1000, 1000, 1000, 9, &Pubkey::new_unique())
+            .await
+            .unwrap();
+
+        let mut vault_whitelist_client = fixture.vault_whitelist_program_client();
+        vault_whitelist_client.do_initialize_config().await.unwrap();
+
+        vault_whitelist_client
+            .do_initialize_whitelist(&vault_root)
+            .await
+            .unwrap();
+
+        let result = vault_whitelist_client
+            .do_set_deposit_limits(&vault_root, 1_000, 100)
+            .await;
+
+        assert_vault_whitelist_error(result, VaultWhitelistError::InvalidDepositLimits);
+    }
+
+    #[tokio::test]
+    async fn test_set_user_deposit_limits() {
+        let fixture = TestBuilder::new().await;
+        let mut vault_program_client = fixture.vault_program_client();
+        vault_program_client.do_initialize_config().await.unwrap();
+        let vault_root = vault_program_client
+            .do_initialize_vault(1000, 1000, 1000, 9, &Pubkey::new_unique())
+            .await
+            .unwrap();
+
+        let mut vault_whitelist_client = fixture.vault_whitelist_program_client();
+        vault_whitelist_client.do_initialize_config().await.unwrap();
+
+        vault_whitelist_client
+            .do_initialize_whitelist(&vault_root)
+            .await
+            .unwrap();
+
+        let user = Pubkey::new_unique();
+        vault_whitelist_client
+            .do_add_to_whitelist(&vault_root, &user)
+            .await
+            .unwrap();
+
+        vault_whitelist_client
+            .do_set_user_deposit_limits(&vault_root, &user, 10, 10_000)
+            .await
+            .unwrap();
+
+        let whitelist_pubkey = Whitelist::find_program_address(
+            &jito_vault_whitelist_program::id(),
+            &vault_root.vault_pubkey,
+        )
+        .0;
+        let whitelist_user = vault_whitelist_client
+            .get_whitelist_user(
+                &WhitelistUser::find_program_address(
+                    &jito_vault_whitelist_program::id(),
+                    &whitelist_pubkey,
+                    &user,
+                )
+                .0,
+            )
+            .await
+            .unwrap();
+        assert_eq!(whitelist_user.min_deposit(), 10);
+        assert_eq!(whitelist_user.max_deposit(), 10_000);
+    }
+}
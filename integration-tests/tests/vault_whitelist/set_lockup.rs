@@ -0,0 +1,75 @@
+#[cfg(test)]
+mod tests {
+    use jito_vault_sdk::error::VaultError;
+    use jito_vault_whitelist_core::whitelist::Whitelist;
+    use solana_sdk::pubkey::Pubkey;
+
+    use crate::{client::vault_client::assert_vault_error, fixtures::fixture::TestBuilder};
+
+    #[tokio::test]
+    async fn test_set_lockup() {
+        let fixture = TestBuilder::new().await;
+        let mut vault_program_client = fixture.vault_program_client();
+        vault_program_client.do_initialize_config().await.unwrap();
+        let vault_root = vault_program_client
+            .do_initialize_vault(1000, 1000, 1000, 9, &Pubkey::new_unique())
+            .await
+            .unwrap();
+
+        let mut vault_whitelist_client = fixture.vault_whitelist_program_client();
+        vault_whitelist_client.do_initialize_config().await.unwrap();
+
+        vault_whitelist_client
+            .do_initialize_whitelist(&vault_root)
+            .await
+            .unwrap();
+
+        vault_whitelist_client
+            .do_set_lockup(&vault_root, 1_000)
+            .await
+            .unwrap();
+
+        let whitelist = vault_whitelist_client
+            .get_whitelist(
+                &Whitelist::find_program_address(
+                    &jito_vault_whitelist_program::id(),
+                    &vault_root.vault_pubkey,
+                )
+                .0,
+            )
+            .await
+            .unwrap();
+        assert_eq!(whitelist.lockup_slots(), 1_000);
+    }
+
+    #[tokio::test]
+    async fn test_set_lockup_invalid_vault_admin_fails() {
+        let fixture = TestBuilder::new().await;
+        let mut vault_program_client = fixture.vault_program_client();
+        vault_program_client.do_initialize_config().await.unwrap();
+        let mut vault_root_a = vault_program_client
+            .do_initialize_vault(1000, 1000, 1000, 9, &Pubkey::new_unique())
+            .await
+            .unwrap();
+
+        let mut vault_whitelist_client = fixture.vault_whitelist_program_client();
+        vault_whitelist_client.do_initialize_config().await.unwrap();
+
+        vault_whitelist_client
+            .do_initialize_whitelist(&vault_root_a)
+            .await
+            .unwrap();
+
+        let vault_root_b = vault_program_client
+            .do_initialize_vault(1000, 1000, 1000, 9, &Pubkey::new_unique())
+            .await
+            .unwrap();
+        vault_root_a.vault_admin = vault_root_b.vault_admin;
+
+        let result = vault_whitelist_client
+            .do_set_lockup(&vault_root_a, 1_000)
+            .await;
+
+        assert_vault_error(result, VaultError::VaultAdminInvalid);
+    }
+}
@@ -0,0 +1,161 @@
+#[cfg(test)]
+mod tests {
+    use jito_vault_sdk::error::VaultError;
+    use jito_vault_whitelist_core::{
+        whitelist::Whitelist,
+        whitelist_metadata::{WhitelistMetadata, WHITELIST_NAME_LEN, WHITELIST_URI_LEN},
+    };
+    use solana_sdk::pubkey::Pubkey;
+
+    use crate::{client::vault_client::assert_vault_error, fixtures::fixture::TestBuilder};
+
+    #[tokio::test]
+    async fn test_set_whitelist_metadata_ok() {
+        let fixture = TestBuilder::new().await;
+        let mut vault_program_client = fixture.vault_program_client();
+        vault_program_client.do_initialize_config().await.unwrap();
+        let vault_root = vault_program_client
+            .do_initialize_vault(1000, 1000, 1000, 9, &Pubkey::new_unique())
+            .await
+            .unwrap();
+
+        let mut vault_whitelist_client = fixture.vault_whitelist_program_client();
+        vault_whitelist_client.do_initialize_config().await.unwrap();
+
+        vault_whitelist_client
+            .do_initialize_whitelist(&vault_root)
+            .await
+            .unwrap();
+
+        let whitelist_pubkey = Whitelist::find_program_address(
+            &jito_vault_whitelist_program::id(),
+            &vault_root.vault_pubkey,
+        )
+        .0;
+        let whitelist_metadata_pubkey = WhitelistMetadata::find_program_address(
+            &jito_vault_whitelist_program::id(),
+            &whitelist_pubkey,
+        )
+        .0;
+        let whitelist_metadata = vault_whitelist_client
+            .get_whitelist_metadata(&whitelist_metadata_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(whitelist_metadata.whitelist, whitelist_pubkey);
+        assert_eq!(whitelist_metadata.name(), &[0; WHITELIST_NAME_LEN]);
+        assert_eq!(whitelist_metadata.kyc_provider, Pubkey::default());
+        assert_eq!(whitelist_metadata.uri(), &[0; WHITELIST_URI_LEN]);
+
+        let mut name = [0; WHITELIST_NAME_LEN];
+        name[..5].copy_from_slice(b"Alpha");
+        let kyc_provider = Pubkey::new_unique();
+        let mut uri = [0; WHITELIST_URI_LEN];
+        uri[..19].copy_from_slice(b"https://example.com");
+
+        vault_whitelist_client
+            .do_set_whitelist_metadata(&vault_root, name, &kyc_provider, uri)
+            .await
+            .unwrap();
+
+        let whitelist_metadata = vault_whitelist_client
+            .get_whitelist_metadata(&whitelist_metadata_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(whitelist_metadata.name(), &name);
+        assert_eq!(whitelist_metadata.kyc_provider, kyc_provider);
+        assert_eq!(whitelist_metadata.uri(), &uri);
+    }
+
+    #[tokio::test]
+    async fn test_set_whitelist_metadata_reset_on_recreate() {
+        let fixture = TestBuilder::new().await;
+        let mut vault_program_client = fixture.vault_program_client();
+        vault_program_client.do_initialize_config().await.unwrap();
+        let vault_root = vault_program_client
+            .do_initialize_vault(1000, 1000, 1000, 9, &Pubkey::new_unique())
+            .await
+            .unwrap();
+
+        let mut vault_whitelist_client = fixture.vault_whitelist_program_client();
+        vault_whitelist_client.do_initialize_config().await.unwrap();
+
+        vault_whitelist_client
+            .do_initialize_whitelist(&vault_root)
+            .await
+            .unwrap();
+
+        let mut name = [0; WHITELIST_NAME_LEN];
+        name[..5].copy_from_slice(b"Alpha");
+        vault_whitelist_client
+            .do_set_whitelist_metadata(
+                &vault_root,
+                name,
+                &Pubkey::new_unique(),
+                [0; WHITELIST_URI_LEN],
+            )
+            .await
+            .unwrap();
+
+        vault_whitelist_client
+            .do_close_whitelist(&vault_root)
+            .await
+            .unwrap();
+        vault_whitelist_client
+            .do_initialize_whitelist(&vault_root)
+            .await
+            .unwrap();
+
+        let whitelist_pubkey = Whitelist::find_program_address(
+            &jito_vault_whitelist_program::id(),
+            &vault_root.vault_pubkey,
+        )
+        .0;
+        let whitelist_metadata_pubkey = WhitelistMetadata::find_program_address(
+            &jito_vault_whitelist_program::id(),
+            &whitelist_pubkey,
+        )
+        .0;
+        let whitelist_metadata = vault_whitelist_client
+            .get_whitelist_metadata(&whitelist_metadata_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(whitelist_metadata.name(), &[0; WHITELIST_NAME_LEN]);
+        assert_eq!(whitelist_metadata.kyc_provider, Pubkey::default());
+    }
+
+    #[tokio::test]
+    async fn test_set_whitelist_metadata_invalid_vault_admin_fails() {
+        let fixture = TestBuilder::new().await;
+        let mut vault_program_client = fixture.vault_program_client();
+        vault_program_client.do_initialize_config().await.unwrap();
+        let mut vault_root_a = vault_program_client
+            .do_initialize_vault(1000, 1000, 1000, 9, &Pubkey::new_unique())
+            .await
+            .unwrap();
+
+        let mut vault_whitelist_client = fixture.vault_whitelist_program_client();
+        vault_whitelist_client.do_initialize_config().await.unwrap();
+
+        vault_whitelist_client
+            .do_initialize_whitelist(&vault_root_a)
+            .await
+            .unwrap();
+
+        let vault_root_b = vault_program_client
+            .do_initialize_vault(1000, 1000, 1000, 9, &Pubkey::new_unique())
+            .await
+            .unwrap();
+        vault_root_a.vault_admin = vault_root_b.vault_admin;
+
+        let result = vault_whitelist_client
+            .do_set_whitelist_metadata(
+                &vault_root_a,
+                [0; WHITELIST_NAME_LEN],
+                &Pubkey::new_unique(),
+                [0; WHITELIST_URI_LEN],
+            )
+            .await;
+
+        assert_vault_error(result, VaultError::VaultAdminInvalid);
+    }
+}
@@ -0,0 +1,124 @@
+#[cfg(test)]
+mod tests {
+    use jito_vault_sdk::error::VaultError;
+    use jito_vault_whitelist_core::whitelist::{Whitelist, WhitelistMode};
+    use jito_vault_whitelist_sdk::error::VaultWhitelistError;
+    use solana_sdk::pubkey::Pubkey;
+
+    use crate::{
+        client::{
+            vault_client::assert_vault_error, vault_whitelist_client::assert_vault_whitelist_error,
+        },
+        fixtures::fixture::TestBuilder,
+    };
+
+    #[tokio::test]
+    async fn test_set_whitelist_mode() {
+        let mut fixture = TestBuilder::new().await;
+        let mut vault_program_client = fixture.vault_program_client();
+        vault_program_client.do_initialize_config().await.unwrap();
+        let vault_root = vault_program_client
+            .do_initialize_vault(1000, 1000, 1000, 9, &Pubkey::new_unique())
+            .await
+            .unwrap();
+
+        let mut vault_whitelist_client = fixture.vault_whitelist_program_client();
+        vault_whitelist_client.do_initialize_config().await.unwrap();
+
+        vault_whitelist_client
+            .do_initialize_whitelist(&vault_root)
+            .await
+            .unwrap();
+
+        let whitelist_pubkey = Whitelist::find_program_address(
+            &jito_vault_whitelist_program::id(),
+            &vault_root.vault_pubkey,
+        )
+        .0;
+        let whitelist = vault_whitelist_client
+            .get_whitelist(&whitelist_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(whitelist.mode(), Ok(WhitelistMode::AllowList));
+
+        vault_whitelist_client
+            .do_set_whitelist_mode(&vault_root, WhitelistMode::DenyList)
+            .await
+            .unwrap();
+
+        let whitelist = vault_whitelist_client
+            .get_whitelist(&whitelist_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(whitelist.mode(), Ok(WhitelistMode::DenyList));
+
+        fixture.warp_slot_incremental(1).await.unwrap();
+
+        vault_whitelist_client
+            .do_set_whitelist_mode(&vault_root, WhitelistMode::AllowList)
+            .await
+            .unwrap();
+
+        let whitelist = vault_whitelist_client
+            .get_whitelist(&whitelist_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(whitelist.mode(), Ok(WhitelistMode::AllowList));
+    }
+
+    #[tokio::test]
+    async fn test_set_whitelist_mode_invalid_mode() {
+        let fixture = TestBuilder::new().await;
+        let mut vault_program_client = fixture.vault_program_client();
+        vault_program_client.do_initialize_config().await.unwrap();
+        let vault_root = vault_program_client
+            .do_initialize_vault(1000, 1000, 1000, 9, &Pubkey::new_unique())
+            .await
+            .unwrap();
+
+        let mut vault_whitelist_client = fixture.vault_whitelist_program_client();
+        vault_whitelist_client.do_initialize_config().await.unwrap();
+
+        vault_whitelist_client
+            .do_initialize_whitelist(&vault_root)
+            .await
+            .unwrap();
+
+        let result = vault_whitelist_client
+            .set_whitelist_mode(&vault_root, 2)
+            .await;
+
+        assert_vault_whitelist_error(result, VaultWhitelistError::InvalidWhitelistMode);
+    }
+
+    #[tokio::test]
+    async fn test_set_whitelist_mode_invalid_vault_admin_fails() {
+        let fixture = TestBuilder::new().await;
+        let mut vault_program_client = fixture.vault_program_client();
+        vault_program_client.do_initialize_config().await.unwrap();
+        let mut vault_root_a = vault_program_client
+            .do_initialize_vault(1000, 1000, 1000, 9, &Pubkey::new_unique())
+            .await
+            .unwrap();
+
+        let mut vault_whitelist_client = fixture.vault_whitelist_program_client();
+        vault_whitelist_client.do_initialize_config().await.unwrap();
+
+        vault_whitelist_client
+            .do_initialize_whitelist(&vault_root_a)
+            .await
+            .unwrap();
+
+        let vault_root_b = vault_program_client
+            .do_initialize_vault(1000, 1000, 1000, 9, &Pubkey::new_unique())
+            .await
+            .unwrap();
+        vault_root_a.vault_admin = vault_root_b.vault_admin;
+
+        let result = vault_whitelist_client
+            .do_set_whitelist_mode(&vault_root_a, WhitelistMode::DenyList)
+            .await;
+
+        assert_vault_error(result, VaultError::VaultAdminInvalid);
+    }
+}
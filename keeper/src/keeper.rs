@@ -0,0 +1,497 @@
+use std::mem::offset_of;
+
+use anyhow::anyhow;
+use base64::{engine::general_purpose, Engine};
+use jito_bytemuck::{AccountDeserialize, Discriminator};
+use jito_vault_core::{
+    config::Config as VaultConfig, vault::Vault,
+    vault_staker_withdrawal_ticket::VaultStakerWithdrawalTicket,
+};
+use jito_vault_whitelist_client::{
+    instructions::CrankBurnWithdrawalTicketBuilder, withdrawal_ticket,
+};
+use jito_vault_whitelist_core::{
+    blacklisted::Blacklisted,
+    config::Config,
+    whitelist::{Whitelist, WhitelistMode},
+    whitelist_user::WhitelistUser,
+};
+use log::{info, warn};
+use solana_account_decoder::UiAccountEncoding;
+use solana_rpc_client::rpc_client::RpcClient;
+use solana_rpc_client_api::{
+    config::{RpcAccountInfoConfig, RpcProgramAccountsConfig},
+    filter::{Memcmp, MemcmpEncodedBytes, RpcFilterType},
+};
+use solana_sdk::{
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+    transaction::Transaction,
+};
+use spl_associated_token_account::{
+    get_associated_token_address_with_program_id,
+    instruction::create_associated_token_account_idempotent,
+};
+
+/// Whether the keeper burns a withdrawal ticket
+#[derive(Debug, PartialEq, Eq)]
+enum TicketSelection {
+    /// Burn the ticket, passing the account as `whitelist_user`
+    Burn(Pubkey),
+
+    /// The ticket cannot be burned yet
+    NotMatured,
+
+    /// The staker may not use the whitelist
+    NotWhitelisted,
+
+    /// The keeper is neither the burn crank of the whitelist nor a burn delegate of the staker
+    NotBurnCrank,
+}
+
+/// Decide whether to burn a ticket, only looking up the staker's `whitelisted_user_account` once
+/// the ticket has matured
+fn select_ticket(
+    current_epoch: u64,
+    burnable_epoch: u64,
+    is_burn_crank: bool,
+    whitelisted_user_account: impl FnOnce() -> anyhow::Result<Option<(Pubkey, bool)>>,
+) -> anyhow::Result<TicketSelection> {
+    if current_epoch < burnable_epoch {
+        return Ok(TicketSelection::NotMatured);
+    }
+
+    let Some((whitelist_user, burn_delegate)) = whitelisted_user_account()? else {
+        return Ok(TicketSelection::NotWhitelisted);
+    };
+    if !burn_delegate && !is_burn_crank {
+        return Ok(TicketSelection::NotBurnCrank);
+    }
+
+    Ok(TicketSelection::Burn(whitelist_user))
+}
+
+/// Burns matured withdrawal tickets of whitelisted stakers through the whitelist program
+pub struct Keeper {
+    /// RPC client
+    rpc_client: RpcClient,
+
+    /// The burn crank set on the whitelists, pays for every transaction
+    keypair: Keypair,
+
+    /// The Pubkey of Jito Vault Program ID
+    vault_program_id: Pubkey,
+
+    /// The Pubkey of Jito Vault Whitelist Program ID
+    vault_whitelist_program_id: Pubkey,
+}
+
+impl Keeper {
+    pub const fn new(
+        rpc_client: RpcClient,
+        keypair: Keypair,
+        vault_program_id: Pubkey,
+        vault_whitelist_program_id: Pubkey,
+    ) -> Self {
+        Self {
+            rpc_client,
+            keypair,
+            vault_program_id,
+            vault_whitelist_program_id,
+        }
+    }
+
+    /// Creates an RPC program accounts configuration for accounts of type `T`, optionally
+    /// filtered by a pubkey at a byte offset
+    fn program_accounts_config<T: Discriminator>(
+        filter_pubkey: Option<(&Pubkey, usize)>,
+    ) -> anyhow::Result<RpcProgramAccountsConfig> {
+        let data_size = std::mem::size_of::<T>()
+            .checked_add(8)
+            .ok_or_else(|| anyhow!("Failed to add"))?;
+
+        let encoded_discriminator =
+            general_purpose::STANDARD.encode(vec![T::DISCRIMINATOR, 0, 0, 0, 0, 0, 0, 0]);
+
+        let mut filters = vec![
+            RpcFilterType::DataSize(data_size as u64),
+            RpcFilterType::Memcmp(Memcmp::new(
+                0,
+                MemcmpEncodedBytes::Base64(encoded_discriminator),
+            )),
+        ];
+
+        if let Some((pubkey, offset)) = filter_pubkey {
+            filters.push(RpcFilterType::Memcmp(Memcmp::new(
+                offset,
+                MemcmpEncodedBytes::Base64(general_purpose::STANDARD.encode(pubkey.to_bytes())),
+            )));
+        }
+
+        Ok(RpcProgramAccountsConfig {
+            filters: Some(filters),
+            account_config: RpcAccountInfoConfig {
+                encoding: Some(UiAccountEncoding::Base64),
+                ..RpcAccountInfoConfig::default()
+            },
+            with_context: Some(false),
+            sort_results: Some(false),
+        })
+    }
+
+    /// Crank every whitelist of `vaults`, or every whitelist naming this keeper as burn crank
+    /// when `vaults` is empty. On whitelists with another burn crank only the tickets of stakers
+    /// delegating burns to this keeper are burned
+    pub fn crank(&self, vaults: &[Pubkey]) -> anyhow::Result<()> {
+        let whitelists = if vaults.is_empty() {
+            self.burn_crank_whitelists()?
+        } else {
+            let mut whitelists = Vec::with_capacity(vaults.len());
+            for vault in vaults {
+                match self.vault_whitelist(vault) {
+                    Ok(whitelist) => whitelists.push(whitelist),
+                    Err(e) => warn!("Failed to fetch the whitelist of vault {}: {e}", vault),
+                }
+            }
+            whitelists
+        };
+
+        let vault_config = VaultConfig::find_program_address(&self.vault_program_id).0;
+        let vault_config_account = self.rpc_client.get_account(&vault_config)?;
+        let vault_config_account =
+            *VaultConfig::try_from_slice_unchecked(&vault_config_account.data)?;
+
+        for (whitelist_pubkey, whitelist) in whitelists {
+            // A failing vault, e.g. one that needs a vault update, must not stop the others
+            if let Err(e) =
+                self.crank_whitelist(&vault_config_account, &whitelist_pubkey, &whitelist)
+            {
+                warn!("Failed to crank whitelist {}: {e}", whitelist_pubkey);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Fetch the whitelist of `vault`
+    fn vault_whitelist(&self, vault: &Pubkey) -> anyhow::Result<(Pubkey, Whitelist)> {
+        let whitelist = Whitelist::find_program_address(&self.vault_whitelist_program_id, vault).0;
+        let account = self.rpc_client.get_account(&whitelist)?;
+
+        Ok((
+            whitelist,
+            *Whitelist::try_from_slice_unchecked(&account.data)?,
+        ))
+    }
+
+    /// Fetch the whitelists naming this keeper as burn crank
+    fn burn_crank_whitelists(&self) -> anyhow::Result<Vec<(Pubkey, Whitelist)>> {
+        let offset = offset_of!(Whitelist, burn_crank)
+            .checked_add(8)
+            .ok_or_else(|| anyhow!("Failed to add"))?;
+        let config =
+            Self::program_accounts_config::<Whitelist>(Some((&self.keypair.pubkey(), offset)))?;
+        let accounts = self
+            .rpc_client
+            .get_program_accounts_with_config(&self.vault_whitelist_program_id, config)?;
+
+        let mut whitelists = Vec::with_capacity(accounts.len());
+        for (whitelist_pubkey, account) in accounts {
+            whitelists.push((
+                whitelist_pubkey,
+                *Whitelist::try_from_slice_unchecked(&account.data)?,
+            ));
+        }
+
+        Ok(whitelists)
+    }
+
+    /// Burn the matured withdrawal tickets of whitelisted stakers on the vault of `whitelist`
+    fn crank_whitelist(
+        &self,
+        vault_config_account: &VaultConfig,
+        whitelist_pubkey: &Pubkey,
+        whitelist: &Whitelist,
+    ) -> anyhow::Result<()> {
+        let vault_pubkey = whitelist.vault;
+        let vault_account = self.rpc_client.get_account(&vault_pubkey)?;
+        let vault = *Vault::try_from_slice_unchecked(&vault_account.data)?;
+
+        let epoch_length = vault_config_account.epoch_length();
+        let current_epoch = self
+            .rpc_client
+            .get_slot()?
+            .checked_div(epoch_length)
+            .ok_or_else(|| anyhow!("Vault config epoch length is zero"))?;
+
+        let offset = offset_of!(VaultStakerWithdrawalTicket, vault)
+            .checked_add(8)
+            .ok_or_else(|| anyhow!("Failed to add"))?;
+        let config = Self::program_accounts_config::<VaultStakerWithdrawalTicket>(Some((
+            &vault_pubkey,
+            offset,
+        )))?;
+        let accounts = self
+            .rpc_client
+            .get_program_accounts_with_config(&self.vault_program_id, config)?;
+
+        let is_burn_crank = whitelist.burn_crank.eq(&self.keypair.pubkey());
+
+        for (ticket_pubkey, account) in accounts {
+            let ticket = VaultStakerWithdrawalTicket::try_from_slice_unchecked(&account.data)?;
+
+            let burnable_epoch =
+                withdrawal_ticket::burnable_epoch(ticket.slot_unstaked(), epoch_length)
+                    .ok_or_else(|| anyhow!("Failed to compute burnable epoch"))?;
+            let selection = select_ticket(current_epoch, burnable_epoch, is_burn_crank, || {
+                self.whitelisted_user_account(whitelist_pubkey, whitelist, &ticket.staker)
+            });
+            let whitelist_user = match selection {
+                Ok(TicketSelection::Burn(whitelist_user)) => whitelist_user,
+                Ok(TicketSelection::NotWhitelisted) => {
+                    info!(
+                        "Skipping ticket {}, staker {} is not whitelisted",
+                        ticket_pubkey, ticket.staker
+                    );
+                    continue;
+                }
+                Ok(TicketSelection::NotMatured | TicketSelection::NotBurnCrank) => continue,
+                Err(e) => {
+                    warn!("Failed to check ticket {}: {e}", ticket_pubkey);
+                    continue;
+                }
+            };
+
+            match self.burn_withdrawal_ticket(
+                vault_config_account,
+                &vault,
+                whitelist_pubkey,
+                &whitelist_user,
+                &ticket_pubkey,
+                ticket,
+            ) {
+                Ok(()) => info!(
+                    "Burned ticket {} of staker {} on vault {}",
+                    ticket_pubkey, ticket.staker, vault_pubkey
+                ),
+                Err(e) => warn!("Failed to burn ticket {}: {e}", ticket_pubkey),
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Returns the account passed as `whitelist_user` for `staker` and whether the staker
+    /// delegates burns to this keeper, or `None` when the staker may not use the whitelist
+    fn whitelisted_user_account(
+        &self,
+        whitelist_pubkey: &Pubkey,
+        whitelist: &Whitelist,
+        staker: &Pubkey,
+    ) -> anyhow::Result<Option<(Pubkey, bool)>> {
+        match whitelist.mode()? {
+            WhitelistMode::AllowList => {
+                let whitelist_user = WhitelistUser::find_program_address(
+                    &self.vault_whitelist_program_id,
+                    whitelist_pubkey,
+                    staker,
+                )
+                .0;
+                let Some(account) = self
+                    .rpc_client
+                    .get_account_with_commitment(&whitelist_user, self.rpc_client.commitment())?
+                    .value
+                else {
+                    return Ok(None);
+                };
+
+                let user = WhitelistUser::try_from_slice_unchecked(&account.data)?;
+
+                // Users of an earlier whitelist at the same PDA are not members
+                Ok((user.generation() == whitelist.generation()).then(|| {
+                    (
+                        whitelist_user,
+                        user.is_burn_delegate(&self.keypair.pubkey()),
+                    )
+                }))
+            }
+            WhitelistMode::DenyList => {
+                let blacklisted = Blacklisted::find_program_address(
+                    &self.vault_whitelist_program_id,
+                    whitelist_pubkey,
+                    staker,
+                )
+                .0;
+                let Some(account) = self
+                    .rpc_client
+                    .get_account_with_commitment(&blacklisted, self.rpc_client.commitment())?
+                    .value
+                else {
+                    return Ok(Some((blacklisted, false)));
+                };
+
+                let marker = Blacklisted::try_from_slice_unchecked(&account.data)?;
+
+                // Markers of an earlier whitelist at the same PDA do not deny the staker
+                Ok((marker.generation() != whitelist.generation()).then_some((blacklisted, false)))
+            }
+        }
+    }
+
+    /// Returns the token program owning `mint`, rejecting Token-2022 mints like the CLI does
+    fn token_program_id(&self, mint: &Pubkey) -> anyhow::Result<Pubkey> {
+        let account = self.rpc_client.get_account(mint)?;
+        if account.owner.eq(&spl_token_2022::id()) {
+            return Err(anyhow!(
+                "Mint {} is a Token-2022 mint, which the vault whitelist does not support",
+                mint
+            ));
+        }
+        if account.owner.ne(&spl_token::id()) {
+            return Err(anyhow!(
+                "Mint {} is owned by {}, which is not a token program",
+                mint,
+                account.owner
+            ));
+        }
+
+        Ok(account.owner)
+    }
+
+    /// Submit `CrankBurnWithdrawalTicket` for `ticket`, paying out to the staker's ATA
+    fn burn_withdrawal_ticket(
+        &self,
+        vault_config_account: &VaultConfig,
+        vault: &Vault,
+        whitelist: &Pubkey,
+        whitelist_user: &Pubkey,
+        ticket_pubkey: &Pubkey,
+        ticket: &VaultStakerWithdrawalTicket,
+    ) -> anyhow::Result<()> {
+        let keeper = self.keypair.pubkey();
+        let vault_pubkey = &ticket.vault;
+        let staker = &ticket.staker;
+
+        let supported_token_program = self.token_program_id(&vault.supported_mint)?;
+        let vrt_token_program = self.token_program_id(&vault.vrt_mint)?;
+
+        let staker_token_account = get_associated_token_address_with_program_id(
+            staker,
+            &vault.supported_mint,
+            &supported_token_program,
+        );
+        let program_fee_token_account = get_associated_token_address_with_program_id(
+            &vault_config_account.program_fee_wallet,
+            &vault.vrt_mint,
+            &vrt_token_program,
+        );
+
+        let staker_ata_ix = create_associated_token_account_idempotent(
+            &keeper,
+            staker,
+            &vault.supported_mint,
+            &supported_token_program,
+        );
+        let program_fee_ata_ix = create_associated_token_account_idempotent(
+            &keeper,
+            &vault_config_account.program_fee_wallet,
+            &vault.vrt_mint,
+            &vrt_token_program,
+        );
+
+        let mut ix_builder = CrankBurnWithdrawalTicketBuilder::new();
+        ix_builder
+            .vault_config(VaultConfig::find_program_address(&self.vault_program_id).0)
+            .vault(*vault_pubkey)
+            .vault_token_account(get_associated_token_address_with_program_id(
+                vault_pubkey,
+                &vault.supported_mint,
+                &supported_token_program,
+            ))
+            .vrt_mint(vault.vrt_mint)
+            .staker(*staker)
+            .staker_token_account(staker_token_account)
+            .vault_staker_withdrawal_ticket(*ticket_pubkey)
+            .vault_staker_withdrawal_ticket_token_account(
+                get_associated_token_address_with_program_id(
+                    ticket_pubkey,
+                    &vault.vrt_mint,
+                    &vrt_token_program,
+                ),
+            )
+            .vault_fee_token_account(get_associated_token_address_with_program_id(
+                &vault.fee_wallet,
+                &vault.vrt_mint,
+                &vrt_token_program,
+            ))
+            .program_fee_token_account(program_fee_token_account)
+            // Forwarded to the Jito Vault burn, which burns the ticket's VRT with it
+            .token_program(vrt_token_program)
+            .config(Config::find_program_address(&self.vault_whitelist_program_id).0)
+            .whitelist(*whitelist)
+            .whitelist_user(*whitelist_user)
+            .jito_vault_program(self.vault_program_id)
+            .burn_crank(keeper);
+
+        let mut ix = ix_builder.instruction();
+        ix.program_id = self.vault_whitelist_program_id;
+
+        let blockhash = self.rpc_client.get_latest_blockhash()?;
+        let tx = Transaction::new_signed_with_payer(
+            &[staker_ata_ix, program_fee_ata_ix, ix],
+            Some(&keeper),
+            &[&self.keypair],
+            blockhash,
+        );
+        let signature = self.rpc_client.send_and_confirm_transaction(&tx)?;
+
+        info!("Transaction confirmed: {:?}", signature);
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::anyhow;
+    use solana_sdk::pubkey::Pubkey;
+
+    use super::{select_ticket, TicketSelection};
+
+    #[test]
+    fn test_select_ticket_not_matured() {
+        let selection = select_ticket(3, 4, true, || panic!("looked up an immature ticket"));
+        assert_eq!(selection.unwrap(), TicketSelection::NotMatured);
+    }
+
+    #[test]
+    fn test_select_ticket_not_whitelisted() {
+        let selection = select_ticket(4, 4, true, || Ok(None));
+        assert_eq!(selection.unwrap(), TicketSelection::NotWhitelisted);
+    }
+
+    #[test]
+    fn test_select_ticket_burn_crank() {
+        let whitelist_user = Pubkey::new_unique();
+
+        let selection = select_ticket(5, 4, true, || Ok(Some((whitelist_user, false))));
+        assert_eq!(selection.unwrap(), TicketSelection::Burn(whitelist_user));
+
+        let selection = select_ticket(5, 4, false, || Ok(Some((whitelist_user, false))));
+        assert_eq!(selection.unwrap(), TicketSelection::NotBurnCrank);
+    }
+
+    #[test]
+    fn test_select_ticket_burn_delegate() {
+        let whitelist_user = Pubkey::new_unique();
+
+        let selection = select_ticket(4, 4, false, || Ok(Some((whitelist_user, true))));
+        assert_eq!(selection.unwrap(), TicketSelection::Burn(whitelist_user));
+    }
+
+    #[test]
+    fn test_select_ticket_lookup_error() {
+        let selection = select_ticket(4, 4, true, || Err(anyhow!("RPC unavailable")));
+        assert!(selection.is_err());
+    }
+}
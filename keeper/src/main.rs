@@ -0,0 +1,74 @@
+use std::{path::PathBuf, thread, time::Duration};
+
+use anyhow::anyhow;
+use clap::Parser;
+use env_logger::Env;
+use jito_vault_client::programs::JITO_VAULT_ID;
+use jito_vault_whitelist_client::programs::JITO_VAULT_WHITELIST_ID;
+use jito_vault_whitelist_keeper::keeper::Keeper;
+use log::{error, info};
+use solana_rpc_client::rpc_client::RpcClient;
+use solana_sdk::{
+    commitment_config::CommitmentConfig, pubkey::Pubkey, signature::read_keypair_file,
+};
+
+#[derive(Parser)]
+#[command(author, version, about = "Burns matured withdrawal tickets of whitelisted stakers", long_about = None)]
+struct Args {
+    #[arg(
+        long,
+        env,
+        default_value = "http://localhost:8899",
+        help = "RPC URL to use"
+    )]
+    rpc_url: String,
+
+    #[arg(long, env, help = "Path to the keypair of the whitelist burn crank")]
+    keypair_path: PathBuf,
+
+    #[arg(long, env, default_value_t = JITO_VAULT_ID, help = "Vault program ID")]
+    vault_program_id: Pubkey,
+
+    #[arg(long, env, default_value_t = JITO_VAULT_WHITELIST_ID, help = "Vault whitelist program ID")]
+    vault_whitelist_program_id: Pubkey,
+
+    #[arg(
+        long,
+        env,
+        value_delimiter = ',',
+        help = "Vaults to crank, defaults to every vault whose whitelist names this keypair as burn crank. Listed vaults also have the tickets of stakers delegating burns to this keypair burned"
+    )]
+    vaults: Vec<Pubkey>,
+
+    #[arg(long, env, default_value_t = 60, help = "Seconds between polls")]
+    poll_interval_secs: u64,
+}
+
+fn main() -> anyhow::Result<()> {
+    env_logger::Builder::from_env(Env::default().default_filter_or("info")).init();
+
+    let args = Args::parse();
+
+    let keypair = read_keypair_file(&args.keypair_path)
+        .map_err(|e| anyhow!("Failed to read keypair {:?}: {e}", args.keypair_path))?;
+
+    let keeper = Keeper::new(
+        RpcClient::new_with_commitment(args.rpc_url, CommitmentConfig::confirmed()),
+        keypair,
+        args.vault_program_id,
+        args.vault_whitelist_program_id,
+    );
+
+    info!(
+        "Starting keeper, poll interval {}s",
+        args.poll_interval_secs
+    );
+
+    loop {
+        if let Err(e) = keeper.crank(&args.vaults) {
+            error!("Failed to crank withdrawal tickets: {e}");
+        }
+
+        thread::sleep(Duration::from_secs(args.poll_interval_secs));
+    }
+}
@@ -21,6 +21,8 @@ import {
   getBytesEncoder,
   getStructDecoder,
   getStructEncoder,
+  getU64Decoder,
+  getU64Encoder,
   getU8Decoder,
   getU8Encoder,
   type Account,
@@ -37,29 +39,46 @@ import {
 } from '@solana/kit';
 
 export type Config = {
+  discriminator: bigint;
   admin: Address;
   vaultProgram: Address;
   bump: number;
+  whitelistGeneration: bigint;
+  version: number;
   reserved: ReadonlyUint8Array;
 };
 
-export type ConfigArgs = Config;
+export type ConfigArgs = {
+  discriminator: number | bigint;
+  admin: Address;
+  vaultProgram: Address;
+  bump: number;
+  whitelistGeneration: number | bigint;
+  version: number;
+  reserved: ReadonlyUint8Array;
+};
 
 export function getConfigEncoder(): Encoder<ConfigArgs> {
   return getStructEncoder([
+    ['discriminator', getU64Encoder()],
     ['admin', getAddressEncoder()],
     ['vaultProgram', getAddressEncoder()],
     ['bump', getU8Encoder()],
-    ['reserved', fixEncoderSize(getBytesEncoder(), 263)],
+    ['whitelistGeneration', getU64Encoder()],
+    ['version', getU8Encoder()],
+    ['reserved', fixEncoderSize(getBytesEncoder(), 254)],
   ]);
 }
 
 export function getConfigDecoder(): Decoder<Config> {
   return getStructDecoder([
+    ['discriminator', getU64Decoder()],
     ['admin', getAddressDecoder()],
     ['vaultProgram', getAddressDecoder()],
     ['bump', getU8Decoder()],
-    ['reserved', fixDecoderSize(getBytesDecoder(), 263)],
+    ['whitelistGeneration', getU64Decoder()],
+    ['version', getU8Decoder()],
+    ['reserved', fixDecoderSize(getBytesDecoder(), 254)],
   ]);
 }
 
@@ -121,5 +140,5 @@ export async function fetchAllMaybeConfig(
 }
 
 export function getConfigSize(): number {
-  return 328;
+  return 336;
 }
@@ -6,6 +6,8 @@
  * @see https://github.com/codama-idl/codama
  */
 
+export * from './blacklisted';
 export * from './config';
 export * from './whitelist';
+export * from './whitelistMetadata';
 export * from './whitelistUser';
@@ -21,6 +21,8 @@ import {
   getBytesEncoder,
   getStructDecoder,
   getStructEncoder,
+  getU64Decoder,
+  getU64Encoder,
   getU8Decoder,
   getU8Encoder,
   type Account,
@@ -37,26 +39,114 @@ import {
 } from '@solana/kit';
 
 export type Whitelist = {
+  discriminator: bigint;
   vault: Address;
   bump: number;
+  payer: Address;
+  generation: bigint;
+  userCount: bigint;
+  totalMintedIn: bigint;
+  totalVrtEnqueued: bigint;
+  totalTicketsBurned: bigint;
+  mode: number;
+  lockupSlots: bigint;
+  rateLimitEpochs: bigint;
+  mintLimit: bigint;
+  enqueueLimit: bigint;
+  userMintLimit: bigint;
+  userEnqueueLimit: bigint;
+  windowStartEpoch: bigint;
+  windowMinted: bigint;
+  windowEnqueued: bigint;
+  minDeposit: bigint;
+  maxDeposit: bigint;
+  burnCrank: Address;
+  version: number;
   reserved: ReadonlyUint8Array;
 };
 
-export type WhitelistArgs = Whitelist;
+export type WhitelistArgs = {
+  discriminator: number | bigint;
+  vault: Address;
+  bump: number;
+  payer: Address;
+  generation: number | bigint;
+  userCount: number | bigint;
+  totalMintedIn: number | bigint;
+  totalVrtEnqueued: number | bigint;
+  totalTicketsBurned: number | bigint;
+  mode: number;
+  lockupSlots: number | bigint;
+  rateLimitEpochs: number | bigint;
+  mintLimit: number | bigint;
+  enqueueLimit: number | bigint;
+  userMintLimit: number | bigint;
+  userEnqueueLimit: number | bigint;
+  windowStartEpoch: number | bigint;
+  windowMinted: number | bigint;
+  windowEnqueued: number | bigint;
+  minDeposit: number | bigint;
+  maxDeposit: number | bigint;
+  burnCrank: Address;
+  version: number;
+  reserved: ReadonlyUint8Array;
+};
 
 export function getWhitelistEncoder(): Encoder<WhitelistArgs> {
   return getStructEncoder([
+    ['discriminator', getU64Encoder()],
     ['vault', getAddressEncoder()],
     ['bump', getU8Encoder()],
-    ['reserved', fixEncoderSize(getBytesEncoder(), 263)],
+    ['payer', getAddressEncoder()],
+    ['generation', getU64Encoder()],
+    ['userCount', getU64Encoder()],
+    ['totalMintedIn', getU64Encoder()],
+    ['totalVrtEnqueued', getU64Encoder()],
+    ['totalTicketsBurned', getU64Encoder()],
+    ['mode', getU8Encoder()],
+    ['lockupSlots', getU64Encoder()],
+    ['rateLimitEpochs', getU64Encoder()],
+    ['mintLimit', getU64Encoder()],
+    ['enqueueLimit', getU64Encoder()],
+    ['userMintLimit', getU64Encoder()],
+    ['userEnqueueLimit', getU64Encoder()],
+    ['windowStartEpoch', getU64Encoder()],
+    ['windowMinted', getU64Encoder()],
+    ['windowEnqueued', getU64Encoder()],
+    ['minDeposit', getU64Encoder()],
+    ['maxDeposit', getU64Encoder()],
+    ['burnCrank', getAddressEncoder()],
+    ['version', getU8Encoder()],
+    ['reserved', fixEncoderSize(getBytesEncoder(), 69)],
   ]);
 }
 
 export function getWhitelistDecoder(): Decoder<Whitelist> {
   return getStructDecoder([
+    ['discriminator', getU64Decoder()],
     ['vault', getAddressDecoder()],
     ['bump', getU8Decoder()],
-    ['reserved', fixDecoderSize(getBytesDecoder(), 263)],
+    ['payer', getAddressDecoder()],
+    ['generation', getU64Decoder()],
+    ['userCount', getU64Decoder()],
+    ['totalMintedIn', getU64Decoder()],
+    ['totalVrtEnqueued', getU64Decoder()],
+    ['totalTicketsBurned', getU64Decoder()],
+    ['mode', getU8Decoder()],
+    ['lockupSlots', getU64Decoder()],
+    ['rateLimitEpochs', getU64Decoder()],
+    ['mintLimit', getU64Decoder()],
+    ['enqueueLimit', getU64Decoder()],
+    ['userMintLimit', getU64Decoder()],
+    ['userEnqueueLimit', getU64Decoder()],
+    ['windowStartEpoch', getU64Decoder()],
+    ['windowMinted', getU64Decoder()],
+    ['windowEnqueued', getU64Decoder()],
+    ['minDeposit', getU64Decoder()],
+    ['maxDeposit', getU64Decoder()],
+    ['burnCrank', getAddressDecoder()],
+    ['version', getU8Decoder()],
+    ['reserved', fixDecoderSize(getBytesDecoder(), 69)],
   ]);
 }
 
@@ -118,5 +208,5 @@ export async function fetchAllMaybeWhitelist(
 }
 
 export function getWhitelistSize(): number {
-  return 296;
+  return 304;
 }
@@ -17,10 +17,14 @@ import {
   fixEncoderSize,
   getAddressDecoder,
   getAddressEncoder,
+  getBooleanDecoder,
+  getBooleanEncoder,
   getBytesDecoder,
   getBytesEncoder,
   getStructDecoder,
   getStructEncoder,
+  getU64Decoder,
+  getU64Encoder,
   getU8Decoder,
   getU8Encoder,
   type Account,
@@ -37,29 +41,110 @@ import {
 } from '@solana/kit';
 
 export type WhitelistUser = {
+  discriminator: bigint;
   whitelist: Address;
   user: Address;
   bump: number;
+  payer: Address;
+  generation: bigint;
+  totalDeposited: bigint;
+  totalVrtMinted: bigint;
+  totalVrtEnqueued: bigint;
+  totalTokensWithdrawn: bigint;
+  lastActivitySlot: bigint;
+  lastMintSlot: bigint;
+  windowStartEpoch: bigint;
+  windowMinted: bigint;
+  windowEnqueued: bigint;
+  minDeposit: bigint;
+  maxDeposit: bigint;
+  custodian: boolean;
+  permissionlessBurn: boolean;
+  burnDelegate: Address;
+  version: number;
+  reference: ReadonlyUint8Array;
   reserved: ReadonlyUint8Array;
 };
 
-export type WhitelistUserArgs = WhitelistUser;
+export type WhitelistUserArgs = {
+  discriminator: number | bigint;
+  whitelist: Address;
+  user: Address;
+  bump: number;
+  payer: Address;
+  generation: number | bigint;
+  totalDeposited: number | bigint;
+  totalVrtMinted: number | bigint;
+  totalVrtEnqueued: number | bigint;
+  totalTokensWithdrawn: number | bigint;
+  lastActivitySlot: number | bigint;
+  lastMintSlot: number | bigint;
+  windowStartEpoch: number | bigint;
+  windowMinted: number | bigint;
+  windowEnqueued: number | bigint;
+  minDeposit: number | bigint;
+  maxDeposit: number | bigint;
+  custodian: boolean;
+  permissionlessBurn: boolean;
+  burnDelegate: Address;
+  version: number;
+  reference: ReadonlyUint8Array;
+  reserved: ReadonlyUint8Array;
+};
 
 export function getWhitelistUserEncoder(): Encoder<WhitelistUserArgs> {
   return getStructEncoder([
+    ['discriminator', getU64Encoder()],
     ['whitelist', getAddressEncoder()],
     ['user', getAddressEncoder()],
     ['bump', getU8Encoder()],
-    ['reserved', fixEncoderSize(getBytesEncoder(), 263)],
+    ['payer', getAddressEncoder()],
+    ['generation', getU64Encoder()],
+    ['totalDeposited', getU64Encoder()],
+    ['totalVrtMinted', getU64Encoder()],
+    ['totalVrtEnqueued', getU64Encoder()],
+    ['totalTokensWithdrawn', getU64Encoder()],
+    ['lastActivitySlot', getU64Encoder()],
+    ['lastMintSlot', getU64Encoder()],
+    ['windowStartEpoch', getU64Encoder()],
+    ['windowMinted', getU64Encoder()],
+    ['windowEnqueued', getU64Encoder()],
+    ['minDeposit', getU64Encoder()],
+    ['maxDeposit', getU64Encoder()],
+    ['custodian', getBooleanEncoder()],
+    ['permissionlessBurn', getBooleanEncoder()],
+    ['burnDelegate', getAddressEncoder()],
+    ['version', getU8Encoder()],
+    ['reference', fixEncoderSize(getBytesEncoder(), 32)],
+    ['reserved', fixEncoderSize(getBytesEncoder(), 68)],
   ]);
 }
 
 export function getWhitelistUserDecoder(): Decoder<WhitelistUser> {
   return getStructDecoder([
+    ['discriminator', getU64Decoder()],
     ['whitelist', getAddressDecoder()],
     ['user', getAddressDecoder()],
     ['bump', getU8Decoder()],
-    ['reserved', fixDecoderSize(getBytesDecoder(), 263)],
+    ['payer', getAddressDecoder()],
+    ['generation', getU64Decoder()],
+    ['totalDeposited', getU64Decoder()],
+    ['totalVrtMinted', getU64Decoder()],
+    ['totalVrtEnqueued', getU64Decoder()],
+    ['totalTokensWithdrawn', getU64Decoder()],
+    ['lastActivitySlot', getU64Decoder()],
+    ['lastMintSlot', getU64Decoder()],
+    ['windowStartEpoch', getU64Decoder()],
+    ['windowMinted', getU64Decoder()],
+    ['windowEnqueued', getU64Decoder()],
+    ['minDeposit', getU64Decoder()],
+    ['maxDeposit', getU64Decoder()],
+    ['custodian', getBooleanDecoder()],
+    ['permissionlessBurn', getBooleanDecoder()],
+    ['burnDelegate', getAddressDecoder()],
+    ['version', getU8Decoder()],
+    ['reference', fixDecoderSize(getBytesDecoder(), 32)],
+    ['reserved', fixDecoderSize(getBytesDecoder(), 68)],
   ]);
 }
 
@@ -128,5 +213,5 @@ export async function fetchAllMaybeWhitelistUser(
 }
 
 export function getWhitelistUserSize(): number {
-  return 328;
+  return 336;
 }
@@ -26,6 +26,38 @@ export const JITO_VAULT_WHITELIST_ERROR__INVALID_VAULT = 0xbbb; // 3003
 export const JITO_VAULT_WHITELIST_ERROR__INVALID_WHITELIST = 0xbbc; // 3004
 /** InvalidWhitelistUser: InvalidWhitelistUser */
 export const JITO_VAULT_WHITELIST_ERROR__INVALID_WHITELIST_USER = 0xbbd; // 3005
+/** InvalidRentReceiver: InvalidRentReceiver */
+export const JITO_VAULT_WHITELIST_ERROR__INVALID_RENT_RECEIVER = 0xbbe; // 3006
+/** WhitelistNotClosed: WhitelistNotClosed */
+export const JITO_VAULT_WHITELIST_ERROR__WHITELIST_NOT_CLOSED = 0xbbf; // 3007
+/** StaleWhitelistUser: StaleWhitelistUser */
+export const JITO_VAULT_WHITELIST_ERROR__STALE_WHITELIST_USER = 0xbc0; // 3008
+/** InvalidWhitelistMode: InvalidWhitelistMode */
+export const JITO_VAULT_WHITELIST_ERROR__INVALID_WHITELIST_MODE = 0xbc1; // 3009
+/** UserBlacklisted: UserBlacklisted */
+export const JITO_VAULT_WHITELIST_ERROR__USER_BLACKLISTED = 0xbc2; // 3010
+/** WithdrawalLocked: WithdrawalLocked */
+export const JITO_VAULT_WHITELIST_ERROR__WITHDRAWAL_LOCKED = 0xbc3; // 3011
+/** RateLimitExceeded: RateLimitExceeded */
+export const JITO_VAULT_WHITELIST_ERROR__RATE_LIMIT_EXCEEDED = 0xbc4; // 3012
+/** DepositTooSmall: DepositTooSmall */
+export const JITO_VAULT_WHITELIST_ERROR__DEPOSIT_TOO_SMALL = 0xbc5; // 3013
+/** DepositTooLarge: DepositTooLarge */
+export const JITO_VAULT_WHITELIST_ERROR__DEPOSIT_TOO_LARGE = 0xbc6; // 3014
+/** InvalidDepositLimits: InvalidDepositLimits */
+export const JITO_VAULT_WHITELIST_ERROR__INVALID_DEPOSIT_LIMITS = 0xbc7; // 3015
+/** NotCustodian: NotCustodian */
+export const JITO_VAULT_WHITELIST_ERROR__NOT_CUSTODIAN = 0xbc8; // 3016
+/** InvalidBeneficiary: InvalidBeneficiary */
+export const JITO_VAULT_WHITELIST_ERROR__INVALID_BENEFICIARY = 0xbc9; // 3017
+/** InvalidBurnCrank: InvalidBurnCrank */
+export const JITO_VAULT_WHITELIST_ERROR__INVALID_BURN_CRANK = 0xbca; // 3018
+/** InvalidStakerTokenAccount: InvalidStakerTokenAccount */
+export const JITO_VAULT_WHITELIST_ERROR__INVALID_STAKER_TOKEN_ACCOUNT = 0xbcb; // 3019
+/** SlippageExceeded: SlippageExceeded */
+export const JITO_VAULT_WHITELIST_ERROR__SLIPPAGE_EXCEEDED = 0xbcc; // 3020
+/** UnsupportedAccountVersion: UnsupportedAccountVersion */
+export const JITO_VAULT_WHITELIST_ERROR__UNSUPPORTED_ACCOUNT_VERSION = 0xbcd; // 3021
 
 export type JitoVaultWhitelistError =
   | typeof JITO_VAULT_WHITELIST_ERROR__ARITHMETIC_OVERFLOW
@@ -33,7 +65,23 @@ export type JitoVaultWhitelistError =
   | typeof JITO_VAULT_WHITELIST_ERROR__DIVISION_BY_ZERO
   | typeof JITO_VAULT_WHITELIST_ERROR__INVALID_VAULT
   | typeof JITO_VAULT_WHITELIST_ERROR__INVALID_WHITELIST
-  | typeof JITO_VAULT_WHITELIST_ERROR__INVALID_WHITELIST_USER;
+  | typeof JITO_VAULT_WHITELIST_ERROR__INVALID_WHITELIST_USER
+  | typeof JITO_VAULT_WHITELIST_ERROR__INVALID_RENT_RECEIVER
+  | typeof JITO_VAULT_WHITELIST_ERROR__WHITELIST_NOT_CLOSED
+  | typeof JITO_VAULT_WHITELIST_ERROR__STALE_WHITELIST_USER
+  | typeof JITO_VAULT_WHITELIST_ERROR__INVALID_WHITELIST_MODE
+  | typeof JITO_VAULT_WHITELIST_ERROR__USER_BLACKLISTED
+  | typeof JITO_VAULT_WHITELIST_ERROR__WITHDRAWAL_LOCKED
+  | typeof JITO_VAULT_WHITELIST_ERROR__RATE_LIMIT_EXCEEDED
+  | typeof JITO_VAULT_WHITELIST_ERROR__DEPOSIT_TOO_SMALL
+  | typeof JITO_VAULT_WHITELIST_ERROR__DEPOSIT_TOO_LARGE
+  | typeof JITO_VAULT_WHITELIST_ERROR__INVALID_DEPOSIT_LIMITS
+  | typeof JITO_VAULT_WHITELIST_ERROR__NOT_CUSTODIAN
+  | typeof JITO_VAULT_WHITELIST_ERROR__INVALID_BENEFICIARY
+  | typeof JITO_VAULT_WHITELIST_ERROR__INVALID_BURN_CRANK
+  | typeof JITO_VAULT_WHITELIST_ERROR__INVALID_STAKER_TOKEN_ACCOUNT
+  | typeof JITO_VAULT_WHITELIST_ERROR__SLIPPAGE_EXCEEDED
+  | typeof JITO_VAULT_WHITELIST_ERROR__UNSUPPORTED_ACCOUNT_VERSION;
 
 let jitoVaultWhitelistErrorMessages:
   | Record<JitoVaultWhitelistError, string>
@@ -46,6 +94,22 @@ if (process.env.NODE_ENV !== 'production') {
     [JITO_VAULT_WHITELIST_ERROR__INVALID_VAULT]: `InvalidVault`,
     [JITO_VAULT_WHITELIST_ERROR__INVALID_WHITELIST]: `InvalidWhitelist`,
     [JITO_VAULT_WHITELIST_ERROR__INVALID_WHITELIST_USER]: `InvalidWhitelistUser`,
+    [JITO_VAULT_WHITELIST_ERROR__INVALID_RENT_RECEIVER]: `InvalidRentReceiver`,
+    [JITO_VAULT_WHITELIST_ERROR__WHITELIST_NOT_CLOSED]: `WhitelistNotClosed`,
+    [JITO_VAULT_WHITELIST_ERROR__STALE_WHITELIST_USER]: `StaleWhitelistUser`,
+    [JITO_VAULT_WHITELIST_ERROR__INVALID_WHITELIST_MODE]: `InvalidWhitelistMode`,
+    [JITO_VAULT_WHITELIST_ERROR__USER_BLACKLISTED]: `UserBlacklisted`,
+    [JITO_VAULT_WHITELIST_ERROR__WITHDRAWAL_LOCKED]: `WithdrawalLocked`,
+    [JITO_VAULT_WHITELIST_ERROR__RATE_LIMIT_EXCEEDED]: `RateLimitExceeded`,
+    [JITO_VAULT_WHITELIST_ERROR__DEPOSIT_TOO_SMALL]: `DepositTooSmall`,
+    [JITO_VAULT_WHITELIST_ERROR__DEPOSIT_TOO_LARGE]: `DepositTooLarge`,
+    [JITO_VAULT_WHITELIST_ERROR__INVALID_DEPOSIT_LIMITS]: `InvalidDepositLimits`,
+    [JITO_VAULT_WHITELIST_ERROR__NOT_CUSTODIAN]: `NotCustodian`,
+    [JITO_VAULT_WHITELIST_ERROR__INVALID_BENEFICIARY]: `InvalidBeneficiary`,
+    [JITO_VAULT_WHITELIST_ERROR__INVALID_BURN_CRANK]: `InvalidBurnCrank`,
+    [JITO_VAULT_WHITELIST_ERROR__INVALID_STAKER_TOKEN_ACCOUNT]: `InvalidStakerTokenAccount`,
+    [JITO_VAULT_WHITELIST_ERROR__SLIPPAGE_EXCEEDED]: `SlippageExceeded`,
+    [JITO_VAULT_WHITELIST_ERROR__UNSUPPORTED_ACCOUNT_VERSION]: `UnsupportedAccountVersion`,
   };
 }
 
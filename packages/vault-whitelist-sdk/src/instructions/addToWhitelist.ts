@@ -8,6 +8,10 @@
 
 import {
   combineCodec,
+  fixDecoderSize,
+  fixEncoderSize,
+  getBytesDecoder,
+  getBytesEncoder,
   getStructDecoder,
   getStructEncoder,
   getU8Decoder,
@@ -24,8 +28,10 @@ import {
   type IInstructionWithData,
   type ReadonlyAccount,
   type ReadonlySignerAccount,
+  type ReadonlyUint8Array,
   type TransactionSigner,
   type WritableAccount,
+  type WritableSignerAccount,
 } from '@solana/kit';
 import { JITO_VAULT_WHITELIST_PROGRAM_ADDRESS } from '../programs';
 import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
@@ -43,6 +49,7 @@ export type AddToWhitelistInstruction<
   TAccountWhitelist extends string | IAccountMeta<string> = string,
   TAccountWhitelistUser extends string | IAccountMeta<string> = string,
   TAccountVaultAdmin extends string | IAccountMeta<string> = string,
+  TAccountPayer extends string | IAccountMeta<string> = string,
   TAccountUser extends string | IAccountMeta<string> = string,
   TAccountSystemProgram extends
     | string
@@ -59,7 +66,7 @@ export type AddToWhitelistInstruction<
         ? ReadonlyAccount<TAccountVault>
         : TAccountVault,
       TAccountWhitelist extends string
-        ? ReadonlyAccount<TAccountWhitelist>
+        ? WritableAccount<TAccountWhitelist>
         : TAccountWhitelist,
       TAccountWhitelistUser extends string
         ? WritableAccount<TAccountWhitelistUser>
@@ -68,6 +75,10 @@ export type AddToWhitelistInstruction<
         ? ReadonlySignerAccount<TAccountVaultAdmin> &
             IAccountSignerMeta<TAccountVaultAdmin>
         : TAccountVaultAdmin,
+      TAccountPayer extends string
+        ? WritableSignerAccount<TAccountPayer> &
+            IAccountSignerMeta<TAccountPayer>
+        : TAccountPayer,
       TAccountUser extends string
         ? ReadonlyAccount<TAccountUser>
         : TAccountUser,
@@ -78,19 +89,30 @@ export type AddToWhitelistInstruction<
     ]
   >;
 
-export type AddToWhitelistInstructionData = { discriminator: number };
+export type AddToWhitelistInstructionData = {
+  discriminator: number;
+  reference: ReadonlyUint8Array;
+};
 
-export type AddToWhitelistInstructionDataArgs = {};
+export type AddToWhitelistInstructionDataArgs = {
+  reference: ReadonlyUint8Array;
+};
 
 export function getAddToWhitelistInstructionDataEncoder(): Encoder<AddToWhitelistInstructionDataArgs> {
   return transformEncoder(
-    getStructEncoder([['discriminator', getU8Encoder()]]),
+    getStructEncoder([
+      ['discriminator', getU8Encoder()],
+      ['reference', fixEncoderSize(getBytesEncoder(), 32)],
+    ]),
     (value) => ({ ...value, discriminator: ADD_TO_WHITELIST_DISCRIMINATOR })
   );
 }
 
 export function getAddToWhitelistInstructionDataDecoder(): Decoder<AddToWhitelistInstructionData> {
-  return getStructDecoder([['discriminator', getU8Decoder()]]);
+  return getStructDecoder([
+    ['discriminator', getU8Decoder()],
+    ['reference', fixDecoderSize(getBytesDecoder(), 32)],
+  ]);
 }
 
 export function getAddToWhitelistInstructionDataCodec(): Codec<
@@ -109,6 +131,7 @@ export type AddToWhitelistInput<
   TAccountWhitelist extends string = string,
   TAccountWhitelistUser extends string = string,
   TAccountVaultAdmin extends string = string,
+  TAccountPayer extends string = string,
   TAccountUser extends string = string,
   TAccountSystemProgram extends string = string,
 > = {
@@ -117,8 +140,10 @@ export type AddToWhitelistInput<
   whitelist: Address<TAccountWhitelist>;
   whitelistUser: Address<TAccountWhitelistUser>;
   vaultAdmin: TransactionSigner<TAccountVaultAdmin>;
+  payer: TransactionSigner<TAccountPayer>;
   user: Address<TAccountUser>;
   systemProgram?: Address<TAccountSystemProgram>;
+  reference: AddToWhitelistInstructionDataArgs['reference'];
 };
 
 export function getAddToWhitelistInstruction<
@@ -127,6 +152,7 @@ export function getAddToWhitelistInstruction<
   TAccountWhitelist extends string,
   TAccountWhitelistUser extends string,
   TAccountVaultAdmin extends string,
+  TAccountPayer extends string,
   TAccountUser extends string,
   TAccountSystemProgram extends string,
   TProgramAddress extends Address = typeof JITO_VAULT_WHITELIST_PROGRAM_ADDRESS,
@@ -137,6 +163,7 @@ export function getAddToWhitelistInstruction<
     TAccountWhitelist,
     TAccountWhitelistUser,
     TAccountVaultAdmin,
+    TAccountPayer,
     TAccountUser,
     TAccountSystemProgram
   >,
@@ -148,6 +175,7 @@ export function getAddToWhitelistInstruction<
   TAccountWhitelist,
   TAccountWhitelistUser,
   TAccountVaultAdmin,
+  TAccountPayer,
   TAccountUser,
   TAccountSystemProgram
 > {
@@ -159,9 +187,10 @@ export function getAddToWhitelistInstruction<
   const originalAccounts = {
     config: { value: input.config ?? null, isWritable: false },
     vault: { value: input.vault ?? null, isWritable: false },
-    whitelist: { value: input.whitelist ?? null, isWritable: false },
+    whitelist: { value: input.whitelist ?? null, isWritable: true },
     whitelistUser: { value: input.whitelistUser ?? null, isWritable: true },
     vaultAdmin: { value: input.vaultAdmin ?? null, isWritable: false },
+    payer: { value: input.payer ?? null, isWritable: true },
     user: { value: input.user ?? null, isWritable: false },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
   };
@@ -170,6 +199,9 @@ export function getAddToWhitelistInstruction<
     ResolvedAccount
   >;
 
+  // Original args.
+  const args = { ...input };
+
   // Resolve default values.
   if (!accounts.systemProgram.value) {
     accounts.systemProgram.value =
@@ -184,11 +216,14 @@ export function getAddToWhitelistInstruction<
       getAccountMeta(accounts.whitelist),
       getAccountMeta(accounts.whitelistUser),
       getAccountMeta(accounts.vaultAdmin),
+      getAccountMeta(accounts.payer),
       getAccountMeta(accounts.user),
       getAccountMeta(accounts.systemProgram),
     ],
     programAddress,
-    data: getAddToWhitelistInstructionDataEncoder().encode({}),
+    data: getAddToWhitelistInstructionDataEncoder().encode(
+      args as AddToWhitelistInstructionDataArgs
+    ),
   } as AddToWhitelistInstruction<
     TProgramAddress,
     TAccountConfig,
@@ -196,6 +231,7 @@ export function getAddToWhitelistInstruction<
     TAccountWhitelist,
     TAccountWhitelistUser,
     TAccountVaultAdmin,
+    TAccountPayer,
     TAccountUser,
     TAccountSystemProgram
   >;
@@ -214,8 +250,9 @@ export type ParsedAddToWhitelistInstruction<
     whitelist: TAccountMetas[2];
     whitelistUser: TAccountMetas[3];
     vaultAdmin: TAccountMetas[4];
-    user: TAccountMetas[5];
-    systemProgram: TAccountMetas[6];
+    payer: TAccountMetas[5];
+    user: TAccountMetas[6];
+    systemProgram: TAccountMetas[7];
   };
   data: AddToWhitelistInstructionData;
 };
@@ -228,7 +265,7 @@ export function parseAddToWhitelistInstruction<
     IInstructionWithAccounts<TAccountMetas> &
     IInstructionWithData<Uint8Array>
 ): ParsedAddToWhitelistInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 7) {
+  if (instruction.accounts.length < 8) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -246,6 +283,7 @@ export function parseAddToWhitelistInstruction<
       whitelist: getNextAccount(),
       whitelistUser: getNextAccount(),
       vaultAdmin: getNextAccount(),
+      payer: getNextAccount(),
       user: getNextAccount(),
       systemProgram: getNextAccount(),
     },
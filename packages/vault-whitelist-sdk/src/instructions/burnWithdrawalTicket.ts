@@ -10,6 +10,8 @@ import {
   combineCodec,
   getStructDecoder,
   getStructEncoder,
+  getU64Decoder,
+  getU64Encoder,
   getU8Decoder,
   getU8Encoder,
   transformEncoder,
@@ -107,7 +109,7 @@ export type BurnWithdrawalTicketInstruction<
         ? WritableAccount<TAccountWhitelist>
         : TAccountWhitelist,
       TAccountWhitelistUser extends string
-        ? ReadonlyAccount<TAccountWhitelistUser>
+        ? WritableAccount<TAccountWhitelistUser>
         : TAccountWhitelistUser,
       TAccountJitoVaultProgram extends string
         ? ReadonlyAccount<TAccountJitoVaultProgram>
@@ -116,13 +118,21 @@ export type BurnWithdrawalTicketInstruction<
     ]
   >;
 
-export type BurnWithdrawalTicketInstructionData = { discriminator: number };
+export type BurnWithdrawalTicketInstructionData = {
+  discriminator: number;
+  minAmountOut: bigint;
+};
 
-export type BurnWithdrawalTicketInstructionDataArgs = {};
+export type BurnWithdrawalTicketInstructionDataArgs = {
+  minAmountOut: number | bigint;
+};
 
 export function getBurnWithdrawalTicketInstructionDataEncoder(): Encoder<BurnWithdrawalTicketInstructionDataArgs> {
   return transformEncoder(
-    getStructEncoder([['discriminator', getU8Encoder()]]),
+    getStructEncoder([
+      ['discriminator', getU8Encoder()],
+      ['minAmountOut', getU64Encoder()],
+    ]),
     (value) => ({
       ...value,
       discriminator: BURN_WITHDRAWAL_TICKET_DISCRIMINATOR,
@@ -131,7 +141,10 @@ export function getBurnWithdrawalTicketInstructionDataEncoder(): Encoder<BurnWit
 }
 
 export function getBurnWithdrawalTicketInstructionDataDecoder(): Decoder<BurnWithdrawalTicketInstructionData> {
-  return getStructDecoder([['discriminator', getU8Decoder()]]);
+  return getStructDecoder([
+    ['discriminator', getU8Decoder()],
+    ['minAmountOut', getU64Decoder()],
+  ]);
 }
 
 export function getBurnWithdrawalTicketInstructionDataCodec(): Codec<
@@ -178,6 +191,7 @@ export type BurnWithdrawalTicketInput<
   whitelist: Address<TAccountWhitelist>;
   whitelistUser: Address<TAccountWhitelistUser>;
   jitoVaultProgram: Address<TAccountJitoVaultProgram>;
+  minAmountOut: BurnWithdrawalTicketInstructionDataArgs['minAmountOut'];
 };
 
 export function getBurnWithdrawalTicketInstruction<
@@ -275,7 +289,7 @@ export function getBurnWithdrawalTicketInstruction<
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
     config: { value: input.config ?? null, isWritable: false },
     whitelist: { value: input.whitelist ?? null, isWritable: true },
-    whitelistUser: { value: input.whitelistUser ?? null, isWritable: false },
+    whitelistUser: { value: input.whitelistUser ?? null, isWritable: true },
     jitoVaultProgram: {
       value: input.jitoVaultProgram ?? null,
       isWritable: false,
@@ -286,6 +300,9 @@ export function getBurnWithdrawalTicketInstruction<
     ResolvedAccount
   >;
 
+  // Original args.
+  const args = { ...input };
+
   // Resolve default values.
   if (!accounts.tokenProgram.value) {
     accounts.tokenProgram.value =
@@ -317,7 +334,9 @@ export function getBurnWithdrawalTicketInstruction<
       getAccountMeta(accounts.jitoVaultProgram),
     ],
     programAddress,
-    data: getBurnWithdrawalTicketInstructionDataEncoder().encode({}),
+    data: getBurnWithdrawalTicketInstructionDataEncoder().encode(
+      args as BurnWithdrawalTicketInstructionDataArgs
+    ),
   } as BurnWithdrawalTicketInstruction<
     TProgramAddress,
     TAccountVaultConfig,
@@ -43,6 +43,7 @@ export type CloseWhitelistInstruction<
   TAccountWhitelist extends string | IAccountMeta<string> = string,
   TAccountVault extends string | IAccountMeta<string> = string,
   TAccountVaultAdmin extends string | IAccountMeta<string> = string,
+  TAccountRentReceiver extends string | IAccountMeta<string> = string,
   TAccountJitoVaultProgram extends string | IAccountMeta<string> = string,
   TAccountSystemProgram extends
     | string
@@ -68,6 +69,9 @@ export type CloseWhitelistInstruction<
         ? WritableSignerAccount<TAccountVaultAdmin> &
             IAccountSignerMeta<TAccountVaultAdmin>
         : TAccountVaultAdmin,
+      TAccountRentReceiver extends string
+        ? WritableAccount<TAccountRentReceiver>
+        : TAccountRentReceiver,
       TAccountJitoVaultProgram extends string
         ? ReadonlyAccount<TAccountJitoVaultProgram>
         : TAccountJitoVaultProgram,
@@ -109,6 +113,7 @@ export type CloseWhitelistInput<
   TAccountWhitelist extends string = string,
   TAccountVault extends string = string,
   TAccountVaultAdmin extends string = string,
+  TAccountRentReceiver extends string = string,
   TAccountJitoVaultProgram extends string = string,
   TAccountSystemProgram extends string = string,
 > = {
@@ -117,6 +122,7 @@ export type CloseWhitelistInput<
   whitelist: Address<TAccountWhitelist>;
   vault: Address<TAccountVault>;
   vaultAdmin: TransactionSigner<TAccountVaultAdmin>;
+  rentReceiver: Address<TAccountRentReceiver>;
   jitoVaultProgram: Address<TAccountJitoVaultProgram>;
   systemProgram?: Address<TAccountSystemProgram>;
 };
@@ -127,6 +133,7 @@ export function getCloseWhitelistInstruction<
   TAccountWhitelist extends string,
   TAccountVault extends string,
   TAccountVaultAdmin extends string,
+  TAccountRentReceiver extends string,
   TAccountJitoVaultProgram extends string,
   TAccountSystemProgram extends string,
   TProgramAddress extends Address = typeof JITO_VAULT_WHITELIST_PROGRAM_ADDRESS,
@@ -137,6 +144,7 @@ export function getCloseWhitelistInstruction<
     TAccountWhitelist,
     TAccountVault,
     TAccountVaultAdmin,
+    TAccountRentReceiver,
     TAccountJitoVaultProgram,
     TAccountSystemProgram
   >,
@@ -148,6 +156,7 @@ export function getCloseWhitelistInstruction<
   TAccountWhitelist,
   TAccountVault,
   TAccountVaultAdmin,
+  TAccountRentReceiver,
   TAccountJitoVaultProgram,
   TAccountSystemProgram
 > {
@@ -162,6 +171,7 @@ export function getCloseWhitelistInstruction<
     whitelist: { value: input.whitelist ?? null, isWritable: true },
     vault: { value: input.vault ?? null, isWritable: true },
     vaultAdmin: { value: input.vaultAdmin ?? null, isWritable: true },
+    rentReceiver: { value: input.rentReceiver ?? null, isWritable: true },
     jitoVaultProgram: {
       value: input.jitoVaultProgram ?? null,
       isWritable: false,
@@ -187,6 +197,7 @@ export function getCloseWhitelistInstruction<
       getAccountMeta(accounts.whitelist),
       getAccountMeta(accounts.vault),
       getAccountMeta(accounts.vaultAdmin),
+      getAccountMeta(accounts.rentReceiver),
       getAccountMeta(accounts.jitoVaultProgram),
       getAccountMeta(accounts.systemProgram),
     ],
@@ -199,6 +210,7 @@ export function getCloseWhitelistInstruction<
     TAccountWhitelist,
     TAccountVault,
     TAccountVaultAdmin,
+    TAccountRentReceiver,
     TAccountJitoVaultProgram,
     TAccountSystemProgram
   >;
@@ -217,8 +229,9 @@ export type ParsedCloseWhitelistInstruction<
     whitelist: TAccountMetas[2];
     vault: TAccountMetas[3];
     vaultAdmin: TAccountMetas[4];
-    jitoVaultProgram: TAccountMetas[5];
-    systemProgram: TAccountMetas[6];
+    rentReceiver: TAccountMetas[5];
+    jitoVaultProgram: TAccountMetas[6];
+    systemProgram: TAccountMetas[7];
   };
   data: CloseWhitelistInstructionData;
 };
@@ -231,7 +244,7 @@ export function parseCloseWhitelistInstruction<
     IInstructionWithAccounts<TAccountMetas> &
     IInstructionWithData<Uint8Array>
 ): ParsedCloseWhitelistInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 7) {
+  if (instruction.accounts.length < 8) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -249,6 +262,7 @@ export function parseCloseWhitelistInstruction<
       whitelist: getNextAccount(),
       vault: getNextAccount(),
       vaultAdmin: getNextAccount(),
+      rentReceiver: getNextAccount(),
       jitoVaultProgram: getNextAccount(),
       systemProgram: getNextAccount(),
     },
@@ -102,7 +102,7 @@ export type EnqueueWithdrawalInstruction<
         ? WritableAccount<TAccountWhitelist>
         : TAccountWhitelist,
       TAccountWhitelistUser extends string
-        ? ReadonlyAccount<TAccountWhitelistUser>
+        ? WritableAccount<TAccountWhitelistUser>
         : TAccountWhitelistUser,
       TAccountJitoVaultProgram extends string
         ? ReadonlyAccount<TAccountJitoVaultProgram>
@@ -250,7 +250,7 @@ export function getEnqueueWithdrawalInstruction<
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
     config: { value: input.config ?? null, isWritable: false },
     whitelist: { value: input.whitelist ?? null, isWritable: true },
-    whitelistUser: { value: input.whitelistUser ?? null, isWritable: false },
+    whitelistUser: { value: input.whitelistUser ?? null, isWritable: true },
     jitoVaultProgram: {
       value: input.jitoVaultProgram ?? null,
       isWritable: false,
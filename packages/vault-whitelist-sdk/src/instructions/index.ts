@@ -6,12 +6,28 @@
  * @see https://github.com/codama-idl/codama
  */
 
+export * from './addToBlacklist';
 export * from './addToWhitelist';
 export * from './burnWithdrawalTicket';
+export * from './closeOrphanedWhitelistUser';
 export * from './closeWhitelist';
+export * from './crankBurnWithdrawalTicket';
 export * from './enqueueWithdrawal';
 export * from './initializeConfig';
 export * from './initializeWhitelist';
+export * from './migrateAccount';
 export * from './mint';
+export * from './mintFor';
+export * from './removeFromBlacklist';
 export * from './removeFromWhitelist';
+export * from './setBurnCrank';
+export * from './setBurnDelegate';
+export * from './setCustodian';
+export * from './setDepositLimits';
+export * from './setLockup';
 export * from './setMintBurnAdmin';
+export * from './setRateLimit';
+export * from './setUserDepositLimits';
+export * from './setUserReference';
+export * from './setWhitelistMetadata';
+export * from './setWhitelistMode';
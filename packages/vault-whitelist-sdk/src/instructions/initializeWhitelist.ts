@@ -8,6 +8,10 @@
 
 import {
   combineCodec,
+  fixDecoderSize,
+  fixEncoderSize,
+  getBytesDecoder,
+  getBytesEncoder,
   getStructDecoder,
   getStructEncoder,
   getU8Decoder,
@@ -23,6 +27,8 @@ import {
   type IInstructionWithAccounts,
   type IInstructionWithData,
   type ReadonlyAccount,
+  type ReadonlySignerAccount,
+  type ReadonlyUint8Array,
   type TransactionSigner,
   type WritableAccount,
   type WritableSignerAccount,
@@ -42,16 +48,19 @@ export type InitializeWhitelistInstruction<
   TAccountWhitelist extends string | IAccountMeta<string> = string,
   TAccountVault extends string | IAccountMeta<string> = string,
   TAccountVaultAdmin extends string | IAccountMeta<string> = string,
+  TAccountPayer extends string | IAccountMeta<string> = string,
   TAccountSystemProgram extends
     | string
     | IAccountMeta<string> = '11111111111111111111111111111111',
+  TAccountKycProvider extends string | IAccountMeta<string> = string,
+  TAccountWhitelistMetadata extends string | IAccountMeta<string> = string,
   TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
 > = IInstruction<TProgram> &
   IInstructionWithData<Uint8Array> &
   IInstructionWithAccounts<
     [
       TAccountConfig extends string
-        ? ReadonlyAccount<TAccountConfig>
+        ? WritableAccount<TAccountConfig>
         : TAccountConfig,
       TAccountWhitelist extends string
         ? WritableAccount<TAccountWhitelist>
@@ -60,29 +69,54 @@ export type InitializeWhitelistInstruction<
         ? ReadonlyAccount<TAccountVault>
         : TAccountVault,
       TAccountVaultAdmin extends string
-        ? WritableSignerAccount<TAccountVaultAdmin> &
+        ? ReadonlySignerAccount<TAccountVaultAdmin> &
             IAccountSignerMeta<TAccountVaultAdmin>
         : TAccountVaultAdmin,
+      TAccountPayer extends string
+        ? WritableSignerAccount<TAccountPayer> &
+            IAccountSignerMeta<TAccountPayer>
+        : TAccountPayer,
       TAccountSystemProgram extends string
         ? ReadonlyAccount<TAccountSystemProgram>
         : TAccountSystemProgram,
+      TAccountKycProvider extends string
+        ? ReadonlyAccount<TAccountKycProvider>
+        : TAccountKycProvider,
+      TAccountWhitelistMetadata extends string
+        ? WritableAccount<TAccountWhitelistMetadata>
+        : TAccountWhitelistMetadata,
       ...TRemainingAccounts,
     ]
   >;
 
-export type InitializeWhitelistInstructionData = { discriminator: number };
+export type InitializeWhitelistInstructionData = {
+  discriminator: number;
+  name: ReadonlyUint8Array;
+  uri: ReadonlyUint8Array;
+};
 
-export type InitializeWhitelistInstructionDataArgs = {};
+export type InitializeWhitelistInstructionDataArgs = {
+  name: ReadonlyUint8Array;
+  uri: ReadonlyUint8Array;
+};
 
 export function getInitializeWhitelistInstructionDataEncoder(): Encoder<InitializeWhitelistInstructionDataArgs> {
   return transformEncoder(
-    getStructEncoder([['discriminator', getU8Encoder()]]),
+    getStructEncoder([
+      ['discriminator', getU8Encoder()],
+      ['name', fixEncoderSize(getBytesEncoder(), 32)],
+      ['uri', fixEncoderSize(getBytesEncoder(), 128)],
+    ]),
     (value) => ({ ...value, discriminator: INITIALIZE_WHITELIST_DISCRIMINATOR })
   );
 }
 
 export function getInitializeWhitelistInstructionDataDecoder(): Decoder<InitializeWhitelistInstructionData> {
-  return getStructDecoder([['discriminator', getU8Decoder()]]);
+  return getStructDecoder([
+    ['discriminator', getU8Decoder()],
+    ['name', fixDecoderSize(getBytesDecoder(), 32)],
+    ['uri', fixDecoderSize(getBytesDecoder(), 128)],
+  ]);
 }
 
 export function getInitializeWhitelistInstructionDataCodec(): Codec<
@@ -100,13 +134,21 @@ export type InitializeWhitelistInput<
   TAccountWhitelist extends string = string,
   TAccountVault extends string = string,
   TAccountVaultAdmin extends string = string,
+  TAccountPayer extends string = string,
   TAccountSystemProgram extends string = string,
+  TAccountKycProvider extends string = string,
+  TAccountWhitelistMetadata extends string = string,
 > = {
   config: Address<TAccountConfig>;
   whitelist: Address<TAccountWhitelist>;
   vault: Address<TAccountVault>;
   vaultAdmin: TransactionSigner<TAccountVaultAdmin>;
+  payer: TransactionSigner<TAccountPayer>;
   systemProgram?: Address<TAccountSystemProgram>;
+  kycProvider: Address<TAccountKycProvider>;
+  whitelistMetadata: Address<TAccountWhitelistMetadata>;
+  name: InitializeWhitelistInstructionDataArgs['name'];
+  uri: InitializeWhitelistInstructionDataArgs['uri'];
 };
 
 export function getInitializeWhitelistInstruction<
@@ -114,7 +156,10 @@ export function getInitializeWhitelistInstruction<
   TAccountWhitelist extends string,
   TAccountVault extends string,
   TAccountVaultAdmin extends string,
+  TAccountPayer extends string,
   TAccountSystemProgram extends string,
+  TAccountKycProvider extends string,
+  TAccountWhitelistMetadata extends string,
   TProgramAddress extends Address = typeof JITO_VAULT_WHITELIST_PROGRAM_ADDRESS,
 >(
   input: InitializeWhitelistInput<
@@ -122,7 +167,10 @@ export function getInitializeWhitelistInstruction<
     TAccountWhitelist,
     TAccountVault,
     TAccountVaultAdmin,
-    TAccountSystemProgram
+    TAccountPayer,
+    TAccountSystemProgram,
+    TAccountKycProvider,
+    TAccountWhitelistMetadata
   >,
   config?: { programAddress?: TProgramAddress }
 ): InitializeWhitelistInstruction<
@@ -131,7 +179,10 @@ export function getInitializeWhitelistInstruction<
   TAccountWhitelist,
   TAccountVault,
   TAccountVaultAdmin,
-  TAccountSystemProgram
+  TAccountPayer,
+  TAccountSystemProgram,
+  TAccountKycProvider,
+  TAccountWhitelistMetadata
 > {
   // Program address.
   const programAddress =
@@ -139,17 +190,26 @@ export function getInitializeWhitelistInstruction<
 
   // Original accounts.
   const originalAccounts = {
-    config: { value: input.config ?? null, isWritable: false },
+    config: { value: input.config ?? null, isWritable: true },
     whitelist: { value: input.whitelist ?? null, isWritable: true },
     vault: { value: input.vault ?? null, isWritable: false },
-    vaultAdmin: { value: input.vaultAdmin ?? null, isWritable: true },
+    vaultAdmin: { value: input.vaultAdmin ?? null, isWritable: false },
+    payer: { value: input.payer ?? null, isWritable: true },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+    kycProvider: { value: input.kycProvider ?? null, isWritable: false },
+    whitelistMetadata: {
+      value: input.whitelistMetadata ?? null,
+      isWritable: true,
+    },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
     ResolvedAccount
   >;
 
+  // Original args.
+  const args = { ...input };
+
   // Resolve default values.
   if (!accounts.systemProgram.value) {
     accounts.systemProgram.value =
@@ -163,17 +223,25 @@ export function getInitializeWhitelistInstruction<
       getAccountMeta(accounts.whitelist),
       getAccountMeta(accounts.vault),
       getAccountMeta(accounts.vaultAdmin),
+      getAccountMeta(accounts.payer),
       getAccountMeta(accounts.systemProgram),
+      getAccountMeta(accounts.kycProvider),
+      getAccountMeta(accounts.whitelistMetadata),
     ],
     programAddress,
-    data: getInitializeWhitelistInstructionDataEncoder().encode({}),
+    data: getInitializeWhitelistInstructionDataEncoder().encode(
+      args as InitializeWhitelistInstructionDataArgs
+    ),
   } as InitializeWhitelistInstruction<
     TProgramAddress,
     TAccountConfig,
     TAccountWhitelist,
     TAccountVault,
     TAccountVaultAdmin,
-    TAccountSystemProgram
+    TAccountPayer,
+    TAccountSystemProgram,
+    TAccountKycProvider,
+    TAccountWhitelistMetadata
   >;
 
   return instruction;
@@ -189,7 +257,10 @@ export type ParsedInitializeWhitelistInstruction<
     whitelist: TAccountMetas[1];
     vault: TAccountMetas[2];
     vaultAdmin: TAccountMetas[3];
-    systemProgram: TAccountMetas[4];
+    payer: TAccountMetas[4];
+    systemProgram: TAccountMetas[5];
+    kycProvider: TAccountMetas[6];
+    whitelistMetadata: TAccountMetas[7];
   };
   data: InitializeWhitelistInstructionData;
 };
@@ -202,7 +273,7 @@ export function parseInitializeWhitelistInstruction<
     IInstructionWithAccounts<TAccountMetas> &
     IInstructionWithData<Uint8Array>
 ): ParsedInitializeWhitelistInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 5) {
+  if (instruction.accounts.length < 8) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -219,7 +290,10 @@ export function parseInitializeWhitelistInstruction<
       whitelist: getNextAccount(),
       vault: getNextAccount(),
       vaultAdmin: getNextAccount(),
+      payer: getNextAccount(),
       systemProgram: getNextAccount(),
+      kycProvider: getNextAccount(),
+      whitelistMetadata: getNextAccount(),
     },
     data: getInitializeWhitelistInstructionDataDecoder().decode(
       instruction.data
@@ -94,7 +94,7 @@ export type MintInstruction<
         ? WritableAccount<TAccountWhitelist>
         : TAccountWhitelist,
       TAccountWhitelistUser extends string
-        ? ReadonlyAccount<TAccountWhitelistUser>
+        ? WritableAccount<TAccountWhitelistUser>
         : TAccountWhitelistUser,
       TAccountJitoVaultProgram extends string
         ? ReadonlyAccount<TAccountJitoVaultProgram>
@@ -254,7 +254,7 @@ export function getMintInstruction<
       isWritable: true,
     },
     whitelist: { value: input.whitelist ?? null, isWritable: true },
-    whitelistUser: { value: input.whitelistUser ?? null, isWritable: false },
+    whitelistUser: { value: input.whitelistUser ?? null, isWritable: true },
     jitoVaultProgram: {
       value: input.jitoVaultProgram ?? null,
       isWritable: false,
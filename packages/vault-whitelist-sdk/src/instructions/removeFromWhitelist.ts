@@ -43,6 +43,7 @@ export type RemoveFromWhitelistInstruction<
   TAccountWhitelist extends string | IAccountMeta<string> = string,
   TAccountWhitelistUser extends string | IAccountMeta<string> = string,
   TAccountVaultAdmin extends string | IAccountMeta<string> = string,
+  TAccountRentReceiver extends string | IAccountMeta<string> = string,
   TAccountUser extends string | IAccountMeta<string> = string,
   TAccountSystemProgram extends
     | string
@@ -59,7 +60,7 @@ export type RemoveFromWhitelistInstruction<
         ? ReadonlyAccount<TAccountVault>
         : TAccountVault,
       TAccountWhitelist extends string
-        ? ReadonlyAccount<TAccountWhitelist>
+        ? WritableAccount<TAccountWhitelist>
         : TAccountWhitelist,
       TAccountWhitelistUser extends string
         ? WritableAccount<TAccountWhitelistUser>
@@ -68,6 +69,9 @@ export type RemoveFromWhitelistInstruction<
         ? ReadonlySignerAccount<TAccountVaultAdmin> &
             IAccountSignerMeta<TAccountVaultAdmin>
         : TAccountVaultAdmin,
+      TAccountRentReceiver extends string
+        ? WritableAccount<TAccountRentReceiver>
+        : TAccountRentReceiver,
       TAccountUser extends string
         ? ReadonlyAccount<TAccountUser>
         : TAccountUser,
@@ -112,6 +116,7 @@ export type RemoveFromWhitelistInput<
   TAccountWhitelist extends string = string,
   TAccountWhitelistUser extends string = string,
   TAccountVaultAdmin extends string = string,
+  TAccountRentReceiver extends string = string,
   TAccountUser extends string = string,
   TAccountSystemProgram extends string = string,
 > = {
@@ -120,6 +125,7 @@ export type RemoveFromWhitelistInput<
   whitelist: Address<TAccountWhitelist>;
   whitelistUser: Address<TAccountWhitelistUser>;
   vaultAdmin: TransactionSigner<TAccountVaultAdmin>;
+  rentReceiver: Address<TAccountRentReceiver>;
   user: Address<TAccountUser>;
   systemProgram?: Address<TAccountSystemProgram>;
 };
@@ -130,6 +136,7 @@ export function getRemoveFromWhitelistInstruction<
   TAccountWhitelist extends string,
   TAccountWhitelistUser extends string,
   TAccountVaultAdmin extends string,
+  TAccountRentReceiver extends string,
   TAccountUser extends string,
   TAccountSystemProgram extends string,
   TProgramAddress extends Address = typeof JITO_VAULT_WHITELIST_PROGRAM_ADDRESS,
@@ -140,6 +147,7 @@ export function getRemoveFromWhitelistInstruction<
     TAccountWhitelist,
     TAccountWhitelistUser,
     TAccountVaultAdmin,
+    TAccountRentReceiver,
     TAccountUser,
     TAccountSystemProgram
   >,
@@ -151,6 +159,7 @@ export function getRemoveFromWhitelistInstruction<
   TAccountWhitelist,
   TAccountWhitelistUser,
   TAccountVaultAdmin,
+  TAccountRentReceiver,
   TAccountUser,
   TAccountSystemProgram
 > {
@@ -162,9 +171,10 @@ export function getRemoveFromWhitelistInstruction<
   const originalAccounts = {
     config: { value: input.config ?? null, isWritable: false },
     vault: { value: input.vault ?? null, isWritable: false },
-    whitelist: { value: input.whitelist ?? null, isWritable: false },
+    whitelist: { value: input.whitelist ?? null, isWritable: true },
     whitelistUser: { value: input.whitelistUser ?? null, isWritable: true },
     vaultAdmin: { value: input.vaultAdmin ?? null, isWritable: false },
+    rentReceiver: { value: input.rentReceiver ?? null, isWritable: true },
     user: { value: input.user ?? null, isWritable: false },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
   };
@@ -187,6 +197,7 @@ export function getRemoveFromWhitelistInstruction<
       getAccountMeta(accounts.whitelist),
       getAccountMeta(accounts.whitelistUser),
       getAccountMeta(accounts.vaultAdmin),
+      getAccountMeta(accounts.rentReceiver),
       getAccountMeta(accounts.user),
       getAccountMeta(accounts.systemProgram),
     ],
@@ -199,6 +210,7 @@ export function getRemoveFromWhitelistInstruction<
     TAccountWhitelist,
     TAccountWhitelistUser,
     TAccountVaultAdmin,
+    TAccountRentReceiver,
     TAccountUser,
     TAccountSystemProgram
   >;
@@ -217,8 +229,9 @@ export type ParsedRemoveFromWhitelistInstruction<
     whitelist: TAccountMetas[2];
     whitelistUser: TAccountMetas[3];
     vaultAdmin: TAccountMetas[4];
-    user: TAccountMetas[5];
-    systemProgram: TAccountMetas[6];
+    rentReceiver: TAccountMetas[5];
+    user: TAccountMetas[6];
+    systemProgram: TAccountMetas[7];
   };
   data: RemoveFromWhitelistInstructionData;
 };
@@ -231,7 +244,7 @@ export function parseRemoveFromWhitelistInstruction<
     IInstructionWithAccounts<TAccountMetas> &
     IInstructionWithData<Uint8Array>
 ): ParsedRemoveFromWhitelistInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 7) {
+  if (instruction.accounts.length < 8) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -249,6 +262,7 @@ export function parseRemoveFromWhitelistInstruction<
       whitelist: getNextAccount(),
       whitelistUser: getNextAccount(),
       vaultAdmin: getNextAccount(),
+      rentReceiver: getNextAccount(),
       user: getNextAccount(),
       systemProgram: getNextAccount(),
     },
@@ -13,24 +13,42 @@ import {
   type ReadonlyUint8Array,
 } from '@solana/kit';
 import {
+  type ParsedAddToBlacklistInstruction,
   type ParsedAddToWhitelistInstruction,
   type ParsedBurnWithdrawalTicketInstruction,
+  type ParsedCloseOrphanedWhitelistUserInstruction,
   type ParsedCloseWhitelistInstruction,
+  type ParsedCrankBurnWithdrawalTicketInstruction,
   type ParsedEnqueueWithdrawalInstruction,
   type ParsedInitializeConfigInstruction,
   type ParsedInitializeWhitelistInstruction,
+  type ParsedMigrateAccountInstruction,
+  type ParsedMintForInstruction,
   type ParsedMintInstruction,
+  type ParsedRemoveFromBlacklistInstruction,
   type ParsedRemoveFromWhitelistInstruction,
+  type ParsedSetBurnCrankInstruction,
+  type ParsedSetBurnDelegateInstruction,
+  type ParsedSetCustodianInstruction,
+  type ParsedSetDepositLimitsInstruction,
+  type ParsedSetLockupInstruction,
   type ParsedSetMintBurnAdminInstruction,
+  type ParsedSetRateLimitInstruction,
+  type ParsedSetUserDepositLimitsInstruction,
+  type ParsedSetUserReferenceInstruction,
+  type ParsedSetWhitelistMetadataInstruction,
+  type ParsedSetWhitelistModeInstruction,
 } from '../instructions';
 
 export const JITO_VAULT_WHITELIST_PROGRAM_ADDRESS =
   '7BHULFc6NKwtc7f2ap6y7ty1cRfTN5MBMfJQj1rxEUhP' as Address<'7BHULFc6NKwtc7f2ap6y7ty1cRfTN5MBMfJQj1rxEUhP'>;
 
 export enum JitoVaultWhitelistAccount {
+  Blacklisted,
   Config,
   Whitelist,
   WhitelistUser,
+  WhitelistMetadata,
 }
 
 export enum JitoVaultWhitelistInstruction {
@@ -43,6 +61,22 @@ export enum JitoVaultWhitelistInstruction {
   EnqueueWithdrawal,
   BurnWithdrawalTicket,
   CloseWhitelist,
+  CloseOrphanedWhitelistUser,
+  SetWhitelistMode,
+  AddToBlacklist,
+  RemoveFromBlacklist,
+  SetLockup,
+  SetRateLimit,
+  SetDepositLimits,
+  SetUserDepositLimits,
+  SetCustodian,
+  MintFor,
+  SetBurnCrank,
+  CrankBurnWithdrawalTicket,
+  SetBurnDelegate,
+  MigrateAccount,
+  SetWhitelistMetadata,
+  SetUserReference,
 }
 
 export function identifyJitoVaultWhitelistInstruction(
@@ -76,6 +110,54 @@ export function identifyJitoVaultWhitelistInstruction(
   if (containsBytes(data, getU8Encoder().encode(8), 0)) {
     return JitoVaultWhitelistInstruction.CloseWhitelist;
   }
+  if (containsBytes(data, getU8Encoder().encode(9), 0)) {
+    return JitoVaultWhitelistInstruction.CloseOrphanedWhitelistUser;
+  }
+  if (containsBytes(data, getU8Encoder().encode(10), 0)) {
+    return JitoVaultWhitelistInstruction.SetWhitelistMode;
+  }
+  if (containsBytes(data, getU8Encoder().encode(11), 0)) {
+    return JitoVaultWhitelistInstruction.AddToBlacklist;
+  }
+  if (containsBytes(data, getU8Encoder().encode(12), 0)) {
+    return JitoVaultWhitelistInstruction.RemoveFromBlacklist;
+  }
+  if (containsBytes(data, getU8Encoder().encode(13), 0)) {
+    return JitoVaultWhitelistInstruction.SetLockup;
+  }
+  if (containsBytes(data, getU8Encoder().encode(14), 0)) {
+    return JitoVaultWhitelistInstruction.SetRateLimit;
+  }
+  if (containsBytes(data, getU8Encoder().encode(15), 0)) {
+    return JitoVaultWhitelistInstruction.SetDepositLimits;
+  }
+  if (containsBytes(data, getU8Encoder().encode(16), 0)) {
+    return JitoVaultWhitelistInstruction.SetUserDepositLimits;
+  }
+  if (containsBytes(data, getU8Encoder().encode(17), 0)) {
+    return JitoVaultWhitelistInstruction.SetCustodian;
+  }
+  if (containsBytes(data, getU8Encoder().encode(18), 0)) {
+    return JitoVaultWhitelistInstruction.MintFor;
+  }
+  if (containsBytes(data, getU8Encoder().encode(19), 0)) {
+    return JitoVaultWhitelistInstruction.SetBurnCrank;
+  }
+  if (containsBytes(data, getU8Encoder().encode(20), 0)) {
+    return JitoVaultWhitelistInstruction.CrankBurnWithdrawalTicket;
+  }
+  if (containsBytes(data, getU8Encoder().encode(21), 0)) {
+    return JitoVaultWhitelistInstruction.SetBurnDelegate;
+  }
+  if (containsBytes(data, getU8Encoder().encode(22), 0)) {
+    return JitoVaultWhitelistInstruction.MigrateAccount;
+  }
+  if (containsBytes(data, getU8Encoder().encode(23), 0)) {
+    return JitoVaultWhitelistInstruction.SetWhitelistMetadata;
+  }
+  if (containsBytes(data, getU8Encoder().encode(24), 0)) {
+    return JitoVaultWhitelistInstruction.SetUserReference;
+  }
   throw new Error(
     'The provided instruction could not be identified as a jitoVaultWhitelist instruction.'
   );
@@ -110,4 +192,52 @@ export type ParsedJitoVaultWhitelistInstruction<
     } & ParsedBurnWithdrawalTicketInstruction<TProgram>)
   | ({
       instructionType: JitoVaultWhitelistInstruction.CloseWhitelist;
-    } & ParsedCloseWhitelistInstruction<TProgram>);
+    } & ParsedCloseWhitelistInstruction<TProgram>)
+  | ({
+      instructionType: JitoVaultWhitelistInstruction.CloseOrphanedWhitelistUser;
+    } & ParsedCloseOrphanedWhitelistUserInstruction<TProgram>)
+  | ({
+      instructionType: JitoVaultWhitelistInstruction.SetWhitelistMode;
+    } & ParsedSetWhitelistModeInstruction<TProgram>)
+  | ({
+      instructionType: JitoVaultWhitelistInstruction.AddToBlacklist;
+    } & ParsedAddToBlacklistInstruction<TProgram>)
+  | ({
+      instructionType: JitoVaultWhitelistInstruction.RemoveFromBlacklist;
+    } & ParsedRemoveFromBlacklistInstruction<TProgram>)
+  | ({
+      instructionType: JitoVaultWhitelistInstruction.SetLockup;
+    } & ParsedSetLockupInstruction<TProgram>)
+  | ({
+      instructionType: JitoVaultWhitelistInstruction.SetRateLimit;
+    } & ParsedSetRateLimitInstruction<TProgram>)
+  | ({
+      instructionType: JitoVaultWhitelistInstruction.SetDepositLimits;
+    } & ParsedSetDepositLimitsInstruction<TProgram>)
+  | ({
+      instructionType: JitoVaultWhitelistInstruction.SetUserDepositLimits;
+    } & ParsedSetUserDepositLimitsInstruction<TProgram>)
+  | ({
+      instructionType: JitoVaultWhitelistInstruction.SetCustodian;
+    } & ParsedSetCustodianInstruction<TProgram>)
+  | ({
+      instructionType: JitoVaultWhitelistInstruction.MintFor;
+    } & ParsedMintForInstruction<TProgram>)
+  | ({
+      instructionType: JitoVaultWhitelistInstruction.SetBurnCrank;
+    } & ParsedSetBurnCrankInstruction<TProgram>)
+  | ({
+      instructionType: JitoVaultWhitelistInstruction.CrankBurnWithdrawalTicket;
+    } & ParsedCrankBurnWithdrawalTicketInstruction<TProgram>)
+  | ({
+      instructionType: JitoVaultWhitelistInstruction.SetBurnDelegate;
+    } & ParsedSetBurnDelegateInstruction<TProgram>)
+  | ({
+      instructionType: JitoVaultWhitelistInstruction.MigrateAccount;
+    } & ParsedMigrateAccountInstruction<TProgram>)
+  | ({
+      instructionType: JitoVaultWhitelistInstruction.SetWhitelistMetadata;
+    } & ParsedSetWhitelistMetadataInstruction<TProgram>)
+  | ({
+      instructionType: JitoVaultWhitelistInstruction.SetUserReference;
+    } & ParsedSetUserReferenceInstruction<TProgram>);
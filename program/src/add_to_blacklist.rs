@@ -0,0 +1,107 @@
+use jito_bytemuck::{AccountDeserialize, Discriminator};
+use jito_jsm_core::{
+    create_account,
+    loader::{load_signer, load_system_program},
+};
+use jito_vault_core::vault::Vault;
+use jito_vault_whitelist_core::{blacklisted::Blacklisted, config::Config, whitelist::Whitelist};
+use jito_vault_whitelist_sdk::error::VaultWhitelistError;
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey, rent::Rent, sysvar::Sysvar,
+};
+
+/// Process adding user to blacklist
+pub fn process_add_to_blacklist(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let [config_info, vault_info, whitelist_info, blacklisted_info, vault_admin_info, payer_info, user_info, system_program_info] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    Config::load(program_id, config_info, false)?;
+
+    Vault::load(&jito_vault_program::id(), vault_info, false)?;
+    let vault_data = vault_info.data.borrow();
+    let vault = Vault::try_from_slice_unchecked(&vault_data)?;
+
+    vault.check_admin(vault_admin_info.key)?;
+
+    Whitelist::load(program_id, whitelist_info, vault_info.key, false)?;
+    let whitelist_data = whitelist_info.data.borrow();
+    let whitelist = Whitelist::try_from_slice_unchecked(&whitelist_data)?;
+
+    whitelist.check_vault(vault_info.key)?;
+
+    load_signer(vault_admin_info, false)?;
+    load_signer(payer_info, true)?;
+    load_system_program(system_program_info)?;
+
+    // The Blacklisted account shall be at the canonical PDA
+    let (blacklisted_pubkey, blacklisted_bump, mut blacklisted_seeds) =
+        Blacklisted::find_program_address(program_id, whitelist_info.key, user_info.key);
+    blacklisted_seeds.push(vec![blacklisted_bump]);
+    if blacklisted_pubkey.ne(blacklisted_info.key) {
+        msg!("Blacklisted account is not at the correct PDA");
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    // A Blacklisted left behind by a closed whitelist still sits at the PDA, so it is
+    // reinitialized in place and keeps its original payer, who funded the rent
+    let payer = if blacklisted_info.data_is_empty() {
+        msg!(
+            "Initializing Blacklisted at address {}",
+            blacklisted_info.key
+        );
+        create_account(
+            payer_info,
+            blacklisted_info,
+            system_program_info,
+            program_id,
+            &Rent::get()?,
+            8_u64
+                .checked_add(std::mem::size_of::<Blacklisted>() as u64)
+                .ok_or(VaultWhitelistError::ArithmeticOverflow)?,
+            &blacklisted_seeds,
+        )?;
+
+        let mut blacklisted_data = blacklisted_info.try_borrow_mut_data()?;
+        blacklisted_data[0] = Blacklisted::DISCRIMINATOR;
+
+        *payer_info.key
+    } else {
+        Blacklisted::load(
+            program_id,
+            blacklisted_info,
+            whitelist_info.key,
+            user_info.key,
+            true,
+        )?;
+        let blacklisted_data = blacklisted_info.data.borrow();
+        let blacklisted = Blacklisted::try_from_slice_unchecked(&blacklisted_data)?;
+
+        if blacklisted.generation() == whitelist.generation() {
+            msg!("User is already blacklisted");
+            return Err(ProgramError::AccountAlreadyInitialized);
+        }
+
+        msg!(
+            "Reinitializing stale Blacklisted at address {}",
+            blacklisted_info.key
+        );
+
+        blacklisted.payer
+    };
+
+    let mut blacklisted_data = blacklisted_info.try_borrow_mut_data()?;
+    let blacklisted = Blacklisted::try_from_slice_unchecked_mut(&mut blacklisted_data)?;
+    *blacklisted = Blacklisted::new(
+        *whitelist_info.key,
+        *user_info.key,
+        payer,
+        whitelist.generation(),
+        blacklisted_bump,
+    );
+
+    Ok(())
+}
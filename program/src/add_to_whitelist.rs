@@ -5,7 +5,9 @@ use jito_jsm_core::{
 };
 use jito_vault_core::vault::Vault;
 use jito_vault_whitelist_core::{
-    config::Config, whitelist::Whitelist, whitelist_user::WhitelistUser,
+    config::Config,
+    whitelist::Whitelist,
+    whitelist_user::{WhitelistUser, WHITELIST_USER_REFERENCE_LEN},
 };
 use jito_vault_whitelist_sdk::error::VaultWhitelistError;
 use solana_program::{
@@ -14,8 +16,12 @@ use solana_program::{
 };
 
 /// Process adding new user to whitelist
-pub fn process_add_to_whitelist(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
-    let [config_info, vault_info, whitelist_info, whitelist_user_info, vault_admin_info, user_info, system_program_info] =
+pub fn process_add_to_whitelist(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    reference: [u8; WHITELIST_USER_REFERENCE_LEN],
+) -> ProgramResult {
+    let [config_info, vault_info, whitelist_info, whitelist_user_info, vault_admin_info, payer_info, user_info, system_program_info] =
         accounts
     else {
         return Err(ProgramError::NotEnoughAccountKeys);
@@ -29,15 +35,19 @@ pub fn process_add_to_whitelist(program_id: &Pubkey, accounts: &[AccountInfo]) -
 
     vault.check_admin(vault_admin_info.key)?;
 
-    {
-        Whitelist::load(program_id, whitelist_info, vault_info.key, false)?;
-        let whitelist_data = whitelist_info.data.borrow();
-        let whitelist = Whitelist::try_from_slice_unchecked(&whitelist_data)?;
+    let generation = {
+        Whitelist::load(program_id, whitelist_info, vault_info.key, true)?;
+        let mut whitelist_data = whitelist_info.try_borrow_mut_data()?;
+        let whitelist = Whitelist::try_from_slice_unchecked_mut(&mut whitelist_data)?;
 
         whitelist.check_vault(vault_info.key)?;
-    }
+        whitelist.increment_user_count()?;
+
+        whitelist.generation()
+    };
 
-    load_signer(vault_admin_info, true)?;
+    load_signer(vault_admin_info, false)?;
+    load_signer(payer_info, true)?;
     load_system_program(system_program_info)?;
 
     // The WhitelistUser account shall be at the canonical PDA
@@ -49,26 +59,63 @@ pub fn process_add_to_whitelist(program_id: &Pubkey, accounts: &[AccountInfo]) -
         return Err(ProgramError::InvalidAccountData);
     }
 
-    msg!(
-        "Initializing WhitelistUser at address {}",
-        whitelist_user_info.key
-    );
-    create_account(
-        vault_admin_info,
-        whitelist_user_info,
-        system_program_info,
-        program_id,
-        &Rent::get()?,
-        8_u64
-            .checked_add(std::mem::size_of::<WhitelistUser>() as u64)
-            .ok_or(VaultWhitelistError::ArithmeticOverflow)?,
-        &whitelist_user_seeds,
-    )?;
+    // A WhitelistUser left behind by a closed whitelist still sits at the PDA, so it is
+    // reinitialized in place and keeps its original payer, who funded the rent
+    let payer = if whitelist_user_info.data_is_empty() {
+        msg!(
+            "Initializing WhitelistUser at address {}",
+            whitelist_user_info.key
+        );
+        create_account(
+            payer_info,
+            whitelist_user_info,
+            system_program_info,
+            program_id,
+            &Rent::get()?,
+            8_u64
+                .checked_add(std::mem::size_of::<WhitelistUser>() as u64)
+                .ok_or(VaultWhitelistError::ArithmeticOverflow)?,
+            &whitelist_user_seeds,
+        )?;
+
+        let mut whitelist_user_data = whitelist_user_info.try_borrow_mut_data()?;
+        whitelist_user_data[0] = WhitelistUser::DISCRIMINATOR;
+
+        *payer_info.key
+    } else {
+        WhitelistUser::load(
+            program_id,
+            whitelist_user_info,
+            whitelist_info.key,
+            user_info.key,
+            true,
+        )?;
+        let whitelist_user_data = whitelist_user_info.data.borrow();
+        let whitelist_user = WhitelistUser::try_from_slice_unchecked(&whitelist_user_data)?;
+
+        if whitelist_user.generation() == generation {
+            msg!("User is already whitelisted");
+            return Err(ProgramError::AccountAlreadyInitialized);
+        }
+
+        msg!(
+            "Reinitializing stale WhitelistUser at address {}",
+            whitelist_user_info.key
+        );
+
+        whitelist_user.payer
+    };
 
     let mut whitelist_user_data = whitelist_user_info.try_borrow_mut_data()?;
-    whitelist_user_data[0] = WhitelistUser::DISCRIMINATOR;
     let whitelist_acc = WhitelistUser::try_from_slice_unchecked_mut(&mut whitelist_user_data)?;
-    *whitelist_acc = WhitelistUser::new(*whitelist_info.key, *user_info.key, whitelist_user_bump);
+    *whitelist_acc = WhitelistUser::new(
+        *whitelist_info.key,
+        *user_info.key,
+        payer,
+        generation,
+        whitelist_user_bump,
+    );
+    whitelist_acc.set_reference(reference);
 
     Ok(())
 }
@@ -2,17 +2,23 @@ use jito_bytemuck::AccountDeserialize;
 use jito_jsm_core::loader::load_signer;
 use jito_vault_sdk::sdk::burn_withdrawal_ticket;
 use jito_vault_whitelist_core::{
-    config::Config, whitelist::Whitelist, whitelist_user::WhitelistUser,
+    config::Config,
+    whitelist::{Whitelist, WhitelistMode},
+    whitelist_user::WhitelistUser,
 };
+use jito_vault_whitelist_sdk::error::VaultWhitelistError;
 use solana_program::{
-    account_info::AccountInfo, entrypoint::ProgramResult, msg, program::invoke_signed,
-    program_error::ProgramError, pubkey::Pubkey,
+    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult, msg,
+    program::invoke_signed, program_error::ProgramError, pubkey::Pubkey, sysvar::Sysvar,
 };
 
+use crate::utils::{check_whitelist_access, token_account_amount};
+
 /// Process burning withdrawal ticket
 pub fn process_burn_withdrawal_ticket(
     program_id: &Pubkey,
     accounts: &[AccountInfo],
+    min_amount_out: u64,
 ) -> ProgramResult {
     let [vault_config_info, vault_info, vault_token_account, vrt_mint, staker, staker_token_account, vault_staker_withdrawal_ticket_info, vault_staker_withdrawal_ticket_token_account, vault_fee_token_account, program_fee_token_account, token_program, system_program, config_info, whitelist_info, whitelist_user_info, jito_vault_program_info] =
         accounts
@@ -30,18 +36,13 @@ pub fn process_burn_withdrawal_ticket(
 
     load_signer(staker, true)?;
 
-    WhitelistUser::load(
+    let whitelist_mode = check_whitelist_access(
         program_id,
+        whitelist,
+        whitelist_info,
         whitelist_user_info,
-        whitelist_info.key,
         staker.key,
-        false,
     )?;
-    let whitelist_user_data = whitelist_user_info.data.borrow();
-    let whitelist_user = WhitelistUser::try_from_slice_unchecked(&whitelist_user_data)?;
-
-    whitelist_user.check_whitelist(whitelist_info.key)?;
-    whitelist_user.check_user(staker.key)?;
 
     let (_, whitelist_bump, mut whitelist_seeds) =
         Whitelist::find_program_address(program_id, vault_info.key);
@@ -64,6 +65,8 @@ pub fn process_burn_withdrawal_ticket(
 
     drop(whitelist_data);
 
+    let staker_token_amount_before = token_account_amount(staker_token_account)?;
+
     msg!("Processing burn_withdrawal_ticket instruction on Jito Vault Program");
 
     invoke_signed(
@@ -91,5 +94,29 @@ pub fn process_burn_withdrawal_ticket(
             .as_slice()],
     )?;
 
+    let tokens_withdrawn = token_account_amount(staker_token_account)?
+        .checked_sub(staker_token_amount_before)
+        .ok_or(VaultWhitelistError::ArithmeticUnderflow)?;
+
+    if tokens_withdrawn < min_amount_out {
+        msg!(
+            "Staker received {} supported tokens, less than the minimum {}",
+            tokens_withdrawn,
+            min_amount_out
+        );
+        return Err(VaultWhitelistError::SlippageExceeded.into());
+    }
+
+    let mut whitelist_data = whitelist_info.try_borrow_mut_data()?;
+    let whitelist = Whitelist::try_from_slice_unchecked_mut(&mut whitelist_data)?;
+    whitelist.record_burn_withdrawal_ticket()?;
+
+    // Only allow-list users have a WhitelistUser to record activity on
+    if whitelist_mode == WhitelistMode::AllowList {
+        let mut whitelist_user_data = whitelist_user_info.try_borrow_mut_data()?;
+        let whitelist_user = WhitelistUser::try_from_slice_unchecked_mut(&mut whitelist_user_data)?;
+        whitelist_user.record_burn_withdrawal_ticket(tokens_withdrawn, Clock::get()?.slot)?;
+    }
+
     Ok(())
 }
@@ -0,0 +1,80 @@
+use jito_bytemuck::AccountDeserialize;
+use jito_jsm_core::close_program_account;
+use jito_vault_core::vault::Vault;
+use jito_vault_whitelist_core::{
+    config::Config, whitelist::Whitelist, whitelist_user::WhitelistUser,
+};
+use jito_vault_whitelist_sdk::error::VaultWhitelistError;
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey, system_program,
+};
+
+/// Process closing a WhitelistUser whose Whitelist has been closed or re-created
+pub fn process_close_orphaned_whitelist_user(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    let [config_info, vault_info, whitelist_info, whitelist_user_info, rent_receiver_info, user_info] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    Config::load(program_id, config_info, false)?;
+
+    Vault::load(&jito_vault_program::id(), vault_info, false)?;
+    let vault_data = vault_info.data.borrow();
+    let vault = Vault::try_from_slice_unchecked(&vault_data)?;
+
+    // The Whitelist account shall be at the canonical PDA and either closed or re-created
+    let whitelist_pubkey = Whitelist::find_program_address(program_id, vault_info.key).0;
+    if whitelist_pubkey.ne(whitelist_info.key) {
+        msg!("Whitelist account is not at the correct PDA");
+        return Err(ProgramError::InvalidAccountData);
+    }
+    let whitelist_generation =
+        if whitelist_info.owner.eq(&system_program::id()) && whitelist_info.data_is_empty() {
+            None
+        } else {
+            Whitelist::load(program_id, whitelist_info, vault_info.key, false)?;
+            let whitelist_data = whitelist_info.data.borrow();
+            let whitelist = Whitelist::try_from_slice_unchecked(&whitelist_data)?;
+
+            Some(whitelist.generation())
+        };
+
+    if !rent_receiver_info.is_writable {
+        msg!("Rent receiver account is not writable");
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    {
+        WhitelistUser::load(
+            program_id,
+            whitelist_user_info,
+            whitelist_info.key,
+            user_info.key,
+            true,
+        )?;
+        let whitelist_user_data = whitelist_user_info.data.borrow();
+        let whitelist_user = WhitelistUser::try_from_slice_unchecked(&whitelist_user_data)?;
+
+        if whitelist_generation.eq(&Some(whitelist_user.generation())) {
+            msg!("WhitelistUser belongs to the current whitelist");
+            return Err(VaultWhitelistError::WhitelistNotClosed.into());
+        }
+        whitelist_user.check_user(user_info.key)?;
+        whitelist_user.check_rent_receiver(rent_receiver_info.key, &vault.admin)?;
+    }
+
+    msg!(
+        "Closing orphaned WhitelistUser {} of Whitelist {}",
+        whitelist_user_info.key,
+        whitelist_info.key
+    );
+
+    close_program_account(program_id, whitelist_user_info, rent_receiver_info)?;
+
+    Ok(())
+}
@@ -13,7 +13,7 @@ use solana_program::{
 
 /// Process closing whitelist
 pub fn process_close_whitelist(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
-    let [config_info, vault_config_info, whitelist_info, vault_info, vault_admin_info, jito_vault_program_info, system_program_info] =
+    let [config_info, vault_config_info, whitelist_info, vault_info, vault_admin_info, rent_receiver_info, jito_vault_program_info, system_program_info] =
         accounts
     else {
         return Err(ProgramError::NotEnoughAccountKeys);
@@ -30,6 +30,18 @@ pub fn process_close_whitelist(program_id: &Pubkey, accounts: &[AccountInfo]) ->
     load_signer(vault_admin_info, true)?;
     load_system_program(system_program_info)?;
 
+    if !rent_receiver_info.is_writable {
+        msg!("Rent receiver account is not writable");
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    {
+        let whitelist_data = whitelist_info.data.borrow();
+        let whitelist = Whitelist::try_from_slice_unchecked(&whitelist_data)?;
+
+        whitelist.check_rent_receiver(rent_receiver_info.key, vault_admin_info.key)?;
+    }
+
     let new_admin = Pubkey::default();
 
     msg!(
@@ -58,7 +70,7 @@ pub fn process_close_whitelist(program_id: &Pubkey, accounts: &[AccountInfo]) ->
         ],
     )?;
 
-    close_program_account(program_id, whitelist_info, vault_admin_info)?;
+    close_program_account(program_id, whitelist_info, rent_receiver_info)?;
 
     Ok(())
 }
@@ -0,0 +1,134 @@
+use jito_bytemuck::AccountDeserialize;
+use jito_jsm_core::loader::load_signer;
+use jito_vault_sdk::sdk::burn_withdrawal_ticket;
+use jito_vault_whitelist_core::{
+    config::Config,
+    whitelist::{Whitelist, WhitelistMode},
+    whitelist_user::WhitelistUser,
+};
+use jito_vault_whitelist_sdk::error::VaultWhitelistError;
+use solana_program::{
+    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult, msg,
+    program::invoke_signed, program_error::ProgramError, pubkey::Pubkey, sysvar::Sysvar,
+};
+
+use crate::utils::{check_whitelist_access, token_account_amount, token_account_owner};
+
+/// Process burning withdrawal ticket on behalf of a staker, signed by the whitelist burn crank or
+/// a burn delegate of the staker
+///
+/// Takes no slippage bound since the signer is not the staker being paid
+pub fn process_crank_burn_withdrawal_ticket(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    let [vault_config_info, vault_info, vault_token_account, vrt_mint, staker, staker_token_account, vault_staker_withdrawal_ticket_info, vault_staker_withdrawal_ticket_token_account, vault_fee_token_account, program_fee_token_account, token_program, system_program, config_info, whitelist_info, whitelist_user_info, jito_vault_program_info, burn_crank] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    Config::load(program_id, config_info, false)?;
+
+    Whitelist::load(program_id, whitelist_info, vault_info.key, true)?;
+    let whitelist_data = whitelist_info.data.borrow();
+    let whitelist = Whitelist::try_from_slice_unchecked(&whitelist_data)?;
+
+    whitelist.check_vault(vault_info.key)?;
+
+    load_signer(burn_crank, false)?;
+
+    let whitelist_mode = check_whitelist_access(
+        program_id,
+        whitelist,
+        whitelist_info,
+        whitelist_user_info,
+        staker.key,
+    )?;
+
+    // Burn delegates are set on WhitelistUser, so they only apply in allow-list mode
+    let burn_delegate = whitelist_mode == WhitelistMode::AllowList && {
+        let whitelist_user_data = whitelist_user_info.data.borrow();
+        WhitelistUser::try_from_slice_unchecked(&whitelist_user_data)?
+            .is_burn_delegate(burn_crank.key)
+    };
+    if !burn_delegate {
+        whitelist.check_burn_crank(burn_crank.key)?;
+    }
+
+    // The crank chooses the accounts, so the payout must still go to the staker
+    if token_account_owner(staker_token_account)?.ne(staker.key) {
+        msg!("Staker token account is not owned by the staker");
+        return Err(VaultWhitelistError::InvalidStakerTokenAccount.into());
+    }
+
+    let (_, whitelist_bump, mut whitelist_seeds) =
+        Whitelist::find_program_address(program_id, vault_info.key);
+    whitelist_seeds.push(vec![whitelist_bump]);
+
+    let ix = burn_withdrawal_ticket(
+        &jito_vault_program::id(),
+        vault_config_info.key,
+        vault_info.key,
+        vault_token_account.key,
+        vrt_mint.key,
+        staker.key,
+        staker_token_account.key,
+        vault_staker_withdrawal_ticket_info.key,
+        vault_staker_withdrawal_ticket_token_account.key,
+        vault_fee_token_account.key,
+        program_fee_token_account.key,
+        Some(whitelist_info.key),
+    );
+
+    drop(whitelist_data);
+
+    let staker_token_amount_before = token_account_amount(staker_token_account)?;
+
+    msg!(
+        "Processing burn_withdrawal_ticket instruction on Jito Vault Program for staker {}",
+        staker.key
+    );
+
+    invoke_signed(
+        &ix,
+        &[
+            vault_config_info.clone(),
+            vault_info.clone(),
+            vault_token_account.clone(),
+            vrt_mint.clone(),
+            staker.clone(),
+            staker_token_account.clone(),
+            vault_staker_withdrawal_ticket_info.clone(),
+            vault_staker_withdrawal_ticket_token_account.clone(),
+            vault_fee_token_account.clone(),
+            program_fee_token_account.clone(),
+            whitelist_info.clone(),
+            token_program.clone(),
+            system_program.clone(),
+            jito_vault_program_info.clone(),
+        ],
+        &[whitelist_seeds
+            .iter()
+            .map(|s| s.as_slice())
+            .collect::<Vec<&[u8]>>()
+            .as_slice()],
+    )?;
+
+    let tokens_withdrawn = token_account_amount(staker_token_account)?
+        .checked_sub(staker_token_amount_before)
+        .ok_or(VaultWhitelistError::ArithmeticUnderflow)?;
+
+    let mut whitelist_data = whitelist_info.try_borrow_mut_data()?;
+    let whitelist = Whitelist::try_from_slice_unchecked_mut(&mut whitelist_data)?;
+    whitelist.record_burn_withdrawal_ticket()?;
+
+    // Only allow-list users have a WhitelistUser to record activity on
+    if whitelist_mode == WhitelistMode::AllowList {
+        let mut whitelist_user_data = whitelist_user_info.try_borrow_mut_data()?;
+        let whitelist_user = WhitelistUser::try_from_slice_unchecked_mut(&mut whitelist_user_data)?;
+        whitelist_user.record_burn_withdrawal_ticket(tokens_withdrawn, Clock::get()?.slot)?;
+    }
+
+    Ok(())
+}
@@ -2,13 +2,18 @@ use jito_bytemuck::AccountDeserialize;
 use jito_jsm_core::loader::load_signer;
 use jito_vault_sdk::sdk::enqueue_withdrawal;
 use jito_vault_whitelist_core::{
-    config::Config, whitelist::Whitelist, whitelist_user::WhitelistUser,
+    config::Config,
+    whitelist::{Whitelist, WhitelistMode},
+    whitelist_user::WhitelistUser,
 };
+use jito_vault_whitelist_sdk::error::VaultWhitelistError;
 use solana_program::{
-    account_info::AccountInfo, entrypoint::ProgramResult, msg, program::invoke_signed,
-    program_error::ProgramError, pubkey::Pubkey,
+    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult, msg,
+    program::invoke_signed, program_error::ProgramError, pubkey::Pubkey, sysvar::Sysvar,
 };
 
+use crate::utils::{check_whitelist_access, token_account_amount};
+
 /// Process enqueueing withdrawal
 pub fn process_enqueue_withdrawal(
     program_id: &Pubkey,
@@ -31,18 +36,21 @@ pub fn process_enqueue_withdrawal(
 
     load_signer(staker, true)?;
 
-    WhitelistUser::load(
+    let whitelist_mode = check_whitelist_access(
         program_id,
+        whitelist,
+        whitelist_info,
         whitelist_user_info,
-        whitelist_info.key,
         staker.key,
-        false,
     )?;
-    let whitelist_user_data = whitelist_user_info.data.borrow();
-    let whitelist_user = WhitelistUser::try_from_slice_unchecked(&whitelist_user_data)?;
 
-    whitelist_user.check_whitelist(whitelist_info.key)?;
-    whitelist_user.check_user(staker.key)?;
+    // The lockup is tracked on WhitelistUser, so it only applies in allow-list mode
+    if whitelist_mode == WhitelistMode::AllowList {
+        let whitelist_user_data = whitelist_user_info.data.borrow();
+        let whitelist_user = WhitelistUser::try_from_slice_unchecked(&whitelist_user_data)?;
+
+        whitelist_user.check_lockup(whitelist.lockup_slots(), Clock::get()?.slot)?;
+    }
 
     let (_, whitelist_bump, mut whitelist_seeds) =
         Whitelist::find_program_address(program_id, vault_info.key);
@@ -63,6 +71,30 @@ pub fn process_enqueue_withdrawal(
 
     drop(whitelist_data);
 
+    let epoch = Clock::get()?.epoch;
+    {
+        let mut whitelist_data = whitelist_info.try_borrow_mut_data()?;
+        let whitelist = Whitelist::try_from_slice_unchecked_mut(&mut whitelist_data)?;
+
+        if let Some(window_start_epoch) = whitelist.rate_limit_window(epoch) {
+            whitelist.consume_enqueue_limit(vrt_amount, window_start_epoch)?;
+
+            // Per-user windows are tracked on WhitelistUser, so they only apply in allow-list mode
+            if whitelist_mode == WhitelistMode::AllowList {
+                let mut whitelist_user_data = whitelist_user_info.try_borrow_mut_data()?;
+                let whitelist_user =
+                    WhitelistUser::try_from_slice_unchecked_mut(&mut whitelist_user_data)?;
+                whitelist_user.consume_enqueue_limit(
+                    vrt_amount,
+                    window_start_epoch,
+                    whitelist.user_enqueue_limit(),
+                )?;
+            }
+        }
+    }
+
+    let staker_vrt_amount_before = token_account_amount(staker_vrt_token_account)?;
+
     msg!("Processing enqueue_withdrawal instruction on Jito Vault Program");
 
     invoke_signed(
@@ -87,5 +119,20 @@ pub fn process_enqueue_withdrawal(
             .as_slice()],
     )?;
 
+    let vrt_enqueued = staker_vrt_amount_before
+        .checked_sub(token_account_amount(staker_vrt_token_account)?)
+        .ok_or(VaultWhitelistError::ArithmeticUnderflow)?;
+
+    let mut whitelist_data = whitelist_info.try_borrow_mut_data()?;
+    let whitelist = Whitelist::try_from_slice_unchecked_mut(&mut whitelist_data)?;
+    whitelist.record_enqueue_withdrawal(vrt_enqueued)?;
+
+    // Only allow-list users have a WhitelistUser to record activity on
+    if whitelist_mode == WhitelistMode::AllowList {
+        let mut whitelist_user_data = whitelist_user_info.try_borrow_mut_data()?;
+        let whitelist_user = WhitelistUser::try_from_slice_unchecked_mut(&mut whitelist_user_data)?;
+        whitelist_user.record_enqueue_withdrawal(vrt_enqueued, Clock::get()?.slot)?;
+    }
+
     Ok(())
 }
@@ -4,24 +4,33 @@ use jito_jsm_core::{
     loader::{load_signer, load_system_account, load_system_program},
 };
 use jito_vault_core::vault::Vault;
-use jito_vault_whitelist_core::{config::Config, whitelist::Whitelist};
+use jito_vault_whitelist_core::{
+    config::Config,
+    whitelist::Whitelist,
+    whitelist_metadata::{WhitelistMetadata, WHITELIST_NAME_LEN, WHITELIST_URI_LEN},
+};
 use jito_vault_whitelist_sdk::error::VaultWhitelistError;
 use solana_program::{
     account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
     pubkey::Pubkey, rent::Rent, sysvar::Sysvar,
 };
 
+use crate::utils::load_or_create_whitelist_metadata;
+
 /// Process initializing whitelist
 pub fn process_initialize_whitelist(
     program_id: &Pubkey,
     accounts: &[AccountInfo],
+    name: [u8; WHITELIST_NAME_LEN],
+    uri: [u8; WHITELIST_URI_LEN],
 ) -> ProgramResult {
-    let [config_info, whitelist_info, vault_info, vault_admin_info, system_program_info] = accounts
+    let [config_info, whitelist_info, vault_info, vault_admin_info, payer_info, system_program_info, kyc_provider_info, whitelist_metadata_info] =
+        accounts
     else {
         return Err(ProgramError::NotEnoughAccountKeys);
     };
 
-    Config::load(program_id, config_info, false)?;
+    Config::load(program_id, config_info, true)?;
 
     load_system_account(whitelist_info, true)?;
 
@@ -31,7 +40,8 @@ pub fn process_initialize_whitelist(
 
     vault.check_admin(vault_admin_info.key)?;
 
-    load_signer(vault_admin_info, true)?;
+    load_signer(vault_admin_info, false)?;
+    load_signer(payer_info, true)?;
     load_system_program(system_program_info)?;
 
     // The Whitelist account shall be at the canonical PDA
@@ -48,7 +58,7 @@ pub fn process_initialize_whitelist(
         whitelist_info.key
     );
     create_account(
-        vault_admin_info,
+        payer_info,
         whitelist_info,
         system_program_info,
         program_id,
@@ -59,10 +69,32 @@ pub fn process_initialize_whitelist(
         &whitelist_seeds,
     )?;
 
-    let mut whitelist_data = whitelist_info.try_borrow_mut_data()?;
-    whitelist_data[0] = Whitelist::DISCRIMINATOR;
-    let whitelist_acc = Whitelist::try_from_slice_unchecked_mut(&mut whitelist_data)?;
-    *whitelist_acc = Whitelist::new(*vault_info.key, whitelist_bump);
+    let generation = {
+        let mut config_data = config_info.try_borrow_mut_data()?;
+        let config = Config::try_from_slice_unchecked_mut(&mut config_data)?;
+        config.next_whitelist_generation()?
+    };
+
+    {
+        let mut whitelist_data = whitelist_info.try_borrow_mut_data()?;
+        whitelist_data[0] = Whitelist::DISCRIMINATOR;
+        let whitelist_acc = Whitelist::try_from_slice_unchecked_mut(&mut whitelist_data)?;
+        *whitelist_acc =
+            Whitelist::new(*vault_info.key, *payer_info.key, generation, whitelist_bump);
+    }
+
+    // Metadata left behind by an earlier whitelist at the same PDA is overwritten
+    load_or_create_whitelist_metadata(
+        program_id,
+        whitelist_info,
+        whitelist_metadata_info,
+        payer_info,
+        system_program_info,
+    )?;
+    let mut whitelist_metadata_data = whitelist_metadata_info.try_borrow_mut_data()?;
+    let whitelist_metadata =
+        WhitelistMetadata::try_from_slice_unchecked_mut(&mut whitelist_metadata_data)?;
+    whitelist_metadata.set_metadata(name, *kyc_provider_info.key, uri);
 
     Ok(())
 }
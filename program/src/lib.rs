@@ -1,28 +1,61 @@
+use add_to_blacklist::process_add_to_blacklist;
 use add_to_whitelist::process_add_to_whitelist;
 use borsh::BorshDeserialize;
 use burn_withdrawal_ticket::process_burn_withdrawal_ticket;
+use close_orphaned_whitelist_user::process_close_orphaned_whitelist_user;
 use close_whitelist::process_close_whitelist;
+use crank_burn_withdrawal_ticket::process_crank_burn_withdrawal_ticket;
 use enqueue_withdrawal::process_enqueue_withdrawal;
 use initialize_config::process_initialize_config;
 use initialize_whitelist::process_initialize_whitelist;
 use jito_vault_whitelist_sdk::instruction::VaultWhitelistInstruction;
+use migrate_account::process_migrate_account;
 use mint::process_mint;
+use mint_for::process_mint_for;
+use remove_from_blacklist::process_remove_from_blacklist;
 use remove_from_whitelist::process_remove_from_whitelist;
+use set_burn_crank::process_set_burn_crank;
+use set_burn_delegate::process_set_burn_delegate;
+use set_custodian::process_set_custodian;
+use set_deposit_limits::process_set_deposit_limits;
+use set_lockup::process_set_lockup;
 use set_mint_burn_admin::process_set_mint_burn_admin;
+use set_rate_limit::process_set_rate_limit;
+use set_user_deposit_limits::process_set_user_deposit_limits;
+use set_user_reference::process_set_user_reference;
+use set_whitelist_metadata::process_set_whitelist_metadata;
+use set_whitelist_mode::process_set_whitelist_mode;
 use solana_program::{
     account_info::AccountInfo, declare_id, entrypoint::ProgramResult, msg,
     program_error::ProgramError, pubkey::Pubkey,
 };
 
+mod add_to_blacklist;
 mod add_to_whitelist;
 mod burn_withdrawal_ticket;
+mod close_orphaned_whitelist_user;
 mod close_whitelist;
+mod crank_burn_withdrawal_ticket;
 mod enqueue_withdrawal;
 mod initialize_config;
 mod initialize_whitelist;
+mod migrate_account;
 mod mint;
+mod mint_for;
+mod remove_from_blacklist;
 mod remove_from_whitelist;
+mod set_burn_crank;
+mod set_burn_delegate;
+mod set_custodian;
+mod set_deposit_limits;
+mod set_lockup;
 mod set_mint_burn_admin;
+mod set_rate_limit;
+mod set_user_deposit_limits;
+mod set_user_reference;
+mod set_whitelist_metadata;
+mod set_whitelist_mode;
+mod utils;
 
 declare_id!(env!("VAULT_WHITELIST_PROGRAM_ID"));
 
@@ -46,9 +79,9 @@ pub fn process_instruction(
             process_initialize_config(program_id, accounts)
         }
 
-        VaultWhitelistInstruction::InitializeWhitelist => {
+        VaultWhitelistInstruction::InitializeWhitelist { name, uri } => {
             msg!("Instruction: InitializeWhitelist");
-            process_initialize_whitelist(program_id, accounts)
+            process_initialize_whitelist(program_id, accounts, name, uri)
         }
 
         VaultWhitelistInstruction::SetMintBurnAdmin => {
@@ -56,9 +89,9 @@ pub fn process_instruction(
             process_set_mint_burn_admin(program_id, accounts)
         }
 
-        VaultWhitelistInstruction::AddToWhitelist => {
+        VaultWhitelistInstruction::AddToWhitelist { reference } => {
             msg!("Instruction: AddToWhitelist");
-            process_add_to_whitelist(program_id, accounts)
+            process_add_to_whitelist(program_id, accounts, reference)
         }
 
         VaultWhitelistInstruction::RemoveFromWhitelist => {
@@ -79,14 +112,117 @@ pub fn process_instruction(
             process_enqueue_withdrawal(program_id, accounts, amount)
         }
 
-        VaultWhitelistInstruction::BurnWithdrawalTicket => {
+        VaultWhitelistInstruction::BurnWithdrawalTicket { min_amount_out } => {
             msg!("Instruction: BurnWithdrawalTicket");
-            process_burn_withdrawal_ticket(program_id, accounts)
+            process_burn_withdrawal_ticket(program_id, accounts, min_amount_out)
         }
 
         VaultWhitelistInstruction::CloseWhitelist => {
             msg!("Instruction: CloseWhitelist");
             process_close_whitelist(program_id, accounts)
         }
+
+        VaultWhitelistInstruction::CloseOrphanedWhitelistUser => {
+            msg!("Instruction: CloseOrphanedWhitelistUser");
+            process_close_orphaned_whitelist_user(program_id, accounts)
+        }
+
+        VaultWhitelistInstruction::SetWhitelistMode { mode } => {
+            msg!("Instruction: SetWhitelistMode");
+            process_set_whitelist_mode(program_id, accounts, mode)
+        }
+
+        VaultWhitelistInstruction::AddToBlacklist => {
+            msg!("Instruction: AddToBlacklist");
+            process_add_to_blacklist(program_id, accounts)
+        }
+
+        VaultWhitelistInstruction::RemoveFromBlacklist => {
+            msg!("Instruction: RemoveFromBlacklist");
+            process_remove_from_blacklist(program_id, accounts)
+        }
+
+        VaultWhitelistInstruction::SetLockup { lockup_slots } => {
+            msg!("Instruction: SetLockup");
+            process_set_lockup(program_id, accounts, lockup_slots)
+        }
+
+        VaultWhitelistInstruction::SetRateLimit {
+            rate_limit_epochs,
+            mint_limit,
+            enqueue_limit,
+            user_mint_limit,
+            user_enqueue_limit,
+        } => {
+            msg!("Instruction: SetRateLimit");
+            process_set_rate_limit(
+                program_id,
+                accounts,
+                rate_limit_epochs,
+                mint_limit,
+                enqueue_limit,
+                user_mint_limit,
+                user_enqueue_limit,
+            )
+        }
+
+        VaultWhitelistInstruction::SetDepositLimits {
+            min_deposit,
+            max_deposit,
+        } => {
+            msg!("Instruction: SetDepositLimits");
+            process_set_deposit_limits(program_id, accounts, min_deposit, max_deposit)
+        }
+
+        VaultWhitelistInstruction::SetUserDepositLimits {
+            min_deposit,
+            max_deposit,
+        } => {
+            msg!("Instruction: SetUserDepositLimits");
+            process_set_user_deposit_limits(program_id, accounts, min_deposit, max_deposit)
+        }
+
+        VaultWhitelistInstruction::SetCustodian { custodian } => {
+            msg!("Instruction: SetCustodian");
+            process_set_custodian(program_id, accounts, custodian)
+        }
+
+        VaultWhitelistInstruction::MintFor {
+            amount_in,
+            min_amount_out,
+        } => {
+            msg!("Instruction: MintFor");
+            process_mint_for(program_id, accounts, amount_in, min_amount_out)
+        }
+
+        VaultWhitelistInstruction::SetBurnCrank => {
+            msg!("Instruction: SetBurnCrank");
+            process_set_burn_crank(program_id, accounts)
+        }
+
+        VaultWhitelistInstruction::CrankBurnWithdrawalTicket => {
+            msg!("Instruction: CrankBurnWithdrawalTicket");
+            process_crank_burn_withdrawal_ticket(program_id, accounts)
+        }
+
+        VaultWhitelistInstruction::SetBurnDelegate { permissionless } => {
+            msg!("Instruction: SetBurnDelegate");
+            process_set_burn_delegate(program_id, accounts, permissionless)
+        }
+
+        VaultWhitelistInstruction::MigrateAccount => {
+            msg!("Instruction: MigrateAccount");
+            process_migrate_account(program_id, accounts)
+        }
+
+        VaultWhitelistInstruction::SetWhitelistMetadata { name, uri } => {
+            msg!("Instruction: SetWhitelistMetadata");
+            process_set_whitelist_metadata(program_id, accounts, name, uri)
+        }
+
+        VaultWhitelistInstruction::SetUserReference { reference } => {
+            msg!("Instruction: SetUserReference");
+            process_set_user_reference(program_id, accounts, reference)
+        }
     }
 }
@@ -0,0 +1,59 @@
+use jito_bytemuck::{AccountDeserialize, Discriminator};
+use jito_vault_whitelist_core::{
+    blacklisted::Blacklisted, config::Config, whitelist::Whitelist,
+    whitelist_metadata::WhitelistMetadata, whitelist_user::WhitelistUser,
+};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+/// Process upgrading a program account to the current layout version
+///
+/// Every field added so far was carved from reserved space, so accounts keep their size and only
+/// the version byte changes.
+pub fn process_migrate_account(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let [account_info] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    if account_info.owner.ne(program_id) {
+        msg!("Account has an invalid owner");
+        return Err(ProgramError::InvalidAccountOwner);
+    }
+    if !account_info.is_writable {
+        msg!("Account is not writable");
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    let mut data = account_info.try_borrow_mut_data()?;
+    let discriminator = data
+        .first()
+        .copied()
+        .ok_or(ProgramError::InvalidAccountData)?;
+    let migrated = if discriminator == Config::DISCRIMINATOR {
+        Config::try_from_slice_unchecked_mut(&mut data)?.migrate()?
+    } else if discriminator == Whitelist::DISCRIMINATOR {
+        Whitelist::try_from_slice_unchecked_mut(&mut data)?.migrate()?
+    } else if discriminator == WhitelistUser::DISCRIMINATOR {
+        WhitelistUser::try_from_slice_unchecked_mut(&mut data)?.migrate()?
+    } else if discriminator == Blacklisted::DISCRIMINATOR {
+        Blacklisted::try_from_slice_unchecked_mut(&mut data)?.migrate()?
+    } else if discriminator == WhitelistMetadata::DISCRIMINATOR {
+        WhitelistMetadata::try_from_slice_unchecked_mut(&mut data)?.migrate()?
+    } else {
+        msg!("Account is not a program account");
+        return Err(ProgramError::InvalidAccountData);
+    };
+
+    if migrated {
+        msg!("Migrated account {}", account_info.key);
+    } else {
+        msg!(
+            "Account {} is already at the current version",
+            account_info.key
+        );
+    }
+
+    Ok(())
+}
@@ -2,13 +2,18 @@ use jito_bytemuck::AccountDeserialize;
 use jito_jsm_core::loader::load_signer;
 use jito_vault_sdk::sdk::mint_to;
 use jito_vault_whitelist_core::{
-    config::Config, whitelist::Whitelist, whitelist_user::WhitelistUser,
+    config::Config,
+    whitelist::{Whitelist, WhitelistMode},
+    whitelist_user::WhitelistUser,
 };
+use jito_vault_whitelist_sdk::error::VaultWhitelistError;
 use solana_program::{
-    account_info::AccountInfo, entrypoint::ProgramResult, msg, program::invoke_signed,
-    program_error::ProgramError, pubkey::Pubkey,
+    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult, msg,
+    program::invoke_signed, program_error::ProgramError, pubkey::Pubkey, sysvar::Sysvar,
 };
 
+use crate::utils::{check_whitelist_access, token_account_amount};
+
 /// Process minting
 pub fn process_mint(
     program_id: &Pubkey,
@@ -32,18 +37,23 @@ pub fn process_mint(
 
     load_signer(depositor, true)?;
 
-    WhitelistUser::load(
+    let whitelist_mode = check_whitelist_access(
         program_id,
+        whitelist,
+        whitelist_info,
         whitelist_user_info,
-        whitelist_info.key,
         depositor.key,
-        false,
     )?;
-    let whitelist_user_data = whitelist_user_info.data.borrow();
-    let whitelist_user = WhitelistUser::try_from_slice_unchecked(&whitelist_user_data)?;
 
-    whitelist_user.check_whitelist(whitelist_info.key)?;
-    whitelist_user.check_user(depositor.key)?;
+    // Per-user overrides are tracked on WhitelistUser, so they only apply in allow-list mode
+    if whitelist_mode == WhitelistMode::AllowList {
+        let whitelist_user_data = whitelist_user_info.data.borrow();
+        let whitelist_user = WhitelistUser::try_from_slice_unchecked(&whitelist_user_data)?;
+
+        whitelist.check_deposit(amount_in, Some(whitelist_user))?;
+    } else {
+        whitelist.check_deposit(amount_in, None)?;
+    }
 
     let (_, whitelist_bump, mut whitelist_seeds) =
         Whitelist::find_program_address(program_id, vault_info.key);
@@ -66,6 +76,32 @@ pub fn process_mint(
 
     drop(whitelist_data);
 
+    let epoch = Clock::get()?.epoch;
+    {
+        let mut whitelist_data = whitelist_info.try_borrow_mut_data()?;
+        let whitelist = Whitelist::try_from_slice_unchecked_mut(&mut whitelist_data)?;
+
+        if let Some(window_start_epoch) = whitelist.rate_limit_window(epoch) {
+            whitelist.consume_mint_limit(amount_in, window_start_epoch)?;
+
+            // Per-user windows are tracked on WhitelistUser, so they only apply in allow-list mode
+            if whitelist_mode == WhitelistMode::AllowList {
+                let mut whitelist_user_data = whitelist_user_info.try_borrow_mut_data()?;
+                let whitelist_user =
+                    WhitelistUser::try_from_slice_unchecked_mut(&mut whitelist_user_data)?;
+                whitelist_user.consume_mint_limit(
+                    amount_in,
+                    window_start_epoch,
+                    whitelist.user_mint_limit(),
+                )?;
+            }
+        }
+    }
+
+    let vault_token_amount_before = token_account_amount(vault_token_account)?;
+    let depositor_token_amount_before = token_account_amount(depositor_token_account)?;
+    let depositor_vrt_amount_before = token_account_amount(depositor_vrt_token_account)?;
+
     msg!("Processing mint_to instruction on Jito Vault Program");
 
     invoke_signed(
@@ -90,5 +126,27 @@ pub fn process_mint(
             .as_slice()],
     )?;
 
+    let minted_in = token_account_amount(vault_token_account)?
+        .checked_sub(vault_token_amount_before)
+        .ok_or(VaultWhitelistError::ArithmeticUnderflow)?;
+
+    let deposited = depositor_token_amount_before
+        .checked_sub(token_account_amount(depositor_token_account)?)
+        .ok_or(VaultWhitelistError::ArithmeticUnderflow)?;
+    let vrt_minted = token_account_amount(depositor_vrt_token_account)?
+        .checked_sub(depositor_vrt_amount_before)
+        .ok_or(VaultWhitelistError::ArithmeticUnderflow)?;
+
+    let mut whitelist_data = whitelist_info.try_borrow_mut_data()?;
+    let whitelist = Whitelist::try_from_slice_unchecked_mut(&mut whitelist_data)?;
+    whitelist.record_mint(minted_in)?;
+
+    // Only allow-list users have a WhitelistUser to record activity on
+    if whitelist_mode == WhitelistMode::AllowList {
+        let mut whitelist_user_data = whitelist_user_info.try_borrow_mut_data()?;
+        let whitelist_user = WhitelistUser::try_from_slice_unchecked_mut(&mut whitelist_user_data)?;
+        whitelist_user.record_mint(deposited, vrt_minted, Clock::get()?.slot)?;
+    }
+
     Ok(())
 }
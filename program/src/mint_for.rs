@@ -0,0 +1,189 @@
+use jito_bytemuck::AccountDeserialize;
+use jito_jsm_core::loader::load_signer;
+use jito_vault_sdk::sdk::mint_to;
+use jito_vault_whitelist_core::{
+    config::Config,
+    whitelist::{Whitelist, WhitelistMode},
+    whitelist_user::WhitelistUser,
+};
+use jito_vault_whitelist_sdk::error::VaultWhitelistError;
+use solana_program::{
+    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult, msg,
+    program::invoke_signed, program_error::ProgramError, pubkey::Pubkey, sysvar::Sysvar,
+};
+
+use crate::utils::{token_account_amount, token_account_owner};
+
+/// Process minting on behalf of a beneficiary
+pub fn process_mint_for(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    amount_in: u64,
+    min_amount_out: u64,
+) -> ProgramResult {
+    let [config_info, vault_config_info, vault_info, vrt_mint, depositor, depositor_token_account, vault_token_account, depositor_vrt_token_account, vault_fee_token_account, whitelist_info, whitelist_user_info, jito_vault_program_info, token_program_info, beneficiary, beneficiary_whitelist_user_info] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    Config::load(program_id, config_info, false)?;
+
+    Whitelist::load(program_id, whitelist_info, vault_info.key, true)?;
+    let whitelist_data = whitelist_info.data.borrow();
+    let whitelist = Whitelist::try_from_slice_unchecked(&whitelist_data)?;
+
+    whitelist.check_vault(vault_info.key)?;
+
+    load_signer(depositor, true)?;
+
+    // Custodians are flagged on WhitelistUser, so minting for others needs allow-list mode
+    if whitelist.mode()? != WhitelistMode::AllowList {
+        msg!("MintFor is only available in allow-list mode");
+        return Err(VaultWhitelistError::InvalidWhitelistMode.into());
+    }
+
+    {
+        WhitelistUser::load(
+            program_id,
+            whitelist_user_info,
+            whitelist_info.key,
+            depositor.key,
+            true,
+        )?;
+        let whitelist_user_data = whitelist_user_info.data.borrow();
+        let whitelist_user = WhitelistUser::try_from_slice_unchecked(&whitelist_user_data)?;
+
+        whitelist_user.check_whitelist(whitelist_info.key, whitelist.generation())?;
+        whitelist_user.check_user(depositor.key)?;
+        whitelist_user.check_custodian()?;
+
+        whitelist.check_deposit(amount_in, Some(whitelist_user))?;
+    }
+
+    {
+        WhitelistUser::load(
+            program_id,
+            beneficiary_whitelist_user_info,
+            whitelist_info.key,
+            beneficiary.key,
+            true,
+        )?;
+        let beneficiary_whitelist_user_data = beneficiary_whitelist_user_info.data.borrow();
+        let beneficiary_whitelist_user =
+            WhitelistUser::try_from_slice_unchecked(&beneficiary_whitelist_user_data)?;
+
+        beneficiary_whitelist_user.check_whitelist(whitelist_info.key, whitelist.generation())?;
+        beneficiary_whitelist_user.check_user(beneficiary.key)?;
+    }
+
+    if token_account_owner(depositor_vrt_token_account)?.ne(beneficiary.key) {
+        msg!("Depositor VRT token account is not owned by the beneficiary");
+        return Err(VaultWhitelistError::InvalidBeneficiary.into());
+    }
+
+    let (_, whitelist_bump, mut whitelist_seeds) =
+        Whitelist::find_program_address(program_id, vault_info.key);
+    whitelist_seeds.push(vec![whitelist_bump]);
+
+    let ix = mint_to(
+        &jito_vault_program::id(),
+        vault_config_info.key,
+        vault_info.key,
+        vrt_mint.key,
+        depositor.key,
+        depositor_token_account.key,
+        vault_token_account.key,
+        depositor_vrt_token_account.key,
+        vault_fee_token_account.key,
+        Some(whitelist_info.key),
+        amount_in,
+        min_amount_out,
+    );
+
+    drop(whitelist_data);
+
+    let epoch = Clock::get()?.epoch;
+    {
+        let mut whitelist_data = whitelist_info.try_borrow_mut_data()?;
+        let whitelist = Whitelist::try_from_slice_unchecked_mut(&mut whitelist_data)?;
+
+        // The custodian deposits, so it is the custodian's window that is consumed
+        if let Some(window_start_epoch) = whitelist.rate_limit_window(epoch) {
+            whitelist.consume_mint_limit(amount_in, window_start_epoch)?;
+
+            let mut whitelist_user_data = whitelist_user_info.try_borrow_mut_data()?;
+            let whitelist_user =
+                WhitelistUser::try_from_slice_unchecked_mut(&mut whitelist_user_data)?;
+            whitelist_user.consume_mint_limit(
+                amount_in,
+                window_start_epoch,
+                whitelist.user_mint_limit(),
+            )?;
+        }
+    }
+
+    let vault_token_amount_before = token_account_amount(vault_token_account)?;
+    let depositor_token_amount_before = token_account_amount(depositor_token_account)?;
+    let depositor_vrt_amount_before = token_account_amount(depositor_vrt_token_account)?;
+
+    msg!(
+        "Processing mint_to instruction on Jito Vault Program for beneficiary {}",
+        beneficiary.key
+    );
+
+    invoke_signed(
+        &ix,
+        &[
+            vault_config_info.clone(),
+            vault_info.clone(),
+            vrt_mint.clone(),
+            depositor.clone(),
+            depositor_token_account.clone(),
+            vault_token_account.clone(),
+            depositor_vrt_token_account.clone(),
+            vault_fee_token_account.clone(),
+            token_program_info.clone(),
+            whitelist_info.clone(),
+            jito_vault_program_info.clone(),
+        ],
+        &[whitelist_seeds
+            .iter()
+            .map(|s| s.as_slice())
+            .collect::<Vec<&[u8]>>()
+            .as_slice()],
+    )?;
+
+    let minted_in = token_account_amount(vault_token_account)?
+        .checked_sub(vault_token_amount_before)
+        .ok_or(VaultWhitelistError::ArithmeticUnderflow)?;
+
+    let deposited = depositor_token_amount_before
+        .checked_sub(token_account_amount(depositor_token_account)?)
+        .ok_or(VaultWhitelistError::ArithmeticUnderflow)?;
+    let vrt_minted = token_account_amount(depositor_vrt_token_account)?
+        .checked_sub(depositor_vrt_amount_before)
+        .ok_or(VaultWhitelistError::ArithmeticUnderflow)?;
+
+    let mut whitelist_data = whitelist_info.try_borrow_mut_data()?;
+    let whitelist = Whitelist::try_from_slice_unchecked_mut(&mut whitelist_data)?;
+    whitelist.record_mint(minted_in)?;
+
+    let slot = Clock::get()?.slot;
+
+    // Deposits are attributed to the custodian and VRT to the beneficiary, whose lockup starts now
+    {
+        let mut whitelist_user_data = whitelist_user_info.try_borrow_mut_data()?;
+        let whitelist_user = WhitelistUser::try_from_slice_unchecked_mut(&mut whitelist_user_data)?;
+        whitelist_user.record_deposit_for(deposited, slot)?;
+    }
+    {
+        let mut beneficiary_whitelist_user_data =
+            beneficiary_whitelist_user_info.try_borrow_mut_data()?;
+        let beneficiary_whitelist_user =
+            WhitelistUser::try_from_slice_unchecked_mut(&mut beneficiary_whitelist_user_data)?;
+        beneficiary_whitelist_user.record_mint(0, vrt_minted, slot)?;
+    }
+
+    Ok(())
+}
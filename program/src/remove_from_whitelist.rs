@@ -17,7 +17,7 @@ pub fn process_remove_from_whitelist(
     program_id: &Pubkey,
     accounts: &[AccountInfo],
 ) -> ProgramResult {
-    let [config_info, vault_info, whitelist_info, whitelist_user_info, vault_admin_info, user_info, system_program_info] =
+    let [config_info, vault_info, whitelist_info, whitelist_user_info, vault_admin_info, rent_receiver_info, user_info, system_program_info] =
         accounts
     else {
         return Err(ProgramError::NotEnoughAccountKeys);
@@ -31,17 +31,24 @@ pub fn process_remove_from_whitelist(
 
     vault.check_admin(vault_admin_info.key)?;
 
-    {
-        Whitelist::load(program_id, whitelist_info, vault_info.key, false)?;
+    let generation = {
+        Whitelist::load(program_id, whitelist_info, vault_info.key, true)?;
         let whitelist_data = whitelist_info.data.borrow();
         let whitelist = Whitelist::try_from_slice_unchecked(&whitelist_data)?;
 
         whitelist.check_vault(vault_info.key)?;
-    }
 
-    load_signer(vault_admin_info, true)?;
+        whitelist.generation()
+    };
+
+    load_signer(vault_admin_info, false)?;
     load_system_program(system_program_info)?;
 
+    if !rent_receiver_info.is_writable {
+        msg!("Rent receiver account is not writable");
+        return Err(ProgramError::InvalidAccountData);
+    }
+
     {
         WhitelistUser::load(
             program_id,
@@ -53,8 +60,16 @@ pub fn process_remove_from_whitelist(
         let whitelist_user_data = whitelist_user_info.data.borrow();
         let whitelist_user = WhitelistUser::try_from_slice_unchecked(&whitelist_user_data)?;
 
-        whitelist_user.check_whitelist(whitelist_info.key)?;
+        whitelist_user.check_whitelist(whitelist_info.key, generation)?;
         whitelist_user.check_user(user_info.key)?;
+        whitelist_user.check_rent_receiver(rent_receiver_info.key, vault_admin_info.key)?;
+    }
+
+    {
+        let mut whitelist_data = whitelist_info.try_borrow_mut_data()?;
+        let whitelist = Whitelist::try_from_slice_unchecked_mut(&mut whitelist_data)?;
+
+        whitelist.decrement_user_count();
     }
 
     msg!(
@@ -63,7 +78,7 @@ pub fn process_remove_from_whitelist(
         whitelist_info.key
     );
 
-    close_program_account(program_id, whitelist_user_info, vault_admin_info)?;
+    close_program_account(program_id, whitelist_user_info, rent_receiver_info)?;
 
     Ok(())
 }
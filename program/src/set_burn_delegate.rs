@@ -0,0 +1,56 @@
+use jito_bytemuck::AccountDeserialize;
+use jito_jsm_core::loader::load_signer;
+use jito_vault_whitelist_core::{
+    config::Config, whitelist::Whitelist, whitelist_user::WhitelistUser,
+};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+/// Process setting who may burn a whitelisted user's matured withdrawal tickets
+pub fn process_set_burn_delegate(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    permissionless: bool,
+) -> ProgramResult {
+    let [config_info, vault_info, whitelist_info, whitelist_user_info, user_info, burn_delegate_info] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    Config::load(program_id, config_info, false)?;
+
+    Whitelist::load(program_id, whitelist_info, vault_info.key, false)?;
+    let whitelist_data = whitelist_info.data.borrow();
+    let whitelist = Whitelist::try_from_slice_unchecked(&whitelist_data)?;
+
+    whitelist.check_vault(vault_info.key)?;
+
+    load_signer(user_info, false)?;
+
+    WhitelistUser::load(
+        program_id,
+        whitelist_user_info,
+        whitelist_info.key,
+        user_info.key,
+        true,
+    )?;
+    let mut whitelist_user_data = whitelist_user_info.try_borrow_mut_data()?;
+    let whitelist_user = WhitelistUser::try_from_slice_unchecked_mut(&mut whitelist_user_data)?;
+
+    whitelist_user.check_whitelist(whitelist_info.key, whitelist.generation())?;
+    whitelist_user.check_user(user_info.key)?;
+
+    msg!(
+        "Setting user {} burn delegate to {}, permissionless {}",
+        user_info.key,
+        burn_delegate_info.key,
+        permissionless
+    );
+
+    whitelist_user.set_burn_delegate(*burn_delegate_info.key, permissionless);
+
+    Ok(())
+}
@@ -0,0 +1,45 @@
+use jito_bytemuck::AccountDeserialize;
+use jito_jsm_core::loader::load_signer;
+use jito_vault_core::vault::Vault;
+use jito_vault_whitelist_core::{config::Config, whitelist::Whitelist};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+/// Process setting the lockup between mint and enqueue withdrawal
+pub fn process_set_lockup(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    lockup_slots: u64,
+) -> ProgramResult {
+    let [config_info, vault_info, whitelist_info, vault_admin_info] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    Config::load(program_id, config_info, false)?;
+
+    Vault::load(&jito_vault_program::id(), vault_info, false)?;
+    let vault_data = vault_info.data.borrow();
+    let vault = Vault::try_from_slice_unchecked(&vault_data)?;
+
+    vault.check_admin(vault_admin_info.key)?;
+
+    load_signer(vault_admin_info, false)?;
+
+    Whitelist::load(program_id, whitelist_info, vault_info.key, true)?;
+    let mut whitelist_data = whitelist_info.try_borrow_mut_data()?;
+    let whitelist = Whitelist::try_from_slice_unchecked_mut(&mut whitelist_data)?;
+
+    whitelist.check_vault(vault_info.key)?;
+
+    msg!(
+        "Setting Whitelist {} lockup to {} slots",
+        whitelist_info.key,
+        lockup_slots
+    );
+
+    whitelist.set_lockup_slots(lockup_slots);
+
+    Ok(())
+}
@@ -0,0 +1,60 @@
+use jito_bytemuck::AccountDeserialize;
+use jito_jsm_core::loader::load_signer;
+use jito_vault_core::vault::Vault;
+use jito_vault_whitelist_core::{
+    config::Config,
+    whitelist::Whitelist,
+    whitelist_user::{WhitelistUser, WHITELIST_USER_REFERENCE_LEN},
+};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+/// Process setting the tag linking a whitelisted user to an off-chain record
+pub fn process_set_user_reference(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    reference: [u8; WHITELIST_USER_REFERENCE_LEN],
+) -> ProgramResult {
+    let [config_info, vault_info, whitelist_info, whitelist_user_info, vault_admin_info, user_info] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    Config::load(program_id, config_info, false)?;
+
+    Vault::load(&jito_vault_program::id(), vault_info, false)?;
+    let vault_data = vault_info.data.borrow();
+    let vault = Vault::try_from_slice_unchecked(&vault_data)?;
+
+    vault.check_admin(vault_admin_info.key)?;
+
+    load_signer(vault_admin_info, false)?;
+
+    Whitelist::load(program_id, whitelist_info, vault_info.key, false)?;
+    let whitelist_data = whitelist_info.data.borrow();
+    let whitelist = Whitelist::try_from_slice_unchecked(&whitelist_data)?;
+
+    whitelist.check_vault(vault_info.key)?;
+
+    WhitelistUser::load(
+        program_id,
+        whitelist_user_info,
+        whitelist_info.key,
+        user_info.key,
+        true,
+    )?;
+    let mut whitelist_user_data = whitelist_user_info.try_borrow_mut_data()?;
+    let whitelist_user = WhitelistUser::try_from_slice_unchecked_mut(&mut whitelist_user_data)?;
+
+    whitelist_user.check_whitelist(whitelist_info.key, whitelist.generation())?;
+    whitelist_user.check_user(user_info.key)?;
+
+    msg!("Setting user {} reference", user_info.key);
+
+    whitelist_user.set_reference(reference);
+
+    Ok(())
+}
@@ -0,0 +1,67 @@
+use jito_bytemuck::AccountDeserialize;
+use jito_jsm_core::loader::{load_signer, load_system_program};
+use jito_vault_core::vault::Vault;
+use jito_vault_whitelist_core::{
+    config::Config,
+    whitelist::Whitelist,
+    whitelist_metadata::{WhitelistMetadata, WHITELIST_NAME_LEN, WHITELIST_URI_LEN},
+};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+use crate::utils::load_or_create_whitelist_metadata;
+
+/// Process setting the metadata telling a whitelist apart
+pub fn process_set_whitelist_metadata(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    name: [u8; WHITELIST_NAME_LEN],
+    uri: [u8; WHITELIST_URI_LEN],
+) -> ProgramResult {
+    let [config_info, vault_info, whitelist_info, whitelist_metadata_info, vault_admin_info, payer_info, system_program_info, kyc_provider_info] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    Config::load(program_id, config_info, false)?;
+
+    Vault::load(&jito_vault_program::id(), vault_info, false)?;
+    let vault_data = vault_info.data.borrow();
+    let vault = Vault::try_from_slice_unchecked(&vault_data)?;
+
+    vault.check_admin(vault_admin_info.key)?;
+
+    load_signer(vault_admin_info, false)?;
+    load_signer(payer_info, true)?;
+    load_system_program(system_program_info)?;
+
+    Whitelist::load(program_id, whitelist_info, vault_info.key, false)?;
+    let whitelist_data = whitelist_info.data.borrow();
+    let whitelist = Whitelist::try_from_slice_unchecked(&whitelist_data)?;
+
+    whitelist.check_vault(vault_info.key)?;
+
+    load_or_create_whitelist_metadata(
+        program_id,
+        whitelist_info,
+        whitelist_metadata_info,
+        payer_info,
+        system_program_info,
+    )?;
+    let mut whitelist_metadata_data = whitelist_metadata_info.try_borrow_mut_data()?;
+    let whitelist_metadata =
+        WhitelistMetadata::try_from_slice_unchecked_mut(&mut whitelist_metadata_data)?;
+
+    msg!(
+        "Setting Whitelist {} metadata, KYC provider {}",
+        whitelist_info.key,
+        kyc_provider_info.key
+    );
+
+    whitelist_metadata.set_metadata(name, *kyc_provider_info.key, uri);
+
+    Ok(())
+}
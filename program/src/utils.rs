@@ -0,0 +1,127 @@
+use jito_bytemuck::{AccountDeserialize, Discriminator};
+use jito_jsm_core::{create_account, loader::load_system_account};
+use jito_vault_whitelist_core::{
+    blacklisted::Blacklisted,
+    whitelist::{Whitelist, WhitelistMode},
+    whitelist_metadata::WhitelistMetadata,
+    whitelist_user::WhitelistUser,
+};
+use jito_vault_whitelist_sdk::error::VaultWhitelistError;
+use solana_program::{
+    account_info::AccountInfo, msg, program_error::ProgramError, pubkey::Pubkey, rent::Rent,
+    sysvar::Sysvar,
+};
+use spl_token_2022::{extension::StateWithExtensions, state::Account};
+
+/// Read the balance of an SPL Token or Token-2022 account
+pub fn token_account_amount(token_account: &AccountInfo) -> Result<u64, ProgramError> {
+    let token_account_data = token_account.data.borrow();
+    let token_account = StateWithExtensions::<Account>::unpack(&token_account_data)?;
+
+    Ok(token_account.base.amount)
+}
+
+/// Read the owner of an SPL Token or Token-2022 account
+pub fn token_account_owner(token_account: &AccountInfo) -> Result<Pubkey, ProgramError> {
+    let token_account_data = token_account.data.borrow();
+    let token_account = StateWithExtensions::<Account>::unpack(&token_account_data)?;
+
+    Ok(token_account.base.owner)
+}
+
+/// Load the whitelist's WhitelistMetadata, creating it with rent from `payer_info` when missing
+///
+/// The account of a closed whitelist is reused, so its metadata must be overwritten by the caller.
+pub fn load_or_create_whitelist_metadata(
+    program_id: &Pubkey,
+    whitelist_info: &AccountInfo,
+    whitelist_metadata_info: &AccountInfo,
+    payer_info: &AccountInfo,
+    system_program_info: &AccountInfo,
+) -> Result<(), ProgramError> {
+    if !whitelist_metadata_info.data_is_empty() {
+        return WhitelistMetadata::load(
+            program_id,
+            whitelist_metadata_info,
+            whitelist_info.key,
+            true,
+        );
+    }
+
+    load_system_account(whitelist_metadata_info, true)?;
+
+    // The WhitelistMetadata account shall be at the canonical PDA
+    let (whitelist_metadata_pubkey, whitelist_metadata_bump, mut whitelist_metadata_seeds) =
+        WhitelistMetadata::find_program_address(program_id, whitelist_info.key);
+    whitelist_metadata_seeds.push(vec![whitelist_metadata_bump]);
+    if whitelist_metadata_pubkey.ne(whitelist_metadata_info.key) {
+        msg!("WhitelistMetadata account is not at the correct PDA");
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    msg!(
+        "Initializing WhitelistMetadata at address {}",
+        whitelist_metadata_info.key
+    );
+    create_account(
+        payer_info,
+        whitelist_metadata_info,
+        system_program_info,
+        program_id,
+        &Rent::get()?,
+        8_u64
+            .checked_add(std::mem::size_of::<WhitelistMetadata>() as u64)
+            .ok_or(VaultWhitelistError::ArithmeticOverflow)?,
+        &whitelist_metadata_seeds,
+    )?;
+
+    let mut whitelist_metadata_data = whitelist_metadata_info.try_borrow_mut_data()?;
+    whitelist_metadata_data[0] = WhitelistMetadata::DISCRIMINATOR;
+    let whitelist_metadata =
+        WhitelistMetadata::try_from_slice_unchecked_mut(&mut whitelist_metadata_data)?;
+    *whitelist_metadata = WhitelistMetadata::new(*whitelist_info.key, whitelist_metadata_bump);
+
+    Ok(())
+}
+
+/// Check that `user` may mint and redeem through the whitelist, returning its mode
+///
+/// In allow-list mode `whitelist_user_info` is the user's WhitelistUser, in deny-list mode it is
+/// the user's Blacklisted PDA, which must not exist in the current whitelist generation.
+pub fn check_whitelist_access(
+    program_id: &Pubkey,
+    whitelist: &Whitelist,
+    whitelist_info: &AccountInfo,
+    whitelist_user_info: &AccountInfo,
+    user: &Pubkey,
+) -> Result<WhitelistMode, ProgramError> {
+    let mode = whitelist.mode()?;
+
+    match mode {
+        WhitelistMode::AllowList => {
+            WhitelistUser::load(
+                program_id,
+                whitelist_user_info,
+                whitelist_info.key,
+                user,
+                true,
+            )?;
+            let whitelist_user_data = whitelist_user_info.data.borrow();
+            let whitelist_user = WhitelistUser::try_from_slice_unchecked(&whitelist_user_data)?;
+
+            whitelist_user.check_whitelist(whitelist_info.key, whitelist.generation())?;
+            whitelist_user.check_user(user)?;
+        }
+        WhitelistMode::DenyList => {
+            Blacklisted::check_not_blacklisted(
+                program_id,
+                whitelist_user_info,
+                whitelist_info.key,
+                user,
+                whitelist.generation(),
+            )?;
+        }
+    }
+
+    Ok(mode)
+}
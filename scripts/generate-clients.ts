@@ -58,6 +58,25 @@ vaultWhitelistCodama.update(codama.bottomUpTransformerVisitor([
       };
     },
   },
+  {
+    // PodBool -> bool
+    select: (node: any): boolean => {
+      return (
+        codama.isNode(node, "structFieldTypeNode") &&
+        node.type && 
+        typeof node.type === 'object' && 
+        'name' in node.type &&
+        node.type.name === "podBool"
+      );
+    },
+    transform: (node: any): any => {
+      codama.assertIsNode(node, "structFieldTypeNode");
+      return {
+        ...node,
+        type: codama.booleanTypeNode(),
+      };
+    },
+  },
   {
     // PodU32 -> u32
     select: (node: any): boolean => {
@@ -20,6 +20,54 @@ pub enum VaultWhitelistError {
 
     #[error("InvalidWhitelistUser")]
     InvalidWhitelistUser,
+
+    #[error("InvalidRentReceiver")]
+    InvalidRentReceiver,
+
+    #[error("WhitelistNotClosed")]
+    WhitelistNotClosed,
+
+    #[error("StaleWhitelistUser")]
+    StaleWhitelistUser,
+
+    #[error("InvalidWhitelistMode")]
+    InvalidWhitelistMode,
+
+    #[error("UserBlacklisted")]
+    UserBlacklisted,
+
+    #[error("WithdrawalLocked")]
+    WithdrawalLocked,
+
+    #[error("RateLimitExceeded")]
+    RateLimitExceeded,
+
+    #[error("DepositTooSmall")]
+    DepositTooSmall,
+
+    #[error("DepositTooLarge")]
+    DepositTooLarge,
+
+    #[error("InvalidDepositLimits")]
+    InvalidDepositLimits,
+
+    #[error("NotCustodian")]
+    NotCustodian,
+
+    #[error("InvalidBeneficiary")]
+    InvalidBeneficiary,
+
+    #[error("InvalidBurnCrank")]
+    InvalidBurnCrank,
+
+    #[error("InvalidStakerTokenAccount")]
+    InvalidStakerTokenAccount,
+
+    #[error("SlippageExceeded")]
+    SlippageExceeded,
+
+    #[error("UnsupportedAccountVersion")]
+    UnsupportedAccountVersion,
 }
 
 impl From<VaultWhitelistError> for ProgramError {
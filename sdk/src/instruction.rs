@@ -9,12 +9,18 @@ pub enum VaultWhitelistInstruction {
     #[account(3, name = "system_program")]
     InitializeConfig,
 
-    #[account(0, name = "config")]
+    /// Initializes the whitelist of a vault with metadata telling it apart, kept in its own
+    /// WhitelistMetadata account. `name` and `uri` are UTF-8 padded with zeros and the system
+    /// program as `kyc_provider` means none
+    #[account(0, writable, name = "config")]
     #[account(1, writable, name = "whitelist")]
     #[account(2, name = "vault")]
-    #[account(3, writable, signer, name = "vault_admin")]
-    #[account(4, name = "system_program")]
-    InitializeWhitelist,
+    #[account(3, signer, name = "vault_admin")]
+    #[account(4, writable, signer, name = "payer")]
+    #[account(5, name = "system_program")]
+    #[account(6, name = "kyc_provider")]
+    #[account(7, writable, name = "whitelist_metadata")]
+    InitializeWhitelist { name: [u8; 32], uri: [u8; 128] },
 
     #[account(0, name = "config")]
     #[account(1, name = "vault_config")]
@@ -24,24 +30,31 @@ pub enum VaultWhitelistInstruction {
     #[account(5, name = "jito_vault_program")]
     SetMintBurnAdmin,
 
+    /// Adds a user to the whitelist, `reference` is an opaque tag linking the user to an
+    /// off-chain record, such as a hash of a KYC case ID. A WhitelistUser left behind by a closed
+    /// whitelist is reinitialized in place
     #[account(0, name = "config")]
     #[account(1, name = "vault")]
-    #[account(2, name = "whitelist")]
+    #[account(2, writable, name = "whitelist")]
     #[account(3, writable, name = "whitelist_user")]
     #[account(4, signer, name = "vault_admin")]
-    #[account(5, name = "user")]
-    #[account(6, name = "system_program")]
-    AddToWhitelist,
+    #[account(5, writable, signer, name = "payer")]
+    #[account(6, name = "user")]
+    #[account(7, name = "system_program")]
+    AddToWhitelist { reference: [u8; 32] },
 
     #[account(0, name = "config")]
     #[account(1, name = "vault")]
-    #[account(2, name = "whitelist")]
+    #[account(2, writable, name = "whitelist")]
     #[account(3, writable, name = "whitelist_user")]
     #[account(4, signer, name = "vault_admin")]
-    #[account(5, name = "user")]
-    #[account(6, name = "system_program")]
+    #[account(5, writable, name = "rent_receiver")]
+    #[account(6, name = "user")]
+    #[account(7, name = "system_program")]
     RemoveFromWhitelist,
 
+    /// Mints VRT to a whitelisted depositor. In deny-list mode `whitelist_user` is the
+    /// depositor's Blacklisted PDA, which must not exist
     #[account(0, name = "config")]
     #[account(1, writable, name = "vault_config")]
     #[account(2, writable, name = "vault")]
@@ -52,13 +65,15 @@ pub enum VaultWhitelistInstruction {
     #[account(7, writable, name = "depositor_vrt_token_account")]
     #[account(8, writable, name = "vault_fee_token_account")]
     #[account(9, writable, name = "whitelist")]
-    #[account(10, name = "whitelist_user")]
+    #[account(10, writable, name = "whitelist_user")]
     #[account(11, name = "jito_vault_program")]
     #[account(12, name = "token_program")]
     Mint { amount_in: u64, min_amount_out: u64 },
 
     /// Enqueues a withdrawal of VRT tokens
-    /// Used when there aren't enough idle assets in the vault to cover a withdrawal
+    /// This is the only way to withdraw, the Jito Vault program has no immediate redemption so the
+    /// ticket can be burned with `BurnWithdrawalTicket` once a full epoch has passed
+    /// In deny-list mode `whitelist_user` is the staker's Blacklisted PDA, which must not exist
     #[account(0, name = "vault_config")]
     #[account(1, writable, name = "vault")]
     #[account(2, writable, name = "vault_staker_withdrawal_ticket")]
@@ -70,12 +85,14 @@ pub enum VaultWhitelistInstruction {
     #[account(8, name = "system_program")]
     #[account(9, name = "config")]
     #[account(10, writable, name = "whitelist")]
-    #[account(11, name = "whitelist_user")]
+    #[account(11, writable, name = "whitelist_user")]
     #[account(12, name = "jito_vault_program")]
     EnqueueWithdrawal { amount: u64 },
 
     /// Burns the withdrawal ticket, returning funds to the staker. Withdraw tickets can be burned
-    /// after one full epoch of being enqueued.
+    /// after one full epoch of being enqueued. Fails when the staker receives less than
+    /// `min_amount_out` supported tokens, zero disables the check.
+    /// In deny-list mode `whitelist_user` is the staker's Blacklisted PDA, which must not exist
     #[account(0, name = "vault_config")]
     #[account(1, writable, name = "vault")]
     #[account(2, writable, name = "vault_token_account")]
@@ -90,16 +107,190 @@ pub enum VaultWhitelistInstruction {
     #[account(11, name = "system_program")]
     #[account(12, name = "config")]
     #[account(13, writable, name = "whitelist")]
-    #[account(14, name = "whitelist_user")]
+    #[account(14, writable, name = "whitelist_user")]
     #[account(15, name = "jito_vault_program")]
-    BurnWithdrawalTicket,
+    BurnWithdrawalTicket { min_amount_out: u64 },
 
     #[account(0, name = "config")]
     #[account(1, name = "vault_config")]
     #[account(2, writable, name = "whitelist")]
     #[account(3, writable, name = "vault")]
     #[account(4, writable, signer, name = "vault_admin")]
-    #[account(5, name = "jito_vault_program")]
-    #[account(6, name = "system_program")]
+    #[account(5, writable, name = "rent_receiver")]
+    #[account(6, name = "jito_vault_program")]
+    #[account(7, name = "system_program")]
     CloseWhitelist,
+
+    /// Closes a WhitelistUser whose Whitelist has been closed or re-created, refunding the rent to
+    /// the payer. Permissionless.
+    #[account(0, name = "config")]
+    #[account(1, name = "vault")]
+    #[account(2, name = "whitelist")]
+    #[account(3, writable, name = "whitelist_user")]
+    #[account(4, writable, name = "rent_receiver")]
+    #[account(5, name = "user")]
+    CloseOrphanedWhitelistUser,
+
+    /// Sets whether the whitelist is an allow list (0) or a deny list (1)
+    #[account(0, name = "config")]
+    #[account(1, name = "vault")]
+    #[account(2, writable, name = "whitelist")]
+    #[account(3, signer, name = "vault_admin")]
+    SetWhitelistMode { mode: u8 },
+
+    /// Denies a user on a whitelist in deny-list mode
+    #[account(0, name = "config")]
+    #[account(1, name = "vault")]
+    #[account(2, name = "whitelist")]
+    #[account(3, writable, name = "blacklisted")]
+    #[account(4, signer, name = "vault_admin")]
+    #[account(5, writable, signer, name = "payer")]
+    #[account(6, name = "user")]
+    #[account(7, name = "system_program")]
+    AddToBlacklist,
+
+    /// Lifts the denial of a user on a whitelist, refunding the rent to the payer
+    #[account(0, name = "config")]
+    #[account(1, name = "vault")]
+    #[account(2, name = "whitelist")]
+    #[account(3, writable, name = "blacklisted")]
+    #[account(4, signer, name = "vault_admin")]
+    #[account(5, writable, name = "rent_receiver")]
+    #[account(6, name = "user")]
+    #[account(7, name = "system_program")]
+    RemoveFromBlacklist,
+
+    /// Sets the number of slots after a user's last mint before the user may enqueue a withdrawal
+    #[account(0, name = "config")]
+    #[account(1, name = "vault")]
+    #[account(2, writable, name = "whitelist")]
+    #[account(3, signer, name = "vault_admin")]
+    SetLockup { lockup_slots: u64 },
+
+    /// Sets the epoch-window limits on minting and enqueueing withdrawals, zero disables a limit
+    #[account(0, name = "config")]
+    #[account(1, name = "vault")]
+    #[account(2, writable, name = "whitelist")]
+    #[account(3, signer, name = "vault_admin")]
+    SetRateLimit {
+        rate_limit_epochs: u64,
+        mint_limit: u64,
+        enqueue_limit: u64,
+        user_mint_limit: u64,
+        user_enqueue_limit: u64,
+    },
+
+    /// Sets the smallest and largest supported token amounts accepted by a single mint, zero is
+    /// unbounded
+    #[account(0, name = "config")]
+    #[account(1, name = "vault")]
+    #[account(2, writable, name = "whitelist")]
+    #[account(3, signer, name = "vault_admin")]
+    SetDepositLimits { min_deposit: u64, max_deposit: u64 },
+
+    /// Sets deposit limits for a whitelisted user, overriding the whitelist's when non-zero
+    #[account(0, name = "config")]
+    #[account(1, name = "vault")]
+    #[account(2, name = "whitelist")]
+    #[account(3, writable, name = "whitelist_user")]
+    #[account(4, signer, name = "vault_admin")]
+    #[account(5, name = "user")]
+    SetUserDepositLimits { min_deposit: u64, max_deposit: u64 },
+
+    /// Sets whether a whitelisted user may mint VRT on behalf of other whitelisted users
+    #[account(0, name = "config")]
+    #[account(1, name = "vault")]
+    #[account(2, name = "whitelist")]
+    #[account(3, writable, name = "whitelist_user")]
+    #[account(4, signer, name = "vault_admin")]
+    #[account(5, name = "user")]
+    SetCustodian { custodian: bool },
+
+    /// Mints VRT from a custodian's supported tokens to a whitelisted beneficiary's VRT token
+    /// account. Only available in allow-list mode
+    #[account(0, name = "config")]
+    #[account(1, writable, name = "vault_config")]
+    #[account(2, writable, name = "vault")]
+    #[account(3, writable, name = "vrt_mint")]
+    #[account(4, writable, signer, name = "depositor")]
+    #[account(5, writable, name = "depositor_token_account")]
+    #[account(6, writable, name = "vault_token_account")]
+    #[account(7, writable, name = "depositor_vrt_token_account")]
+    #[account(8, writable, name = "vault_fee_token_account")]
+    #[account(9, writable, name = "whitelist")]
+    #[account(10, writable, name = "whitelist_user")]
+    #[account(11, name = "jito_vault_program")]
+    #[account(12, name = "token_program")]
+    #[account(13, name = "beneficiary")]
+    #[account(14, writable, name = "beneficiary_whitelist_user")]
+    MintFor { amount_in: u64, min_amount_out: u64 },
+
+    /// Sets the key allowed to burn matured withdrawal tickets on behalf of stakers, the system
+    /// program disables it
+    #[account(0, name = "config")]
+    #[account(1, name = "vault")]
+    #[account(2, writable, name = "whitelist")]
+    #[account(3, signer, name = "vault_admin")]
+    #[account(4, name = "burn_crank")]
+    SetBurnCrank,
+
+    /// Burns a matured withdrawal ticket on behalf of a whitelisted staker, paying out to the
+    /// staker's token account. Signed by the whitelist burn crank or, in allow-list mode, a burn
+    /// delegate of the staker instead of the staker.
+    /// There is no `min_amount_out`: a bound only protects the party choosing it, and the signer
+    /// here is not the one paid. Stakers who want a bound burn with `BurnWithdrawalTicket`.
+    #[account(0, name = "vault_config")]
+    #[account(1, writable, name = "vault")]
+    #[account(2, writable, name = "vault_token_account")]
+    #[account(3, writable, name = "vrt_mint")]
+    #[account(4, writable, name = "staker")]
+    #[account(5, writable, name = "staker_token_account")]
+    #[account(6, writable, name = "vault_staker_withdrawal_ticket")]
+    #[account(7, writable, name = "vault_staker_withdrawal_ticket_token_account")]
+    #[account(8, writable, name = "vault_fee_token_account")]
+    #[account(9, writable, name = "program_fee_token_account")]
+    #[account(10, name = "token_program")]
+    #[account(11, name = "system_program")]
+    #[account(12, name = "config")]
+    #[account(13, writable, name = "whitelist")]
+    #[account(14, writable, name = "whitelist_user")]
+    #[account(15, name = "jito_vault_program")]
+    #[account(16, signer, name = "burn_crank")]
+    CrankBurnWithdrawalTicket,
+
+    /// Sets who may burn the signing user's matured withdrawal tickets on the user's behalf,
+    /// anyone when `permissionless` is set. The system program as `burn_delegate` clears it
+    #[account(0, name = "config")]
+    #[account(1, name = "vault")]
+    #[account(2, name = "whitelist")]
+    #[account(3, writable, name = "whitelist_user")]
+    #[account(4, signer, name = "user")]
+    #[account(5, name = "burn_delegate")]
+    SetBurnDelegate { permissionless: bool },
+
+    /// Upgrades a program account to the current layout version
+    #[account(0, writable, name = "account")]
+    MigrateAccount,
+
+    /// Sets the metadata telling a whitelist apart, creating its WhitelistMetadata account with
+    /// rent from `payer` if missing. `name` and `uri` are UTF-8 padded with zeros and the system
+    /// program as `kyc_provider` means none
+    #[account(0, name = "config")]
+    #[account(1, name = "vault")]
+    #[account(2, name = "whitelist")]
+    #[account(3, writable, name = "whitelist_metadata")]
+    #[account(4, signer, name = "vault_admin")]
+    #[account(5, writable, signer, name = "payer")]
+    #[account(6, name = "system_program")]
+    #[account(7, name = "kyc_provider")]
+    SetWhitelistMetadata { name: [u8; 32], uri: [u8; 128] },
+
+    /// Sets the opaque tag linking a whitelisted user to an off-chain record
+    #[account(0, name = "config")]
+    #[account(1, name = "vault")]
+    #[account(2, name = "whitelist")]
+    #[account(3, writable, name = "whitelist_user")]
+    #[account(4, signer, name = "vault_admin")]
+    #[account(5, name = "user")]
+    SetUserReference { reference: [u8; 32] },
 }